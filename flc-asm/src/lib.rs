@@ -338,6 +338,30 @@ impl FlashController<'_, '_> {
             });
         }
     }
+
+    /// Erases the entirety of flash memory.
+    ///
+    /// # Safety
+    ///
+    /// - The argument `sys_clk_freq` must be equal to the current system clock's
+    ///   frequency divided by its divider.
+    /// - The calling program must be executing entirely from SRAM: mass-erasing
+    ///   flash out from under a flash-resident program is immediate undefined
+    ///   behavior.
+    ///
+    /// # Panics
+    /// - If `sys_clk_freq` is not a multiple of 1 MHz, this function panics.
+    #[link_section = ".analogsucks"]
+    unsafe fn mass_erase(&self, sys_clk_freq: u32) {
+        // SAFETY: the caller must guarantee that `sys_clk_freq` is valid per this function's
+        // safety comment.
+        unsafe {
+            self.write_guard(sys_clk_freq, || {
+                self.flc.ctrl().modify(|_, w| w.erase_code().erase_all());
+                self.flc.ctrl().modify(|_, w| w.me().set_bit());
+            });
+        }
+    }
 }
 
 /// Reads a little-endian `u32` from flash memory.
@@ -447,3 +471,39 @@ pub unsafe extern "C" fn page_erase(address: *mut u8, sys_clk_freq: u32) {
         flc.page_erase(address as u32, sys_clk_freq);
     }
 }
+
+/// Erases the entirety of flash memory, via `FLC.ctrl`'s `erase_code`/`me` fields.
+///
+/// # Safety
+///
+/// - The caller must hold a shared reference to the [`FLC`], [`ICC0`], and [`GCR`] registers.
+/// - `sys_clk_freq` must be equal to `freq / div` where `freq` is the frequency of
+///   the current system clock, and `div` is the divider of the system clock.
+/// - `sys_clk_freq` must be divisible by one million (`1_000_000`).
+/// - The calling program must be executing entirely from SRAM: mass-erasing flash
+///   out from under a flash-resident program is immediate undefined behavior.
+///
+/// # Panics
+///
+/// Panics if any of the following preconditions are not true:
+/// - `sys_clk_freq` must be divisible by one million (`1_000_000`).
+#[export_name = "flc_mass_erase_primitive"]
+#[link_section = ".analogsucks"]
+pub unsafe extern "C" fn mass_erase(sys_clk_freq: u32) {
+    // SAFETY: the caller must hold a valid reference to these registers during this call.
+    let flc = unsafe {
+        FlashController {
+            flc: FLC::steal(),
+            icc: &ICC0::steal(),
+            gcr: &GCR::steal(),
+        }
+    };
+
+    // SAFETY:
+    // - the caller must ensure that sys_clk_freq is calculated correctly per this function's
+    //   safety comment.
+    // - the caller must guarantee that the program is executing entirely from SRAM.
+    unsafe {
+        flc.mass_erase(sys_clk_freq);
+    }
+}
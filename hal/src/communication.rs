@@ -17,6 +17,7 @@
 
 use core::time::Duration;
 
+pub mod buf_ring;
 pub mod lower_layers;
 
 /// Type definition for any [`CommunicationError`] [`Results`](core::result::Result).
@@ -90,6 +91,22 @@ pub trait RxChannel {
     fn recv_with_timeout<T: Timeout>(&mut self, dest: &mut [u8], tmr: &mut T) -> Result<usize>
     where
         T: Timeout;
+
+    /// Drains whatever bytes are immediately available into `dest` and
+    /// returns at once, never blocking and never consulting a [`Timeout`].
+    /// This is the embedded analog of a `MSG_DONTWAIT` read, letting a
+    /// firmware main loop poll this channel alongside other peripherals
+    /// without committing to a blocking `recv_with_timeout` call.
+    ///
+    /// # ERRORS:
+    ///
+    /// - [`CommunicationError::RecvError`]
+    ///   - Only returned if a partial frame/message was buffered but can't
+    ///     be completed without blocking, which can happen on the framing
+    ///     or crypto layers. Contains the number of bytes read so far. A
+    ///     plain byte-stream channel with nothing buffered returns `Ok(0)`,
+    ///     never an error.
+    fn try_recv(&mut self, dest: &mut [u8]) -> Result<usize>;
 }
 
 /// A channel to receive data from which supports reading until a line delimiter.
@@ -144,6 +161,26 @@ pub trait TxChannel {
     ///   - This can occur if some internal error happens. This should only occur if something is wrong
     ///     with the implementation.
     fn send(&mut self, src: &mut [u8]) -> Result<()>;
+
+    /// Like [`Self::send`], but bounded by `tmr` for the entire send instead of
+    /// blocking indefinitely while the destination drains (eg. a stalled
+    /// peer or full hardware FIFO). Returns the number of bytes actually
+    /// written if `tmr`'s timeout expires before the whole buffer drains.
+    ///
+    /// # ERRORS:
+    ///
+    /// - [`CommunicationError::SendError`]
+    ///   - This could occur if any implementation-based error occurs while sending data,
+    ///     unrelated to the timeout.
+    fn send_with_timeout<T: Timeout>(&mut self, src: &mut [u8], tmr: &mut T) -> Result<usize>;
+
+    /// Like [`Self::send_with_timeout`], but `tmr` is reset after every byte
+    /// written instead of only bounding the whole operation.
+    ///
+    /// # ERRORS:
+    ///
+    /// See [`Self::send_with_timeout`].
+    fn send_with_data_timeout<T: Timeout>(&mut self, src: &mut [u8], tmr: &mut T) -> Result<usize>;
 }
 
 /// The possible errors that can occur while sending or receiving data through an [`RxChannel`] or a
@@ -163,6 +200,136 @@ pub enum CommunicationError {
     InternalError,
 }
 
+/// Explicit byte order for the multi-byte [`ProtoRead`]/[`ProtoWrite`] methods.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Endian {
+    /// Least-significant byte first.
+    Little,
+    /// Most-significant byte first.
+    Big,
+}
+
+/// Declarative macro generating one `read_*` method per integer type, to
+/// avoid writing out the same "fill a fixed-size buffer, then decode it
+/// with the requested endianness" boilerplate for every width.
+macro_rules! read_int_method {
+    ($name:ident, $int:ty) => {
+        /// Reads
+        #[doc = concat!("a [`", stringify!($int), "`]")]
+        /// from the channel in the given byte order. Resets `tmr`'s data
+        /// timeout on each byte received, same as
+        /// [`recv_with_data_timeout`](RxChannel::recv_with_data_timeout).
+        fn $name<T: Timeout>(&mut self, endian: Endian, tmr: &mut T) -> Result<$int> {
+            let mut buf = [0u8; core::mem::size_of::<$int>()];
+            self.recv_with_data_timeout(&mut buf, tmr)?;
+            Ok(match endian {
+                Endian::Little => <$int>::from_le_bytes(buf),
+                Endian::Big => <$int>::from_be_bytes(buf),
+            })
+        }
+    };
+}
+
+/// Layers typed, length-delimited message fields on top of [`RxChannel`],
+/// so application code exchanging framed messages with integer fields and
+/// length-delimited blobs doesn't have to hand-roll byte packing on every
+/// channel. Blanket-implemented for every [`RxChannel`].
+pub trait ProtoRead: RxChannel {
+    /// Reads a single byte from the channel.
+    fn read_u8<T: Timeout>(&mut self, tmr: &mut T) -> Result<u8> {
+        let mut buf = [0u8; 1];
+        self.recv_with_data_timeout(&mut buf, tmr)?;
+        Ok(buf[0])
+    }
+
+    /// Reads a single signed byte from the channel.
+    fn read_i8<T: Timeout>(&mut self, tmr: &mut T) -> Result<i8> {
+        Ok(self.read_u8(tmr)? as i8)
+    }
+
+    read_int_method!(read_u16, u16);
+    read_int_method!(read_u32, u32);
+    read_int_method!(read_u64, u64);
+    read_int_method!(read_i16, i16);
+    read_int_method!(read_i32, i32);
+    read_int_method!(read_i64, i64);
+
+    /// Reads a `u32` length prefix (in `endian` byte order) followed by
+    /// that many bytes into `dest`, returning the number of bytes written.
+    /// `tmr`'s data timeout is reset on each byte received, including the
+    /// length prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommunicationError::RecvError`] with the number of bytes
+    /// already read (ie. the 4-byte length prefix) if `dest` isn't big
+    /// enough to hold the decoded length.
+    fn read_bytes<T: Timeout>(
+        &mut self,
+        endian: Endian,
+        dest: &mut [u8],
+        tmr: &mut T,
+    ) -> Result<usize> {
+        let len = self.read_u32(endian, tmr)? as usize;
+        if len > dest.len() {
+            return Err(CommunicationError::RecvError(core::mem::size_of::<u32>()));
+        }
+
+        self.recv_with_data_timeout(&mut dest[..len], tmr)?;
+        Ok(len)
+    }
+}
+
+impl<T: RxChannel> ProtoRead for T {}
+
+/// Declarative macro generating one `write_*` method per integer type,
+/// mirroring [`read_int_method!`].
+macro_rules! write_int_method {
+    ($name:ident, $int:ty) => {
+        /// Writes
+        #[doc = concat!("a [`", stringify!($int), "`]")]
+        /// to the channel in the given byte order.
+        fn $name(&mut self, endian: Endian, value: $int) -> Result<()> {
+            let mut buf = match endian {
+                Endian::Little => value.to_le_bytes(),
+                Endian::Big => value.to_be_bytes(),
+            };
+            self.send(&mut buf)
+        }
+    };
+}
+
+/// Layers typed, length-delimited message fields on top of [`TxChannel`],
+/// mirroring [`ProtoRead`]. Blanket-implemented for every [`TxChannel`].
+pub trait ProtoWrite: TxChannel {
+    /// Writes a single byte to the channel.
+    fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.send(&mut [value])
+    }
+
+    /// Writes a single signed byte to the channel.
+    fn write_i8(&mut self, value: i8) -> Result<()> {
+        self.write_u8(value as u8)
+    }
+
+    write_int_method!(write_u16, u16);
+    write_int_method!(write_u32, u32);
+    write_int_method!(write_u64, u64);
+    write_int_method!(write_i16, i16);
+    write_int_method!(write_i32, i32);
+    write_int_method!(write_i64, i64);
+
+    /// Writes `data`'s length as a `u32` prefix (in `endian` byte order),
+    /// then `data` itself. `data` is taken `&mut` since [`TxChannel::send`]
+    /// may transform it in place (eg. encrypt it).
+    fn write_bytes(&mut self, endian: Endian, data: &mut [u8]) -> Result<()> {
+        self.write_u32(endian, data.len() as u32)?;
+        self.send(data)
+    }
+}
+
+impl<T: TxChannel> ProtoWrite for T {}
+
 /// Specifies what is counted as the end of a line for the RxChannel::recv_line_* methods
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum LineEnding {
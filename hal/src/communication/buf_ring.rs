@@ -0,0 +1,173 @@
+//! Provided-buffer ring pool for zero-copy receives. See [`BufRing`] and
+//! [`PooledRxChannel`] for details.
+
+use core::cell::{Cell, UnsafeCell};
+use core::ops::Deref;
+
+use super::{CommunicationError, Result, RxChannel, Timeout};
+
+/// A pool of `N` fixed-size, caller-registered buffers arranged as a ring,
+/// so a [`PooledRxChannel`] can fill the next free buffer directly instead
+/// of copying into a per-call stack buffer. This matters for large
+/// transfers (eg. the `INTERJECTION`-sized payloads exercised in
+/// `run_uart_test`) where a stack buffer big enough to hold the whole
+/// transfer isn't feasible on a constrained MCU. Build one with [`Builder`].
+pub struct BufRing<const N: usize, const BUF_SIZE: usize> {
+    buffers: [UnsafeCell<[u8; BUF_SIZE]>; N],
+    /// Bit `i` set means buffer `i` is free. `N` must be `<= 32`.
+    free_mask: Cell<u32>,
+}
+
+impl<const N: usize, const BUF_SIZE: usize> BufRing<N, BUF_SIZE> {
+    /// Checks out the next free buffer, returning its id, or `None` if the
+    /// pool is exhausted.
+    fn checkout(&self) -> Option<usize> {
+        let mask = self.free_mask.get();
+        if mask == 0 {
+            return None;
+        }
+
+        let id = mask.trailing_zeros() as usize;
+        self.free_mask.set(mask & !(1 << id));
+        Some(id)
+    }
+
+    /// Returns buffer `id` to the free list. Called from [`BufX`]'s `Drop`.
+    fn release(&self, id: usize) {
+        self.free_mask.set(self.free_mask.get() | (1 << id));
+    }
+}
+
+/// Builds a [`BufRing`] of `N` buffers, each `BUF_SIZE` bytes.
+pub struct Builder<const N: usize, const BUF_SIZE: usize>;
+
+impl<const N: usize, const BUF_SIZE: usize> Default for Builder<N, BUF_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const BUF_SIZE: usize> Builder<N, BUF_SIZE> {
+    /// Creates a new builder for a ring of `N` buffers, each `BUF_SIZE`
+    /// bytes. `N` must be `<= 32`, the width of the internal free-list
+    /// bitmask; [`Self::build`] panics otherwise.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Allocates the backing storage (`N * BUF_SIZE` bytes) and returns the
+    /// ready-to-use [`BufRing`], with every buffer initially free.
+    pub fn build(self) -> BufRing<N, BUF_SIZE> {
+        assert!(N <= 32, "BufRing only supports up to 32 buffers");
+
+        BufRing {
+            buffers: core::array::from_fn(|_| UnsafeCell::new([0u8; BUF_SIZE])),
+            free_mask: Cell::new(if N == 32 { u32::MAX } else { (1u32 << N) - 1 }),
+        }
+    }
+}
+
+/// Guard wrapping a [`BufRing`] buffer filled by
+/// [`PooledRxChannel::recv_buf`]. Dereferences to the filled bytes and
+/// returns the buffer to the ring once dropped.
+pub struct BufX<'ring, const N: usize, const BUF_SIZE: usize> {
+    ring: &'ring BufRing<N, BUF_SIZE>,
+    id: usize,
+    len: usize,
+}
+
+impl<const N: usize, const BUF_SIZE: usize> BufX<'_, N, BUF_SIZE> {
+    /// The number of bytes actually filled in this buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this buffer has no filled bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize, const BUF_SIZE: usize> Deref for BufX<'_, N, BUF_SIZE> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `id` is exclusively checked out to this `BufX` from
+        // `BufRing::checkout` until `Drop` returns it to the free list, so
+        // no other `BufX` can alias this slot while this reference is live.
+        let buf: &[u8; BUF_SIZE] = unsafe { &*self.ring.buffers[self.id].get() };
+        &buf[..self.len]
+    }
+}
+
+impl<const N: usize, const BUF_SIZE: usize> Drop for BufX<'_, N, BUF_SIZE> {
+    fn drop(&mut self) {
+        self.ring.release(self.id);
+    }
+}
+
+/// Receives directly into a pooled buffer instead of a caller-supplied
+/// slice, eliminating the per-call copy (and the large stack buffer it
+/// would otherwise require) for channels wrapped in a [`BufRing`].
+pub trait PooledRxChannel<'ring, const N: usize, const BUF_SIZE: usize> {
+    /// Checks out the next free buffer from the ring and fills it from the
+    /// underlying channel, returning a [`BufX`] guard over the filled
+    /// bytes. The buffer is returned to the ring once the guard drops.
+    ///
+    /// # ERRORS:
+    ///
+    /// - [`CommunicationError::InternalError`] - The ring has no free
+    ///   buffers left (all are checked out by live [`BufX`] guards).
+    /// - See [`RxChannel::recv_with_data_timeout`] for the errors that can
+    ///   occur while filling the checked-out buffer.
+    fn recv_buf<T: Timeout>(&mut self, tmr: &mut T) -> Result<BufX<'ring, N, BUF_SIZE>>;
+}
+
+/// Pairs an [`RxChannel`] with a [`BufRing`] to implement [`PooledRxChannel`].
+pub struct Pooled<'ring, C, const N: usize, const BUF_SIZE: usize> {
+    channel: C,
+    ring: &'ring BufRing<N, BUF_SIZE>,
+}
+
+impl<'ring, C, const N: usize, const BUF_SIZE: usize> Pooled<'ring, C, N, BUF_SIZE> {
+    /// Wraps `channel` with `ring`, borrowed for as long as this [`Pooled`]
+    /// (and any [`BufX`] it hands out) is alive.
+    pub fn new(channel: C, ring: &'ring BufRing<N, BUF_SIZE>) -> Self {
+        Self { channel, ring }
+    }
+
+    /// Releases the underlying channel.
+    pub fn release(self) -> C {
+        self.channel
+    }
+}
+
+impl<'ring, C: RxChannel, const N: usize, const BUF_SIZE: usize> PooledRxChannel<'ring, N, BUF_SIZE>
+    for Pooled<'ring, C, N, BUF_SIZE>
+{
+    fn recv_buf<T: Timeout>(&mut self, tmr: &mut T) -> Result<BufX<'ring, N, BUF_SIZE>> {
+        let id = self
+            .ring
+            .checkout()
+            .ok_or(CommunicationError::InternalError)?;
+
+        // SAFETY: `id` was just exclusively checked out above and isn't
+        // released until the `BufX` returned below drops, so no other
+        // `BufX` can alias it while we hold this `&mut` reference.
+        let buf: &mut [u8; BUF_SIZE] = unsafe { &mut *self.ring.buffers[id].get() };
+
+        let len = match self.channel.recv_with_data_timeout(buf, tmr) {
+            Ok(len) => len,
+            Err(err) => {
+                self.ring.release(id);
+                return Err(err);
+            }
+        };
+
+        Ok(BufX {
+            ring: self.ring,
+            id,
+            len,
+        })
+    }
+}
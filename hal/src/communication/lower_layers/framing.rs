@@ -4,10 +4,19 @@
 //! framing while [`TxChannels`](TxChannel) do not necessarily require any concept of framing. No framing
 //! protocols are provided in this module.
 //!
+//! [`Frame::append_crc`]/[`verify_crc_trailer`] add an optional CRC-32
+//! trailer to a frame, for links that skip the encryption and integrity
+//! layer and would otherwise have no way to detect a truncated or garbled
+//! transfer.
+//!
 //! See the documentation for [`communication`](crate::communication) for a description of full communication
 //! stack.
 
-use crate::communication::{CommunicationError, TxChannel};
+use crate::communication::{CommunicationError, Timeout, TxChannel};
+use crate::peripherals::crc::{CrcAlgorithm, CrcCalculator, CrcDataU32};
+
+/// Number of bytes [`Frame::append_crc`] appends: a CRC-32/ISO-HDLC trailer.
+pub const CRC_TRAILER_LEN: usize = 4;
 
 /// A trait to be implemented by all transmission channels in framing protocol implementations.
 /// This contains one function to specify the slices that go into the frame to be transmitted.
@@ -31,6 +40,33 @@ impl<T: FramedTxChannel> TxChannel for T {
     fn send(&mut self, src: &mut [u8]) -> Result<(), CommunicationError> {
         self.frame::<1>(|| Frame::new().append(src))
     }
+
+    /// Framed sends go out as one atomic group of writes (see [`Self::send`]),
+    /// so there's no meaningful partial drain to bound with a timeout; this
+    /// only checks `tmr` once up front, returning `Ok(0)` if it has already
+    /// expired, and otherwise forwards to `send`.
+    fn send_with_timeout<U: Timeout>(
+        &mut self,
+        src: &mut [u8],
+        tmr: &mut U,
+    ) -> Result<usize, CommunicationError> {
+        if tmr.poll() {
+            return Ok(0);
+        }
+
+        self.send(src)?;
+        Ok(src.len())
+    }
+
+    /// See [`Self::send_with_timeout`]; there's no per-byte drain to reset
+    /// `tmr` on, so this is identical to [`Self::send_with_timeout`].
+    fn send_with_data_timeout<U: Timeout>(
+        &mut self,
+        src: &mut [u8],
+        tmr: &mut U,
+    ) -> Result<usize, CommunicationError> {
+        self.send_with_timeout(src, tmr)
+    }
 }
 
 /// A struct that keeps track of slices of u8's to write as one frame
@@ -98,6 +134,67 @@ impl<'a, const FRAME_CT: usize> Frame<'a, FRAME_CT> {
             current_byte_index: 0,
         }
     }
+
+    /// Appends a CRC-32/ISO-HDLC trailer computed over every slice already
+    /// in the frame, written into `crc_buf`. Non-encrypted links carry no
+    /// integrity check of their own, so a [`FramedTxChannel`] built from a
+    /// frame closure that ends with this call lets the receiving side
+    /// detect a truncated or garbled transfer with [`verify_crc_trailer`]
+    /// instead of relying on the crypto layer's AEAD tag.
+    ///
+    /// # ERRORS:
+    ///
+    /// - [`CommunicationError::InternalError`] - Occurs when there's no
+    ///   more space in the frame for the trailer slice.
+    pub fn append_crc(
+        self,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        crc_buf: &'a mut [u8; CRC_TRAILER_LEN],
+    ) -> Result<Self, CommunicationError> {
+        crc.configure(CrcAlgorithm::CRC32_ISO_HDLC);
+        for slice in &self.frame_components {
+            crc.update(slice);
+        }
+        *crc_buf = crc.finalize().to_le_bytes();
+        self.append(crc_buf)
+    }
+}
+
+/// Verifies and strips the CRC-32 trailer [`Frame::append_crc`] appends.
+/// `buf` should hold the full received frame, payload followed by the
+/// trailer; returns the payload length with the trailer removed.
+///
+/// # ERRORS:
+///
+/// - [`CommunicationError::RecvError`] - `buf` is shorter than
+///   [`CRC_TRAILER_LEN`], or the trailer doesn't match the CRC-32 computed
+///   over the payload, meaning the transfer was truncated or corrupted in
+///   transit. Contains `buf.len()` in the first case and the payload length
+///   in the second.
+pub fn verify_crc_trailer(
+    buf: &[u8],
+    crc: &mut CrcCalculator<CrcDataU32>,
+) -> Result<usize, CommunicationError> {
+    if buf.len() < CRC_TRAILER_LEN {
+        return Err(CommunicationError::RecvError(buf.len()));
+    }
+
+    let payload_len = buf.len() - CRC_TRAILER_LEN;
+    let (payload, trailer) = buf.split_at(payload_len);
+
+    crc.configure(CrcAlgorithm::CRC32_ISO_HDLC);
+    let expected = crc.checksum(payload);
+    let received = u32::from_le_bytes(
+        trailer
+            .try_into()
+            .expect("trailer is exactly CRC_TRAILER_LEN bytes"),
+    );
+
+    if expected != received {
+        return Err(CommunicationError::RecvError(payload_len));
+    }
+
+    Ok(payload_len)
 }
 
 /// An iterator over the bytes of a frame, not the slices
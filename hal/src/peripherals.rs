@@ -43,20 +43,23 @@ use core::cell::{BorrowMutError, RefCell, RefMut};
 use core::ops::{Deref, DerefMut};
 use embedded_hal::i2c::SevenBitAddress;
 
+use crate::peripherals::adc::Adc;
+#[cfg(feature = "flc-ram")]
 #[cfg(feature = "flc-ram")]
 use crate::peripherals::flash_controller::FlashController;
-use crate::peripherals::i2c::{BusSpeed, I2CMaster, I2CSlave};
+use crate::peripherals::i2c::{BusSpeed, FifoEmptyMode, I2CMaster, I2CSlave, SlaveAddress};
 use crate::peripherals::oscillator::SystemClock;
+use crate::peripherals::spi::{ChipSelect, SpiConfig, SpiMaster};
 use max78000::*;
 use rand_chacha::ChaCha20Rng;
 
-use self::gpio::{new_gpio0, new_gpio1, new_gpio2, Gpio0, Gpio1, Gpio2};
+use self::gpio::{new_gpio0, new_gpio1, new_gpio2, Gpio0, Gpio1, Gpio2, PinOperatingMode};
 use self::oscillator::{private, Oscillator};
-use self::power::{PowerControl, ToggleableModule};
+use self::power::{Power, PowerControl, ToggleableModule};
 use self::random::{CsprngInitArgs, EntropyGatherer};
-use self::timer::{Clock, Prescaler};
+use self::timer::{AnyTimer, Clock, Prescaler};
 use self::trng::Trng;
-use self::uart::{Uart0, UartBuilder, UartBuilderError};
+use self::uart::{Uart0, Uart1, Uart2, Uart3, UartBuilder, UartBuilderError};
 
 pub use rand_chacha;
 
@@ -73,22 +76,27 @@ pub mod aes;
 pub mod bit_banding;
 pub mod bootloader;
 pub mod crc;
+pub mod dma;
 pub mod ecc;
+pub mod eeprom24x;
+pub mod flash_config;
 pub mod flash_controller;
 pub mod i2c;
+pub mod i2c_bitbang;
 pub mod oscillator;
 pub mod power;
 pub mod random;
 pub mod raw;
 pub mod rtc;
+pub mod signing;
+pub mod spi;
 pub mod synchronization;
 pub mod timer;
 pub mod trng;
+pub mod update;
 
 /// The peripherals that are completely unused by the [`PeripheralManager`].
 pub struct RemainingPeripherals {
-    /// ADC
-    pub adc: ADC,
     /// AES
     pub aes: AES,
     /// AESKEYS
@@ -135,20 +143,10 @@ pub struct RemainingPeripherals {
     pub simo: SIMO,
     /// SIR
     pub sir: SIR,
-    /// SPI0
-    pub spi0: SPI0,
-    /// SPI1
-    pub spi1: SPI1,
     /// TMR4
     pub tmr4: TMR4,
     /// TMR5
     pub tmr5: TMR5,
-    /// UART1
-    pub uart1: UART1,
-    /// UART2
-    pub uart2: UART2,
-    /// UART3
-    pub uart3: UART3,
     /// WDT
     pub wdt: WDT,
     /// WDT1
@@ -184,7 +182,13 @@ pub struct PeripheralsToConsume {
     tmr2: TMR2,
     tmr3: TMR3,
     i2c1: I2C1,
+    spi0: SPI0,
+    spi1: SPI1,
+    adc: ADC,
     uart: UART,
+    uart1: UART1,
+    uart2: UART2,
+    uart3: UART3,
 }
 
 /// Extension trait for splitting peripherals for the [`PeripheralManager`].
@@ -222,7 +226,13 @@ impl SplittablePeripheral for Peripherals {
             tmr2: self.TMR2,
             tmr3: self.TMR3,
             i2c1: self.I2C1,
+            spi0: self.SPI0,
+            spi1: self.SPI1,
+            adc: self.ADC,
             uart: self.UART,
+            uart1: self.UART1,
+            uart2: self.UART2,
+            uart3: self.UART3,
         };
 
         let to_borrow = PeripheralsToBorrow {
@@ -234,7 +244,6 @@ impl SplittablePeripheral for Peripherals {
         };
 
         let remaining = RemainingPeripherals {
-            adc: self.ADC,
             aes: self.AES,
             aeskeys: self.AESKEYS,
             cameraif: self.CAMERAIF,
@@ -258,13 +267,8 @@ impl SplittablePeripheral for Peripherals {
             sema: self.SEMA,
             simo: self.SIMO,
             sir: self.SIR,
-            spi0: self.SPI0,
-            spi1: self.SPI1,
             tmr4: self.TMR4,
             tmr5: self.TMR5,
-            uart1: self.UART1,
-            uart2: self.UART2,
-            uart3: self.UART3,
             wdt: self.WDT,
             wdt1: self.WDT1,
             wut: self.WUT,
@@ -318,12 +322,15 @@ pub struct PeripheralManagerBuilder<'a, T: Oscillator + private::Oscillator, F:
 
 macro_rules! timer_field {
     ($self:ident, $tmr_field:ident, $cfg_field:ident) => {
-        RefCell::new(Clock::new(
-            $self.consumed_periphs.$tmr_field,
-            &$self.borrowed_periphs.gcr,
-            $self.$cfg_field.0,
-            $self.$cfg_field.1,
-        ))
+        RefCell::new(
+            Clock::new(
+                $self.consumed_periphs.$tmr_field,
+                &$self.borrowed_periphs.gcr,
+                $self.$cfg_field.0,
+                $self.$cfg_field.1,
+            )
+            .erase(),
+        )
     };
 }
 
@@ -402,30 +409,21 @@ impl<'a, T: Oscillator + private::Oscillator, F: FnMut(&mut [u8])>
         //       For now, they're eagerly intialized.
         let power_ctrl =
             PowerControl::new(&self.borrowed_periphs.gcr, &self.borrowed_periphs.lpgcr);
+        let power = Power::new(&self.borrowed_periphs.gcr);
 
         // Timers are eagerly initialized because they are configured upon creation of a Clock.
-        power_ctrl.enable_peripheral(ToggleableModule::TMR0);
-        power_ctrl.enable_peripheral(ToggleableModule::TMR1);
-        power_ctrl.enable_peripheral(ToggleableModule::TMR2);
-        power_ctrl.enable_peripheral(ToggleableModule::TMR3);
-
-        power_ctrl.reset_toggleable(ToggleableModule::TMR0);
-        power_ctrl.reset_toggleable(ToggleableModule::TMR1);
-        power_ctrl.reset_toggleable(ToggleableModule::TMR2);
-        power_ctrl.reset_toggleable(ToggleableModule::TMR3);
+        power_ctrl.enable_and_reset(ToggleableModule::TMR0);
+        power_ctrl.enable_and_reset(ToggleableModule::TMR1);
+        power_ctrl.enable_and_reset(ToggleableModule::TMR2);
+        power_ctrl.enable_and_reset(ToggleableModule::TMR3);
 
         // GPIO ports are eagerly initialized because they do not use `PeripheralHandle`s.
-        power_ctrl.enable_peripheral(ToggleableModule::GPIO0);
-        power_ctrl.enable_peripheral(ToggleableModule::GPIO1);
-        power_ctrl.enable_peripheral(ToggleableModule::GPIO2);
-
-        power_ctrl.reset_toggleable(ToggleableModule::GPIO0);
-        power_ctrl.reset_toggleable(ToggleableModule::GPIO1);
-        power_ctrl.reset_toggleable(ToggleableModule::GPIO2);
+        power_ctrl.enable_and_reset(ToggleableModule::GPIO0);
+        power_ctrl.enable_and_reset(ToggleableModule::GPIO1);
+        power_ctrl.enable_and_reset(ToggleableModule::GPIO2);
 
         // TRNG needs to be eagerly initialized to initialize the CSPRNG.
-        power_ctrl.enable_peripheral(ToggleableModule::TRNG);
-        power_ctrl.reset_toggleable(ToggleableModule::TRNG);
+        power_ctrl.enable_and_reset(ToggleableModule::TRNG);
 
         let trng = Trng::new(self.consumed_periphs.trng);
         let csprng_timer_config = (timer::Oscillator::IBRO, Prescaler::_4096);
@@ -441,6 +439,7 @@ impl<'a, T: Oscillator + private::Oscillator, F: FnMut(&mut [u8])>
             trng: &trng,
             csprng_timer: &timer_0,
             get_rng_static_secret: self.get_rng_static_secret,
+            reseed_interval: Default::default(),
         });
 
         timer_0
@@ -455,12 +454,19 @@ impl<'a, T: Oscillator + private::Oscillator, F: FnMut(&mut [u8])>
                 &self.borrowed_periphs.icc0,
                 &self.borrowed_periphs.gcr,
             )),
-            system_clock: RefCell::new(SystemClock::new(
-                &T::new(self.sysclk_osc_freq, self.sysclk_osc_div),
-                self.borrowed_periphs.gcr.clkctrl(),
-                self.borrowed_periphs.trimsir.inro(),
-            )),
-            timer_0: RefCell::new(timer_0),
+            system_clock: RefCell::new(
+                SystemClock::new(
+                    &T::new(self.sysclk_osc_freq, self.sysclk_osc_div),
+                    self.borrowed_periphs.gcr.clkctrl(),
+                    self.borrowed_periphs.trimsir.inro(),
+                    &power,
+                )
+                .expect(
+                    "default sysclk configuration must fit within the active VCORE range",
+                ),
+            ),
+            power,
+            timer_0: RefCell::new(timer_0.erase()),
             timer_1: timer_field!(self, tmr1, timer_1_cfg),
             timer_2: timer_field!(self, tmr2, timer_2_cfg),
             timer_3: timer_field!(self, tmr3, timer_3_cfg),
@@ -469,7 +475,13 @@ impl<'a, T: Oscillator + private::Oscillator, F: FnMut(&mut [u8])>
             gpio2: new_gpio2(self.consumed_periphs.gpio2),
             trng: RefCell::new(trng),
             i2c1_reg: RefCell::new(self.consumed_periphs.i2c1),
+            spi0_reg: RefCell::new(self.consumed_periphs.spi0),
+            spi1_reg: RefCell::new(self.consumed_periphs.spi1),
+            adc_reg: RefCell::new(self.consumed_periphs.adc),
             uart: RefCell::new(self.consumed_periphs.uart),
+            uart1: RefCell::new(self.consumed_periphs.uart1),
+            uart2: RefCell::new(self.consumed_periphs.uart2),
+            uart3: RefCell::new(self.consumed_periphs.uart3),
             csprng: RefCell::new(initialized_csprng),
         }
     }
@@ -500,8 +512,7 @@ macro_rules! enable_rst_periph_fn {
         /// resetting it. Otherwise, returns [`BorrowMutError`].
         pub fn $fn_name(&self) -> Result<PeripheralHandle<$p_type>, BorrowMutError> {
             let handle = PeripheralHandle::new(&self.$field_name)?;
-            self.power_ctrl.enable_peripheral($variant);
-            self.power_ctrl.reset_toggleable($variant);
+            self.power_ctrl.enable_and_reset($variant);
             Ok(handle)
         }
     };
@@ -512,19 +523,26 @@ macro_rules! enable_rst_periph_fn {
 /// The methods inside here can be used to interact with the board peripherals.
 pub struct PeripheralManager<'a> {
     power_ctrl: PowerControl<'a, 'a>,
+    power: Power<'a>,
     #[cfg(feature = "flc-ram")]
     flash_controller: RefCell<FlashController<'a, 'a>>,
     system_clock: RefCell<SystemClock<'a, 'a>>,
     gpio0: Gpio0,
     gpio1: Gpio1,
     gpio2: Gpio2,
-    timer_0: RefCell<Clock<'a, TMR>>,
-    timer_1: RefCell<Clock<'a, TMR1>>,
-    timer_2: RefCell<Clock<'a, TMR2>>,
-    timer_3: RefCell<Clock<'a, TMR3>>,
+    timer_0: RefCell<Clock<'a, AnyTimer>>,
+    timer_1: RefCell<Clock<'a, AnyTimer>>,
+    timer_2: RefCell<Clock<'a, AnyTimer>>,
+    timer_3: RefCell<Clock<'a, AnyTimer>>,
     trng: RefCell<Trng>,
     uart: RefCell<UART>,
+    uart1: RefCell<UART1>,
+    uart2: RefCell<UART2>,
+    uart3: RefCell<UART3>,
     i2c1_reg: RefCell<I2C1>,
+    spi0_reg: RefCell<SPI0>,
+    spi1_reg: RefCell<SPI1>,
+    adc_reg: RefCell<ADC>,
     csprng: RefCell<ChaCha20Rng>,
 }
 
@@ -533,12 +551,31 @@ impl<'a> PeripheralManager<'a> {
     no_enable_rst_periph_fn!(flash_controller, FlashController<'a, 'a>, flash_controller);
     no_enable_rst_periph_fn!(system_clock, SystemClock<'a, 'a>, system_clock);
 
+    /// Gets the handle for querying/raising the VCORE range that gates
+    /// [`SystemClock`]'s maximum SYS_CLK frequency. See [`power::Power`].
+    pub fn power(&self) -> &Power<'a> {
+        &self.power
+    }
+
     // Timers CANNOT be enabled and reset again after creation because
     // Clock holds state for it
-    no_enable_rst_periph_fn!(timer_0, Clock<'a, TMR>, timer_0);
-    no_enable_rst_periph_fn!(timer_1, Clock<'a, TMR1>, timer_1);
-    no_enable_rst_periph_fn!(timer_2, Clock<'a, TMR2>, timer_2);
-    no_enable_rst_periph_fn!(timer_3, Clock<'a, TMR3>, timer_3);
+    no_enable_rst_periph_fn!(timer_0, Clock<'a, AnyTimer>, timer_0);
+    no_enable_rst_periph_fn!(timer_1, Clock<'a, AnyTimer>, timer_1);
+    no_enable_rst_periph_fn!(timer_2, Clock<'a, AnyTimer>, timer_2);
+    no_enable_rst_periph_fn!(timer_3, Clock<'a, AnyTimer>, timer_3);
+
+    /// Gets all four timer peripherals, type-erased over which concrete
+    /// `TMR`/`TMR1`/`TMR2`/`TMR3` register block backs each one, indexed by
+    /// timer number. Useful for code that wants to store timers in an array
+    /// or otherwise treat them generically instead of naming one specifically.
+    pub fn timers(&'a self) -> [Result<PeripheralHandle<'a, Clock<'a, AnyTimer>>, BorrowMutError>; 4] {
+        [
+            PeripheralHandle::new(&self.timer_0),
+            PeripheralHandle::new(&self.timer_1),
+            PeripheralHandle::new(&self.timer_2),
+            PeripheralHandle::new(&self.timer_3),
+        ]
+    }
 
     no_enable_rst_periph_fn_no_handle!(gpio0, Gpio0, gpio0);
     no_enable_rst_periph_fn_no_handle!(gpio1, Gpio1, gpio1);
@@ -555,8 +592,7 @@ impl<'a> PeripheralManager<'a> {
         bus_speed: BusSpeed,
         target_address: SevenBitAddress,
     ) -> Result<I2CMaster<I2C1>, BorrowMutError> {
-        self.power_ctrl.enable_peripheral(ToggleableModule::I2C1);
-        self.power_ctrl.reset_toggleable(ToggleableModule::I2C1);
+        self.power_ctrl.enable_and_reset(ToggleableModule::I2C1);
 
         let scl_handle = self.gpio0.get_pin_handle(16).unwrap();
         let sda_handle = self.gpio0.get_pin_handle(17).unwrap();
@@ -577,13 +613,19 @@ impl<'a> PeripheralManager<'a> {
 
     /// Attempt to instantiate a new I2C slave instance. Will fail is there already is an existing
     /// instance of either an I2C master or slave.
+    ///
+    /// `address` may be a 7-bit or 10-bit [`SlaveAddress`]. If `respond_to_general_call` is set,
+    /// the slave also matches the reserved general-call address, surfaced through
+    /// [`SlavePollResult::IncomingTransmission`]'s `general_call` flag. `fifo_empty_mode`
+    /// selects whether a TX FIFO underrun stretches the clock or ends the transaction.
     pub fn i2c_slave(
         &self,
         bus_speed: BusSpeed,
-        address: SevenBitAddress,
+        address: SlaveAddress,
+        respond_to_general_call: bool,
+        fifo_empty_mode: FifoEmptyMode,
     ) -> Result<I2CSlave<I2C1>, BorrowMutError> {
-        self.power_ctrl.enable_peripheral(ToggleableModule::I2C1);
-        self.power_ctrl.reset_toggleable(ToggleableModule::I2C1);
+        self.power_ctrl.enable_and_reset(ToggleableModule::I2C1);
 
         let scl_handle = self.gpio0.get_pin_handle(16).unwrap();
         let sda_handle = self.gpio0.get_pin_handle(17).unwrap();
@@ -591,6 +633,8 @@ impl<'a> PeripheralManager<'a> {
         // TODO: replace .unwrap()
         let periph = I2CSlave::new(
             address,
+            respond_to_general_call,
+            fifo_empty_mode,
             bus_speed,
             self.system_clock.try_borrow().unwrap(),
             self.i2c1_reg.try_borrow_mut()?,
@@ -602,12 +646,301 @@ impl<'a> PeripheralManager<'a> {
         Ok(periph)
     }
 
+    /// Attempt to instantiate SPI0 as a bus master. Will fail if SPI0 already has
+    /// an outstanding instance, or if any of its pins are already taken.
+    ///
+    /// `config.chip_select` selects which of SPI0's three SS lines (`Ss0`/`Ss1`/`Ss2`)
+    /// is driven by hardware for the duration of a transaction.
+    pub fn spi0_master(&self, config: SpiConfig) -> Result<SpiMaster<SPI0>, BorrowMutError> {
+        self.power_ctrl.enable_and_reset(ToggleableModule::SPI0);
+
+        let sck_handle = self.gpio0.get_pin_handle(7).unwrap();
+        let mosi_handle = self.gpio0.get_pin_handle(5).unwrap();
+        let miso_handle = self.gpio0.get_pin_handle(6).unwrap();
+        let (cs_pin, cs_alt_function) = match config.chip_select {
+            ChipSelect::Ss0 => (4, PinOperatingMode::AltFunction1),
+            ChipSelect::Ss1 => (11, PinOperatingMode::AltFunction2),
+            ChipSelect::Ss2 => (10, PinOperatingMode::AltFunction2),
+        };
+        let cs_handle = self.gpio0.get_pin_handle(cs_pin).unwrap();
+
+        // TODO: replace .unwrap()
+        let periph = SpiMaster::new(
+            config,
+            self.system_clock.try_borrow().unwrap(),
+            self.spi0_reg.try_borrow_mut()?,
+            sck_handle,
+            mosi_handle,
+            miso_handle,
+            cs_handle,
+            cs_alt_function,
+        )
+        .unwrap();
+
+        Ok(periph)
+    }
+
+    /// Attempt to instantiate SPI1 as a bus master. Will fail if SPI1 already has
+    /// an outstanding instance, or if any of its pins are already taken.
+    ///
+    /// SPI1 only brings out a single hardware SS line, so `config.chip_select`
+    /// must be [`ChipSelect::Ss0`].
+    pub fn spi1_master(&self, config: SpiConfig) -> Result<SpiMaster<SPI1>, BorrowMutError> {
+        self.power_ctrl.enable_and_reset(ToggleableModule::SPI1);
+
+        let sck_handle = self.gpio0.get_pin_handle(23).unwrap();
+        let mosi_handle = self.gpio0.get_pin_handle(21).unwrap();
+        let miso_handle = self.gpio0.get_pin_handle(22).unwrap();
+        let cs_handle = self.gpio0.get_pin_handle(20).unwrap();
+
+        // TODO: replace .unwrap()
+        let periph = SpiMaster::new(
+            config,
+            self.system_clock.try_borrow().unwrap(),
+            self.spi1_reg.try_borrow_mut()?,
+            sck_handle,
+            mosi_handle,
+            miso_handle,
+            cs_handle,
+            PinOperatingMode::AltFunction1,
+        )
+        .unwrap();
+
+        Ok(periph)
+    }
+
+    /// Attempt to instantiate the ADC. Will fail if there is already an outstanding
+    /// instance. Build [`adc::Channel`]s to convert from GPIO2's `AIN0..AIN7` pins
+    /// (via [`adc::Channel::new_pin`] with a handle from [`Self::gpio2`]) or from an
+    /// internal source such as the temperature sensor (via
+    /// [`adc::Channel::new_temp_sensor`]).
+    pub fn adc(&self) -> Result<Adc, BorrowMutError> {
+        self.power_ctrl.enable_and_reset(ToggleableModule::ADC);
+        Ok(Adc::new(self.adc_reg.try_borrow_mut()?))
+    }
+
     enable_rst_periph_fn!(uart, UART, uart, ToggleableModule::UART0);
+    enable_rst_periph_fn!(uart1, UART1, uart1, ToggleableModule::UART1);
+    enable_rst_periph_fn!(uart2, UART2, uart2, ToggleableModule::UART2);
+    enable_rst_periph_fn!(uart3, UART3, uart3, ToggleableModule::UART3);
 
-    /// Create a [`UartBuilder`] for the UART0
+    /// Create a [`UartBuilder`] for UART0.
     pub fn build_uart(&'a self) -> Result<UartBuilder<'a, Uart0>, UartBuilderError> {
         UartBuilder::new(self)
     }
 
+    /// Create a [`UartBuilder`] for UART1.
+    pub fn build_uart1(&'a self) -> Result<UartBuilder<'a, Uart1>, UartBuilderError> {
+        UartBuilder::new(self)
+    }
+
+    /// Create a [`UartBuilder`] for UART2.
+    pub fn build_uart2(&'a self) -> Result<UartBuilder<'a, Uart2>, UartBuilderError> {
+        UartBuilder::new(self)
+    }
+
+    /// Create a [`UartBuilder`] for UART3, the MAX78000's low-power UART.
+    pub fn build_uart3(&'a self) -> Result<UartBuilder<'a, Uart3>, UartBuilderError> {
+        UartBuilder::new(self)
+    }
+
     no_enable_rst_periph_fn!(csprng, ChaCha20Rng, csprng);
 }
+
+/// Which oscillator [`init`] drives the system clock from, and its
+/// frequency/divider, selected by [`Config::sysclk`]. Mirrors the oscillator
+/// types [`PeripheralManagerBuilder`] is generic over, collapsed into a
+/// single runtime choice so [`Config`] doesn't need a type parameter for it.
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum SysclkSource {
+    /// Drive the system clock from the Internal Primary Oscillator.
+    Ipo(oscillator::IpoFrequency, oscillator::IpoDivider),
+    /// Drive the system clock from the Internal Secondary Oscillator.
+    Iso(oscillator::IsoFrequency, oscillator::IsoDivider),
+    /// Drive the system clock from the Internal Baud Rate Oscillator.
+    Ibro(oscillator::IbroFrequency, oscillator::IbroDivider),
+    /// Drive the system clock from the Internal Nano-Ring Oscillator.
+    #[cfg(feature = "low_frequency")]
+    Inro(oscillator::InroFrequency, oscillator::InroDivider),
+    /// Drive the system clock from the External Real-Time Clock Oscillator.
+    #[cfg(feature = "low_frequency")]
+    Ertco(oscillator::ErtcoFrequency, oscillator::ErtcoDivider),
+}
+
+impl Default for SysclkSource {
+    /// Defaults to the Internal Primary Oscillator at its default frequency
+    /// undivided.
+    fn default() -> Self {
+        SysclkSource::Ipo(oscillator::IpoFrequency::default(), oscillator::IpoDivider::_1)
+    }
+}
+
+/// No-op default for [`Config::get_rng_static_secret`]: leaves the CSPRNG's
+/// static secret as all zeroes. Callers that need real entropy here should
+/// set the field explicitly rather than rely on [`Config::default`].
+fn no_static_secret(_buf: &mut [u8]) {}
+
+/// Configuration consumed by [`init`] to build a [`PeripheralManager`] and
+/// its [`Clocks`] in one call, collecting what would otherwise be several
+/// [`PeripheralManagerBuilder`] calls into a single value with a [`Default`]
+/// impl. Prefer [`PeripheralManagerBuilder`] directly for anything [`init`]
+/// doesn't expose, e.g. reusing a [`PeripheralsToBorrow`] already split out
+/// by hand.
+#[non_exhaustive]
+pub struct Config<F: FnMut(&mut [u8]) = fn(&mut [u8])> {
+    /// Oscillator driving the system clock, and its frequency/divider.
+    pub sysclk: SysclkSource,
+    /// Oscillator and prescaler for timer 0.
+    pub timer_0: (timer::Oscillator, Prescaler),
+    /// Oscillator and prescaler for timer 1.
+    pub timer_1: (timer::Oscillator, Prescaler),
+    /// Oscillator and prescaler for timer 2.
+    pub timer_2: (timer::Oscillator, Prescaler),
+    /// Oscillator and prescaler for timer 3.
+    pub timer_3: (timer::Oscillator, Prescaler),
+    /// Called once to fill in the CSPRNG's static secret; see
+    /// [`PeripheralManagerBuilder::new`].
+    pub get_rng_static_secret: F,
+}
+
+impl Default for Config<fn(&mut [u8])> {
+    fn default() -> Self {
+        Config {
+            sysclk: SysclkSource::default(),
+            timer_0: (timer::Oscillator::IBRO, Prescaler::_1),
+            timer_1: (timer::Oscillator::IBRO, Prescaler::_1),
+            timer_2: (timer::Oscillator::IBRO, Prescaler::_1),
+            timer_3: (timer::Oscillator::IBRO, Prescaler::_1),
+            get_rng_static_secret: no_static_secret,
+        }
+    }
+}
+
+/// A frozen snapshot of the system clock frequency/divider [`init`] settled
+/// on, handed out alongside the [`PeripheralManager`] it configured so
+/// downstream drivers have a cheap, `Copy` token to read actual frequencies
+/// from instead of borrowing [`PeripheralManager::system_clock`].
+#[derive(Debug, Clone, Copy)]
+pub struct Clocks {
+    sysclk_freq: u32,
+    sysclk_div: u8,
+}
+
+impl Clocks {
+    /// The system clock (SYS_OSC) frequency in hertz, after its divider is applied.
+    pub fn sysclk_hz(&self) -> u32 {
+        self.sysclk_freq / self.sysclk_div as u32
+    }
+
+    /// The SYS_OSC divider currently in effect.
+    pub fn sysclk_div(&self) -> u8 {
+        self.sysclk_div
+    }
+}
+
+/// Set by [`init`] the first time it's called, so a second call can panic
+/// instead of splitting [`Peripherals`] a second time.
+static INIT_CALLED: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+/// Storage [`init`] writes the split-out [`PeripheralsToBorrow`] into so it
+/// can hand back a [`PeripheralManager`] borrowing from it with `'static`
+/// lifetime, despite this crate being `no_std` with no allocator.
+static mut INIT_BORROWED_PERIPHERALS: core::mem::MaybeUninit<PeripheralsToBorrow> =
+    core::mem::MaybeUninit::uninit();
+
+/// Higher-level entry point collapsing `Peripherals::take().split()` followed
+/// by a [`PeripheralManagerBuilder`] chain into a single call: takes a
+/// [`Config`], performs the split internally, and returns both the resulting
+/// [`PeripheralManager`] and a frozen [`Clocks`] describing its system clock.
+///
+/// Reach for [`PeripheralsToBorrow`]/[`PeripheralsToConsume`]/
+/// [`PeripheralManagerBuilder`] directly instead if this doesn't cover your
+/// use case, e.g. needing [`RemainingPeripherals`] alongside the manager.
+///
+/// # Panics
+///
+/// Panics if called more than once, since the [`Peripherals`] singleton can
+/// only be taken and split once.
+pub fn init<F: FnMut(&mut [u8])>(config: Config<F>) -> (PeripheralManager<'static>, Clocks) {
+    if INIT_CALLED.swap(true, core::sync::atomic::Ordering::SeqCst) {
+        panic!("peripherals::init must only be called once");
+    }
+
+    let Config {
+        sysclk,
+        timer_0,
+        timer_1,
+        timer_2,
+        timer_3,
+        get_rng_static_secret,
+    } = config;
+
+    let (to_consume, to_borrow, _remaining) = Peripherals::take()
+        .expect("Peripherals::take is only None if called twice, which init already guards against")
+        .split();
+
+    // SAFETY: `INIT_CALLED` guarantees this function body runs at most once,
+    // so this is the only write to `INIT_BORROWED_PERIPHERALS` that will ever
+    // happen, and the `&'static` handed back is the only reference to it for
+    // the rest of the program's life. Goes through a raw pointer rather than
+    // `&mut INIT_BORROWED_PERIPHERALS` directly so no `&mut` to the `static
+    // mut` ever exists alongside the `&'static` reference we're about to hand out.
+    let to_borrow: &'static PeripheralsToBorrow = unsafe {
+        let storage = core::ptr::addr_of_mut!(INIT_BORROWED_PERIPHERALS);
+        (*storage).write(to_borrow)
+    };
+
+    macro_rules! build_with {
+        ($osc:ty, $freq:ident, $div:ident) => {
+            PeripheralManagerBuilder::<$osc, F>::new(to_borrow, to_consume, $freq, $div, get_rng_static_secret)
+                .configure_timer_0(timer_0.0, timer_0.1)
+                .configure_timer_1(timer_1.0, timer_1.1)
+                .configure_timer_2(timer_2.0, timer_2.1)
+                .configure_timer_3(timer_3.0, timer_3.1)
+                .build()
+        };
+    }
+
+    // Read off of `sysclk` (a `Copy` type) before it's matched again below to
+    // pick the builder's oscillator type: avoids having to borrow the built
+    // `PeripheralManager`'s `'a`-tied `system_clock()` accessor here, which
+    // would require a reference as long-lived as the manager we're about to
+    // move out as this function's return value.
+    let clocks = match sysclk {
+        SysclkSource::Ipo(freq, div) => Clocks {
+            sysclk_freq: freq.into(),
+            sysclk_div: div.into(),
+        },
+        SysclkSource::Iso(freq, div) => Clocks {
+            sysclk_freq: freq.into(),
+            sysclk_div: div.into(),
+        },
+        SysclkSource::Ibro(freq, div) => Clocks {
+            sysclk_freq: freq.into(),
+            sysclk_div: div.into(),
+        },
+        #[cfg(feature = "low_frequency")]
+        SysclkSource::Inro(freq, div) => Clocks {
+            sysclk_freq: freq.into(),
+            sysclk_div: div.into(),
+        },
+        #[cfg(feature = "low_frequency")]
+        SysclkSource::Ertco(freq, div) => Clocks {
+            sysclk_freq: freq.into(),
+            sysclk_div: div.into(),
+        },
+    };
+
+    let manager = match sysclk {
+        SysclkSource::Ipo(freq, div) => build_with!(oscillator::Ipo, freq, div),
+        SysclkSource::Iso(freq, div) => build_with!(oscillator::Iso, freq, div),
+        SysclkSource::Ibro(freq, div) => build_with!(oscillator::Ibro, freq, div),
+        #[cfg(feature = "low_frequency")]
+        SysclkSource::Inro(freq, div) => build_with!(oscillator::Inro, freq, div),
+        #[cfg(feature = "low_frequency")]
+        SysclkSource::Ertco(freq, div) => build_with!(oscillator::Ertco, freq, div),
+    };
+
+    (manager, clocks)
+}
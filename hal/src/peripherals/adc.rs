@@ -0,0 +1,109 @@
+//! Analog-to-digital converter (ADC) peripheral API.
+
+use core::cell::RefMut;
+
+use max78000::ADC;
+
+use crate::peripherals::gpio::active::port_num_types::GpioTwo;
+use crate::peripherals::gpio::active::ActivePinHandle;
+use crate::peripherals::gpio::pin_traits::IoPin;
+use crate::peripherals::gpio::{GpioError, PinHandle, PinOperatingMode};
+
+/// An internal, non-pin analog source wired directly into the ADC mux.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InternalSource {
+    /// On-die temperature sensor.
+    TempSensor,
+    /// VDDA supply monitor.
+    VddaSense,
+    /// VDDB supply monitor.
+    VddbSense,
+    /// VDDIO supply monitor.
+    VddioSense,
+}
+
+impl InternalSource {
+    fn channel_sel(self) -> u8 {
+        match self {
+            InternalSource::TempSensor => 8,
+            InternalSource::VddaSense => 9,
+            InternalSource::VddbSense => 10,
+            InternalSource::VddioSense => 11,
+        }
+    }
+}
+
+/// A single-ended input channel for the ADC, either one of the `AIN0..AIN7`
+/// pins on GPIO2 or one of the chip's [`InternalSource`]s.
+pub struct Channel<'a> {
+    // Held so the pin can't be reconfigured to another operating mode or
+    // handed out again while the ADC is still wired to it. `None` for
+    // internal sources, which don't go through a GPIO pin at all.
+    _pin: Option<ActivePinHandle<'a, GpioTwo, 8>>,
+    channel_sel: u8,
+}
+
+impl<'a> Channel<'a> {
+    /// Builds a channel from one of GPIO2's `AIN0..AIN7` pins, switching it
+    /// into analog-input mode.
+    pub fn new_pin(mut pin: ActivePinHandle<'a, GpioTwo, 8>) -> Result<Self, GpioError> {
+        let channel_sel = pin.get_pin_idx() as u8;
+        pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
+        Ok(Self {
+            _pin: Some(pin),
+            channel_sel,
+        })
+    }
+
+    /// Builds a channel from an internal, non-pin analog source such as the
+    /// temperature sensor.
+    pub fn new_temp_sensor(source: InternalSource) -> Self {
+        Self {
+            _pin: None,
+            channel_sel: source.channel_sel(),
+        }
+    }
+}
+
+/// The result of an ADC conversion.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// The raw, right-justified conversion result.
+    pub raw: u16,
+    /// Whether hardware reported this conversion as valid. A conversion can
+    /// come back invalid if it was aborted by a higher-priority request.
+    pub valid: bool,
+}
+
+/// ADC peripheral, guarded by [`crate::peripherals::PeripheralManager::adc`]
+/// so only one handle can be taken out at a time.
+pub struct Adc<'a> {
+    adc_regs: RefMut<'a, ADC>,
+}
+
+impl<'a> Adc<'a> {
+    pub(crate) fn new(adc_regs: RefMut<'a, ADC>) -> Self {
+        adc_regs.ctrl().modify(|_, w| w.adc_pwr().variant(2));
+        while adc_regs.status().read().ain_volt_stab().bit_is_clear() {}
+
+        Self { adc_regs }
+    }
+
+    /// Selects `channel`, starts a conversion, and blocks until the result
+    /// is ready.
+    pub fn convert(&mut self, channel: &Channel) -> Sample {
+        self.adc_regs
+            .ctrl()
+            .modify(|_, w| w.ch_sel().variant(channel.channel_sel));
+        self.adc_regs.ctrl().modify(|_, w| w.start().bit(true));
+
+        while self.adc_regs.intfl().read().done().bit_is_clear() {}
+        self.adc_regs.intfl().modify(|_, w| w.done().bit(true));
+
+        let data = self.adc_regs.data().read();
+        Sample {
+            raw: data.data().bits(),
+            valid: !self.adc_regs.intfl().read().overflow_if().bit(),
+        }
+    }
+}
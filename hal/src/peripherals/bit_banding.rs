@@ -77,3 +77,81 @@ fn ref_to_bitband(address: u32, bit: u8) -> *mut u32 {
     let bit_word_addr = bit_word_offset + prefix + 0x0200_0000;
     bit_word_addr as *mut u32
 }
+
+/// Type-safe handle onto a single register bit, accessed through the
+/// bit-banding alias region instead of a racy read-modify-write. Build one
+/// with the [`bit_band!`] macro rather than [`BitBand::new`] directly -- the
+/// macro ties the handle to a named, single-bit `svd2rust` field so the
+/// address and bit index this struct wraps never appear as bare numbers at
+/// the call site.
+pub struct BitBand<T> {
+    address: *const T,
+    bit: u8,
+}
+
+impl<T> BitBand<T> {
+    /// Builds a handle for `bit` of the register at `address`. Prefer
+    /// [`bit_band!`], which derives both from a register/field path and
+    /// rejects fields that aren't single-bit.
+    ///
+    /// # Safety
+    ///
+    /// Same preconditions as [`change_bit`]/[`read_bit`]/[`spin_bit`]:
+    /// `address` must be a valid address within the bit-banding range
+    /// (SRAM or peripheral space, see those functions' safety docs), and
+    /// `bit` must be a valid bit index (`< 32`) of a writable/readable bit
+    /// in that register.
+    pub const unsafe fn new(address: *const T, bit: u8) -> Self {
+        Self { address, bit }
+    }
+
+    /// Atomically sets this bit.
+    pub fn set(&self) {
+        // SAFETY: `address`/`bit` were validated by the caller of `new`/`bit_band!`.
+        unsafe { change_bit(self.address, self.bit, true) }
+    }
+
+    /// Atomically clears this bit.
+    pub fn clear(&self) {
+        // SAFETY: `address`/`bit` were validated by the caller of `new`/`bit_band!`.
+        unsafe { change_bit(self.address, self.bit, false) }
+    }
+
+    /// Atomically reads this bit.
+    pub fn read(&self) -> bool {
+        // SAFETY: `address`/`bit` were validated by the caller of `new`/`bit_band!`.
+        unsafe { read_bit(self.address, self.bit) }
+    }
+
+    /// Busy-waits until this bit reads as `state`.
+    pub fn spin_until(&self, state: bool) {
+        // SAFETY: `address`/`bit` were validated by the caller of `new`/`bit_band!`.
+        unsafe { spin_bit(self.address, self.bit, state) }
+    }
+}
+
+/// Builds a [`BitBand`] handle for a single-bit `svd2rust` register field,
+/// e.g. `bit_band!(rtc_regs.ctrl(), rdy, 4)` for `RTC_CTRL.rdy` instead of
+/// `spin_bit(rtc_regs.ctrl().as_ptr(), 4, true)` with a hand-written safety
+/// comment at every call site.
+///
+/// The field name is re-checked against the register on every expansion by
+/// calling `.read().<field>().bit()`: a typo'd field name fails to compile,
+/// and so does a multi-bit field, since only the single-bit reader
+/// `svd2rust` generates has a `bit()` method. The bit index still has to be
+/// given explicitly -- `svd2rust` doesn't expose field offsets as a constant
+/// callers can read -- but it's now attached to the field name that
+/// motivates it instead of floating on its own.
+#[macro_export]
+macro_rules! bit_band {
+    ($reg:expr, $field:ident, $bit:expr) => {{
+        // Type-checks that `$field` exists on this register and is a
+        // single-bit field; its value isn't otherwise used.
+        let _ = $reg.read().$field().bit();
+        // SAFETY: `$reg` is an `svd2rust` register accessor, so its address
+        // is a valid peripheral address, and `$field`'s existence/bit-ness
+        // was just checked above; `$bit` must still be that field's actual
+        // offset.
+        unsafe { $crate::peripherals::bit_banding::BitBand::new($reg.as_ptr(), $bit) }
+    }};
+}
@@ -0,0 +1,243 @@
+#![cfg(feature = "flc-ram")]
+//! Secure-boot support.
+//!
+//! Verifies an Ed25519 signature (via the no_std [`salty`] crate) over a
+//! firmware image living in flash before handing control to it, so a device
+//! only ever runs images signed by a trusted key. An optional, off-by-default
+//! recovery path lets an already-verified image reflash the bootloader
+//! region itself, for recovering a bricked boot path without a debugger.
+//!
+//! [`salty`]: https://docs.rs/salty
+
+use crate::peripherals::flash_controller::{
+    FlashController, FlashErr, FLASH_MEM_BASE, FLASH_MEM_SIZE, FLASH_PAGE_SIZE,
+};
+#[cfg(feature = "bootloader-recovery")]
+use crate::peripherals::oscillator::SystemClock;
+
+/// Magic bytes identifying a valid [`ImageFooter`], so an erased/blank image
+/// is rejected outright instead of being verified against garbage.
+const FOOTER_MAGIC: [u8; 4] = *b"ED2S";
+
+/// Length in bytes of the footer appended to a signed image:
+/// `[magic: 4][key_id: 1][image_len: 4 LE][signature: 64]`.
+const FOOTER_LEN: usize = FOOTER_MAGIC.len() + 1 + 4 + 64;
+
+/// Signature metadata appended immediately after a signed firmware image.
+pub struct ImageFooter {
+    /// Selects which entry of [`TRUSTED_KEYS`] this image was signed with.
+    pub key_id: u8,
+    /// Length of the signed image, not including this footer.
+    pub image_len: u32,
+    /// Raw Ed25519 signature bytes.
+    pub signature: [u8; 64],
+}
+
+impl ImageFooter {
+    fn parse(bytes: &[u8; FOOTER_LEN]) -> Result<Self, BootloaderError> {
+        if bytes[0..4] != FOOTER_MAGIC {
+            return Err(BootloaderError::MissingFooter);
+        }
+
+        let key_id = bytes[4];
+        let image_len = u32::from_le_bytes(bytes[5..9].try_into().unwrap());
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&bytes[9..FOOTER_LEN]);
+
+        Ok(Self {
+            key_id,
+            image_len,
+            signature,
+        })
+    }
+}
+
+/// Trusted Ed25519 public keys this bootloader accepts image signatures
+/// from, indexed by [`ImageFooter::key_id`].
+///
+/// TODO: populate with the real signing key(s) before shipping; the
+/// all-zero placeholder below can never verify a real signature.
+pub static TRUSTED_KEYS: [[u8; 32]; 1] = [[0u8; 32]];
+
+/// Failure reasons for [`verify_image`] and [`verify_and_boot`].
+#[derive(Debug)]
+pub enum BootloaderError {
+    /// `total_len` was too short to contain an [`ImageFooter`], the footer
+    /// didn't start with the expected magic bytes, or its `image_len`
+    /// didn't match `total_len` minus the footer.
+    MissingFooter,
+    /// The footer's `key_id` doesn't index a [`TRUSTED_KEYS`] entry.
+    UnknownKey,
+    /// The trusted key bytes are not a valid Ed25519 point.
+    MalformedKey,
+    /// The footer's signature bytes are malformed.
+    MalformedSignature,
+    /// The image's signature did not verify against the trusted key.
+    VerificationFailed,
+    /// Reading the image or footer out of flash failed.
+    Flash(FlashErr),
+}
+
+impl From<FlashErr> for BootloaderError {
+    fn from(err: FlashErr) -> Self {
+        BootloaderError::Flash(err)
+    }
+}
+
+/// Checks that `[addr, addr + len)` neither wraps nor falls outside flash,
+/// shared by every entry point here that takes a caller-supplied image
+/// address/length before trusting either one enough to read from flash.
+fn check_image_bounds(addr: u32, len: u32) -> Result<(), BootloaderError> {
+    if addr < FLASH_MEM_BASE
+        || addr.checked_add(len).is_none()
+        || addr + len > FLASH_MEM_BASE + FLASH_MEM_SIZE
+    {
+        return Err(BootloaderError::Flash(FlashErr::PtrBoundsErr));
+    }
+
+    Ok(())
+}
+
+/// Verifies the Ed25519 signature over the image occupying
+/// `[image_addr, image_addr + total_len - FOOTER_LEN)` in flash, where the
+/// trailing [`FOOTER_LEN`] bytes hold the [`ImageFooter`].
+///
+/// Flash is memory-mapped on the MAX78000, so the image is verified in
+/// place; it is never copied into a RAM buffer.
+pub fn verify_image(image_addr: u32, total_len: u32) -> Result<(), BootloaderError> {
+    if (total_len as usize) < FOOTER_LEN {
+        return Err(BootloaderError::MissingFooter);
+    }
+
+    check_image_bounds(image_addr, total_len)?;
+
+    let image_len = total_len - FOOTER_LEN as u32;
+    let footer_addr = image_addr + image_len;
+
+    let mut footer_bytes = [0u8; FOOTER_LEN];
+    FlashController::read_bytes(footer_addr, &mut footer_bytes)?;
+    let footer = ImageFooter::parse(&footer_bytes)?;
+
+    if footer.image_len != image_len {
+        return Err(BootloaderError::MissingFooter);
+    }
+
+    let key_bytes = TRUSTED_KEYS
+        .get(footer.key_id as usize)
+        .ok_or(BootloaderError::UnknownKey)?;
+
+    verify_footer_against_key(image_addr, image_len, &footer, key_bytes)
+}
+
+/// Checks `footer`'s signature over the image at
+/// `[image_addr, image_addr + image_len)` against `key_bytes`, split out of
+/// [`verify_image`] so the [`TRUSTED_KEYS`]/[`ImageFooter::key_id`] lookup
+/// stays separate from the actual signature check.
+fn verify_footer_against_key(
+    image_addr: u32,
+    image_len: u32,
+    footer: &ImageFooter,
+    key_bytes: &[u8; 32],
+) -> Result<(), BootloaderError> {
+    let public_key =
+        salty::signature::PublicKey::try_from(key_bytes).map_err(|_| BootloaderError::MalformedKey)?;
+    let signature = salty::signature::Signature::try_from(&footer.signature[..])
+        .map_err(|_| BootloaderError::MalformedSignature)?;
+
+    // SAFETY: `image_addr..image_addr + image_len` was range-checked against
+    // flash bounds above, and internal flash is memory-mapped and readable
+    // by the CPU at all times, so a shared byte slice over it is valid.
+    let image = unsafe { core::slice::from_raw_parts(image_addr as *const u8, image_len as usize) };
+
+    public_key
+        .verify(image, &signature)
+        .map_err(|_| BootloaderError::VerificationFailed)
+}
+
+/// Jumps to a verified image's reset vector. Never returns.
+///
+/// # Safety
+///
+/// - `image_addr` must already have passed [`verify_image`].
+/// - The image at `image_addr` must be a valid Cortex-M image: word 0 is
+///   the initial stack pointer and word 1 is the reset vector, matching the
+///   vector table `cortex-m-rt` produces.
+pub unsafe fn jump_to_image(image_addr: u32) -> ! {
+    let vector_table = image_addr as *const u32;
+    // SAFETY: caller guarantees `image_addr` points to a valid vector table.
+    let initial_sp = unsafe { core::ptr::read_volatile(vector_table) };
+    let reset_vector = unsafe { core::ptr::read_volatile(vector_table.add(1)) };
+
+    // SAFETY: relocating VTOR to the verified image's vector table and
+    // jumping to its reset vector with its own initial stack pointer is
+    // exactly what a second-stage bootloader is for; the caller's safety
+    // contract establishes the image is both verified and well-formed.
+    unsafe {
+        (*cortex_m::peripheral::SCB::PTR)
+            .vtor
+            .write(image_addr);
+        cortex_m::register::msp::write(initial_sp);
+        let entry: extern "C" fn() -> ! = core::mem::transmute(reset_vector);
+        entry()
+    }
+}
+
+/// Halts forever in a tight loop, for use when a security check fails so
+/// control can never fall through into unauthenticated code. The RAM-resident
+/// flash primitives in [`crate::peripherals::flash_controller`] reserve
+/// `never_exit!()` for genuine fault-injection anomalies; this is the
+/// equivalent halt for a verification failure detected up here.
+pub(crate) fn never_exit() -> ! {
+    loop {
+        cortex_m::asm::wfi();
+    }
+}
+
+/// Verifies the image at `image_addr` against its trailing [`ImageFooter`]
+/// and, on success, jumps to it via [`jump_to_image`]. On failure, halts
+/// instead of ever falling through into unauthenticated code.
+pub fn verify_and_boot(image_addr: u32, total_len: u32) -> ! {
+    match verify_image(image_addr, total_len) {
+        // SAFETY: `verify_image` just returned `Ok`, satisfying `jump_to_image`'s contract.
+        Ok(()) => unsafe { jump_to_image(image_addr) },
+        Err(_) => never_exit(),
+    }
+}
+
+/// Self-flash-from-RAM recovery path: reflashes the bootloader region at
+/// `bootloader_addr` with `new_bootloader`, page by page. Meant to be called
+/// from a verified recovery image running out of RAM (see the `flc-ram`
+/// feature's `.analogsucks` section), so a bricked bootloader can be
+/// replaced without a debugger.
+///
+/// Gated behind the separate `bootloader-recovery` feature and off by
+/// default: any image that passes [`verify_image`] and calls this function
+/// can overwrite the bootloader, so it should only be built into a
+/// dedicated recovery image, never a normal application image.
+///
+/// # Safety
+///
+/// - `bootloader_addr` must point to the start of the bootloader's flash
+///   region, and `new_bootloader.len()` must not run past the end of that
+///   region.
+/// - This function must itself be executing from RAM (not from the flash
+///   region it is about to erase).
+#[cfg(feature = "bootloader-recovery")]
+pub unsafe fn recover_bootloader(
+    flash: &FlashController,
+    sys_clk: &SystemClock,
+    bootloader_addr: u32,
+    new_bootloader: &[u8],
+) -> Result<(), BootloaderError> {
+    for (i, page) in new_bootloader.chunks(FLASH_PAGE_SIZE as usize).enumerate() {
+        let page_addr = bootloader_addr + (i as u32) * FLASH_PAGE_SIZE;
+        // SAFETY: per this function's own safety contract, `page_addr` lies
+        // within the bootloader region and we are executing from RAM.
+        unsafe {
+            flash.page_erase(page_addr, sys_clk)?;
+            flash.write(page_addr, page, sys_clk)?;
+        }
+    }
+
+    Ok(())
+}
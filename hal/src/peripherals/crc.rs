@@ -2,6 +2,7 @@
 
 // use core::mem;
 
+use core::hash::Hasher;
 use core::marker::PhantomData;
 
 use az::OverflowingCastFrom; // as suggested by brian
@@ -84,6 +85,75 @@ impl<'a> CrcWidth for CrcDataU32<'a> {
         self.data
     }
 }
+/// Full bit-level parameterization of a CRC algorithm, using the same
+/// field names as the catalog at <https://reveng.sourceforge.io/crc-catalogue/all.htm>.
+/// Pass one of the associated constants (or a custom instance) to
+/// [`CrcCalculator::configure`] to set up every register at once instead of
+/// chaining the individual setters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcAlgorithm {
+    /// Width of the checksum in bits (8, 16, or 32). This must match the
+    /// [`CrcWidth`] the [`CrcCalculator`] was created with; it is carried
+    /// here only for documentation/display purposes.
+    pub width: u8,
+    /// Polynomial, with the highest-order term omitted (the peripheral's
+    /// `poly` register already assumes it).
+    pub poly: u32,
+    /// Value loaded into the CRC value register before the first [`CrcCalculator::update`].
+    pub init: u32,
+    /// Whether each input byte is bit-reversed before entering the
+    /// peripheral's datapath. Maps to [`CrcCalculator::byte_swap_in`].
+    pub refin: bool,
+    /// Whether the value register is bit-reversed before [`Self::xorout`]
+    /// is applied. Maps to [`CrcCalculator::byte_swap_out`].
+    pub refout: bool,
+    /// Value XORed into the final checksum exactly once, in [`CrcCalculator::finalize`].
+    pub xorout: u32,
+}
+
+impl CrcAlgorithm {
+    /// CRC-32/ISO-HDLC, the CRC-32 used by Ethernet, gzip, PNG, and zip.
+    pub const CRC32_ISO_HDLC: Self = Self {
+        width: 32,
+        poly: 0x04C1_1DB7,
+        init: 0xFFFF_FFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF_FFFF,
+    };
+
+    /// CRC-16/CCITT-FALSE, the variant most often (if confusingly) just
+    /// called "CRC-CCITT".
+    pub const CRC16_CCITT_FALSE: Self = Self {
+        width: 16,
+        poly: 0x1021,
+        init: 0xFFFF,
+        refin: false,
+        refout: false,
+        xorout: 0x0000,
+    };
+
+    /// CRC-16/MODBUS.
+    pub const CRC16_MODBUS: Self = Self {
+        width: 16,
+        poly: 0x8005,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0x0000,
+    };
+
+    /// CRC-8/SMBUS.
+    pub const CRC8_SMBUS: Self = Self {
+        width: 8,
+        poly: 0x07,
+        init: 0x00,
+        refin: false,
+        refout: false,
+        xorout: 0x00,
+    };
+}
+
 #[derive(Debug)]
 /// Crc Calculator struct. Uses a builder-style pattern
 pub struct CrcCalculator<Width: CrcWidth> {
@@ -94,6 +164,10 @@ pub struct CrcCalculator<Width: CrcWidth> {
     poly: u32,
     xorout: u32,
     crc: Crc,
+    /// Whether a streaming computation is in progress, ie. whether `value`
+    /// has already been loaded into the CRC value register by `update` and
+    /// should be left running rather than reloaded.
+    started: bool,
 
     phantom: PhantomData<Width::OutWidth>, // data: Option<Width::OutWidth>,
 }
@@ -109,10 +183,25 @@ impl<Width: CrcWidth> CrcCalculator<Width> {
             poly: 0xEDB8_8320,
             value: 0x0,
             xorout: 0xFFFF_FFFF,
+            started: false,
             phantom: PhantomData,
         }
     }
 
+    /// Configures `msb`, `byte_swap_in`/`byte_swap_out`, `poly`, `xorout`,
+    /// and the value loaded by the next [`Self::update`] from a single
+    /// [`CrcAlgorithm`] preset, eg. [`CrcAlgorithm::CRC32_ISO_HDLC`].
+    pub fn configure(&mut self, algorithm: CrcAlgorithm) -> &mut Self {
+        self.msb(true)
+            .byte_swap_in(algorithm.refin)
+            .byte_swap_out(algorithm.refout)
+            .poly(algorithm.poly)
+            .xorout(algorithm.xorout)
+            .value(algorithm.init);
+        self.started = false;
+        self
+    }
+
     /// Set msb
     pub fn msb(&mut self, msb: bool) -> &mut Self {
         self.msb = msb;
@@ -209,9 +298,92 @@ impl<Width: CrcWidth> CrcCalculator<Width> {
         a
     }
 
+    /// Feeds `data` into the hardware CRC datapath without resetting the
+    /// running checksum, so a payload that arrives as several buffers (or
+    /// isn't contiguous in memory) can be hashed without first being
+    /// concatenated. Call [`Self::finalize`] once the last chunk has been
+    /// fed in to get the checksum.
+    ///
+    /// The value configured via [`Self::value`]/[`Self::configure`] is
+    /// loaded into the CRC value register the first time this is called
+    /// (or after the previous stream's [`Self::finalize`]); later calls
+    /// leave the running value in the register alone.
+    pub fn update(&mut self, data: &[u8]) {
+        self.crc._crc.ctrl().write(|w| w.en().bit(false));
+        self.crc._crc.ctrl().write(|w| w.msb().bit(self.msb));
+        self.crc
+            ._crc
+            .ctrl()
+            .write(|w| w.byte_swap_in().bit(self.byte_swap_in));
+        self.crc
+            ._crc
+            .ctrl()
+            .write(|w| w.byte_swap_out().bit(self.byte_swap_out));
+        self.crc._crc.poly().write(|w| w.poly().variant(self.poly));
+
+        if !self.started {
+            self.crc._crc.val().write(|w| w.value().variant(self.value));
+            self.started = true;
+        }
+
+        self.crc._crc.ctrl().write(|w| w.en().bit(true));
+
+        const CHUNK_SIZE: usize = core::mem::size_of::<u32>();
+        for chunk in data.chunks(CHUNK_SIZE) {
+            let mut padded_bytes = [0u8; CHUNK_SIZE];
+            padded_bytes[..chunk.len()].copy_from_slice(chunk);
+
+            while self.crc._crc.ctrl().read().busy().bit() {}
+            self.crc
+                ._crc
+                .datain32()
+                .write(|w| w.data().variant(u32::from_ne_bytes(padded_bytes)));
+        }
+
+        while self.crc._crc.ctrl().read().busy().bit() {}
+    }
+
+    /// Finishes a streaming computation started by one or more calls to
+    /// [`Self::update`], XORing the running value with [`Self::xorout`]
+    /// exactly once and casting it down to the output width. Resets the
+    /// streaming state so the next [`Self::update`] call starts a fresh run.
+    pub fn finalize(&mut self) -> Width::OutWidth {
+        let (value, _) =
+            Width::OutWidth::overflowing_cast_from(self.crc._crc.val().read().bits() ^ self.xorout);
+        self.started = false;
+
+        value
+    }
+
     pub fn val_reg_bits(&self) -> u32{
         self.crc._crc.val().read().bits()
     }
+
+    /// Computes the checksum of `data` in one call: feeds it through
+    /// [`Self::update`] and immediately [`Self::finalize`]s, for callers that
+    /// have the whole buffer up front (eg. checking a flash image's
+    /// integrity) and don't need the streaming, multi-call API.
+    pub fn checksum(&mut self, data: &[u8]) -> Width::OutWidth {
+        self.update(data);
+        self.finalize()
+    }
+}
+
+/// Lets a [`CrcCalculator`] back generic hashing (eg. as the `S` in a
+/// `BuildHasherDefault<CrcCalculator<...>>`) on top of its streaming
+/// [`CrcCalculator::update`]/[`Self::finish`] API. Unlike
+/// [`CrcCalculator::finalize`], [`Self::finish`] doesn't reset the streaming
+/// state or apply [`CrcCalculator::xorout`] more than once across repeated
+/// calls, matching the "may be called multiple times" contract `Hasher`
+/// documents for `finish`.
+impl<Width: CrcWidth> Hasher for CrcCalculator<Width> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.val_reg_bits() ^ self.xorout)
+    }
 }
 
 impl Crc {
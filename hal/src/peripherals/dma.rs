@@ -0,0 +1,283 @@
+//! Standard DMA controller driver.
+//!
+//! The MAX78000's system DMA controller has 4 independent channels, each
+//! able to move a run of bytes between a source and destination address
+//! without CPU involvement. Gating a channel on a peripheral's FIFO request
+//! line (rather than running it as fast as possible) lets that peripheral's
+//! driver hand off a whole buffer instead of polling the FIFO byte-by-byte --
+//! see [`crate::peripherals::i2c::master::I2CMaster::recv_raw_dma`] for the
+//! motivating use case.
+//!
+//! [`Dma::channel`] hands out a [`DmaChannel`] for one of the 4 channels;
+//! [`DmaChannel::start`] programs its source/destination/count and enables
+//! it, and [`DmaChannel::wait`]/[`DmaChannel::is_done`] poll the channel's
+//! count-to-zero flag the same way a driver would otherwise wire to an
+//! interrupt. [`DmaChannel::wait_async`] is the actual interrupt-driven
+//! version of that wait, for callers (like
+//! [`crate::peripherals::i2c::asynch::I2CMasterAsync`]) that want to park
+//! the task instead of spinning; it's woken by [`on_interrupt`], which
+//! callers route to from the channel's `DMAn` NVIC handler.
+
+use core::future::poll_fn;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Poll;
+
+use embassy_sync::waker::AtomicWaker;
+use max78000::DMA;
+
+/// Which hardware request line gates a channel's transfers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DmaRequest {
+    /// No gating peripheral; the channel runs as fast as possible
+    /// (memory-to-memory).
+    Memory,
+    /// Gated on `I2C0`'s receive FIFO threshold.
+    I2C0Rx,
+    /// Gated on `I2C0`'s transmit FIFO threshold.
+    I2C0Tx,
+    /// Gated on `I2C1`'s receive FIFO threshold.
+    I2C1Rx,
+    /// Gated on `I2C1`'s transmit FIFO threshold.
+    I2C1Tx,
+    /// Gated on `I2C2`'s receive FIFO threshold.
+    I2C2Rx,
+    /// Gated on `I2C2`'s transmit FIFO threshold.
+    I2C2Tx,
+}
+
+impl DmaRequest {
+    /// The `CTRLn.rqsel` encoding for this request source, per the user
+    /// guide's DMA request-select table.
+    fn rqsel(self) -> u8 {
+        match self {
+            DmaRequest::Memory => 0,
+            DmaRequest::I2C0Rx => 12,
+            DmaRequest::I2C0Tx => 13,
+            DmaRequest::I2C1Rx => 14,
+            DmaRequest::I2C1Tx => 15,
+            DmaRequest::I2C2Rx => 16,
+            DmaRequest::I2C2Tx => 17,
+        }
+    }
+}
+
+/// Error returned by [`DmaChannel::is_done`]/[`DmaChannel::wait`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DmaError {
+    /// The transfer touched an address it isn't allowed to (`STATUSn.bus_err`).
+    BusError,
+}
+
+/// Number of DMA channels (0..3) that need a waker/error slot.
+const NUM_DMA_CHANNELS: usize = 4;
+
+static DMA_WAKERS: [AtomicWaker; NUM_DMA_CHANNELS] =
+    [const { AtomicWaker::new() }; NUM_DMA_CHANNELS];
+static DMA_ERROR: [AtomicBool; NUM_DMA_CHANNELS] =
+    [const { AtomicBool::new(false) }; NUM_DMA_CHANNELS];
+
+/// Call this from the `DMAn` NVIC handler. Masks the channel's
+/// count-to-zero/bus-error interrupt back off (the future re-arms it on its
+/// next poll), latches whether it fired due to a bus error, and wakes
+/// whichever future is waiting on this channel via [`DmaChannel::wait_async`].
+pub fn on_interrupt(regs: &DMA, idx: usize) {
+    macro_rules! handle {
+        ($ctrl:ident, $status:ident) => {{
+            regs.$ctrl().modify(|_, w| w.ien().bit(false));
+            if regs.$status().read().bus_err().bit() {
+                DMA_ERROR[idx].store(true, Ordering::Release);
+            }
+        }};
+    }
+
+    match idx {
+        0 => handle!(ctrl0, status0),
+        1 => handle!(ctrl1, status1),
+        2 => handle!(ctrl2, status2),
+        _ => handle!(ctrl3, status3),
+    }
+
+    DMA_WAKERS[idx].wake();
+}
+
+/// One of the DMA controller's 4 independent transfer channels.
+pub struct DmaChannel<'a> {
+    regs: &'a DMA,
+    idx: usize,
+}
+
+impl<'a> DmaChannel<'a> {
+    fn new(regs: &'a DMA, idx: usize) -> Self {
+        Self { regs, idx }
+    }
+
+    /// Configures and starts a transfer of `count` bytes from `src` to
+    /// `dst`, gated on `request`. `src_increment`/`dst_increment` should be
+    /// `false` for whichever side is a fixed peripheral FIFO register
+    /// address (e.g. reading an I2C FIFO into an incrementing memory
+    /// buffer: `src_increment: false, dst_increment: true`).
+    ///
+    /// # Safety
+    ///
+    /// `src`/`dst` must stay valid for `count` bytes (for whichever side has
+    /// `increment: true`, the whole range; otherwise just the one address)
+    /// until the transfer completes, as observed by [`Self::wait`]/
+    /// [`Self::is_done`], and that memory must not otherwise be accessed
+    /// while the transfer is in flight.
+    pub unsafe fn start(
+        &mut self,
+        src: *const u8,
+        src_increment: bool,
+        dst: *mut u8,
+        dst_increment: bool,
+        count: usize,
+        request: DmaRequest,
+    ) {
+        macro_rules! configure {
+            ($ctrl:ident, $status:ident, $src:ident, $dst:ident, $cnt:ident) => {{
+                self.regs.$ctrl().modify(|_, w| w.en().bit(false));
+                self.regs
+                    .$status()
+                    .write(|w| w.ctz().bit(true).bus_err().bit(true));
+
+                self.regs.$src().write(|w| w.addr().variant(src as u32));
+                self.regs.$dst().write(|w| w.addr().variant(dst as u32));
+                self.regs.$cnt().write(|w| w.cnt().variant(count as u32));
+
+                self.regs.$ctrl().modify(|_, w| {
+                    w.rqsel()
+                        .variant(request.rqsel())
+                        .srcinc()
+                        .bit(src_increment)
+                        .dstinc()
+                        .bit(dst_increment)
+                        .en()
+                        .bit(true)
+                });
+            }};
+        }
+
+        match self.idx {
+            0 => configure!(ctrl0, status0, src0, dst0, cnt0),
+            1 => configure!(ctrl1, status1, src1, dst1, cnt1),
+            2 => configure!(ctrl2, status2, src2, dst2, cnt2),
+            _ => configure!(ctrl3, status3, src3, dst3, cnt3),
+        }
+    }
+
+    /// Whether the transfer started by [`Self::start`] has finished
+    /// (`STATUSn.ctz`, count-to-zero) or faulted (`STATUSn.bus_err`).
+    pub fn is_done(&self) -> Result<bool, DmaError> {
+        macro_rules! check {
+            ($status:ident) => {{
+                let status = self.regs.$status().read();
+                if status.bus_err().bit() {
+                    Err(DmaError::BusError)
+                } else {
+                    Ok(status.ctz().bit())
+                }
+            }};
+        }
+
+        match self.idx {
+            0 => check!(status0),
+            1 => check!(status1),
+            2 => check!(status2),
+            _ => check!(status3),
+        }
+    }
+
+    /// Busy-waits for the transfer started by [`Self::start`] to finish.
+    pub fn wait(&self) -> Result<(), DmaError> {
+        while !self.is_done()? {}
+        Ok(())
+    }
+
+    /// Async equivalent of [`Self::wait`]: parks the task on a per-channel
+    /// [`AtomicWaker`] instead of spinning, woken by [`on_interrupt`] once
+    /// the transfer started by [`Self::start`] finishes or faults. Callers
+    /// are responsible for routing the `DMAn` NVIC interrupt to
+    /// [`on_interrupt`].
+    pub async fn wait_async(&self) -> Result<(), DmaError> {
+        DMA_ERROR[self.idx].store(false, Ordering::Release);
+
+        poll_fn(|cx| {
+            DMA_WAKERS[self.idx].register(cx.waker());
+            self.set_interrupt_enabled(true);
+
+            if DMA_ERROR[self.idx].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(DmaError::BusError));
+            }
+
+            match self.is_done() {
+                Ok(true) => Poll::Ready(Ok(())),
+                Ok(false) => Poll::Pending,
+                Err(e) => Poll::Ready(Err(e)),
+            }
+        })
+        .await
+    }
+
+    /// Arms or masks this channel's count-to-zero/bus-error interrupt.
+    /// [`Self::wait_async`] re-arms it on every poll; [`on_interrupt`] masks
+    /// it back off once it fires, mirroring the FIFO-interrupt arm/disarm
+    /// pairs in [`crate::peripherals::i2c::asynch`].
+    fn set_interrupt_enabled(&self, enabled: bool) {
+        macro_rules! set {
+            ($ctrl:ident) => {
+                self.regs.$ctrl().modify(|_, w| w.ien().bit(enabled))
+            };
+        }
+
+        match self.idx {
+            0 => set!(ctrl0),
+            1 => set!(ctrl1),
+            2 => set!(ctrl2),
+            _ => set!(ctrl3),
+        }
+    }
+
+    /// Bytes remaining in the in-flight (or just-completed) transfer started
+    /// by [`Self::start`], read back from the channel's live count register.
+    /// Useful when a transfer can legitimately end before the full `count`
+    /// arrives (e.g. an I2C slave RX, where the master -- not this channel --
+    /// decides how many bytes the transaction carries).
+    pub fn bytes_remaining(&self) -> u32 {
+        macro_rules! remaining {
+            ($cnt:ident) => {
+                self.regs.$cnt().read().cnt().bits()
+            };
+        }
+
+        match self.idx {
+            0 => remaining!(cnt0),
+            1 => remaining!(cnt1),
+            2 => remaining!(cnt2),
+            _ => remaining!(cnt3),
+        }
+    }
+}
+
+/// Owns the DMA controller's register block and hands out per-channel
+/// handles.
+pub struct Dma {
+    regs: DMA,
+}
+
+impl Dma {
+    /// Wraps `regs`. Doesn't touch any channel's configuration.
+    pub fn new(regs: DMA) -> Self {
+        Self { regs }
+    }
+
+    /// Gets a handle to one of the controller's 4 channels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx >= 4`.
+    pub fn channel(&mut self, idx: usize) -> DmaChannel<'_> {
+        assert!(idx < 4, "MAX78000 DMA controller only has 4 channels");
+        DmaChannel::new(&self.regs, idx)
+    }
+}
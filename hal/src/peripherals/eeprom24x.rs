@@ -0,0 +1,147 @@
+//! Driver for 24xx-series I2C EEPROMs (24C01 through 24C512 and compatible
+//! parts), built on top of anything implementing `embedded_hal::i2c::I2c`
+//! (e.g. the bit-banged [`I2CMaster`](crate::peripherals::i2c_bitbang::I2CMaster)).
+//!
+//! Handles the two pieces of the 24Cxx addressing dance that differ across
+//! the family and that callers would otherwise have to hand-roll: encoding
+//! the in-device word address as 8 or 16 bits (see [`Addr8`]/[`Addr16`]),
+//! and splitting writes at the device's page boundary with
+//! acknowledge-polling after each page so a caller never races the device's
+//! internal write cycle.
+
+use core::marker::PhantomData;
+
+use embedded_hal::i2c::{Error as _, ErrorKind, I2c};
+
+/// Largest single write [`Eeprom24x::write_page`] will issue to the bus:
+/// `PAGE_SIZE` data bytes plus up to two address bytes. Generous enough for
+/// every 24Cxx part's page size (the largest common ones top out at 128
+/// bytes).
+const MAX_WRITE_CHUNK: usize = 256;
+
+/// Number of address-only probes [`Eeprom24x::ack_poll`] sends before giving
+/// up on a write ever completing.
+const ACK_POLL_RETRIES: u32 = 1000;
+
+/// Selects how [`Eeprom24x`] frames its in-device word address on the wire.
+/// Implemented by [`Addr8`] and [`Addr16`]; pick whichever matches the part
+/// in use.
+pub trait AddressWidth {
+    /// Encodes `addr` into the front of `buf`, returning the prefix of `buf`
+    /// actually used.
+    fn encode(addr: u32, buf: &mut [u8; 2]) -> &[u8];
+}
+
+/// 8-bit word address, used by small 24Cxx parts (24C01 through 24C16).
+/// Only the low 8 bits of the address are sent; the block-select bits that
+/// 24C04 through 24C16 parts take in the device address are not set, so on
+/// those parts only the first 256 bytes are reachable.
+pub struct Addr8;
+
+impl AddressWidth for Addr8 {
+    fn encode(addr: u32, buf: &mut [u8; 2]) -> &[u8] {
+        buf[0] = addr as u8;
+        &buf[..1]
+    }
+}
+
+/// 16-bit, big-endian word address, used by larger 24Cxx parts (24C32 and
+/// up).
+pub struct Addr16;
+
+impl AddressWidth for Addr16 {
+    fn encode(addr: u32, buf: &mut [u8; 2]) -> &[u8] {
+        *buf = (addr as u16).to_be_bytes();
+        &buf[..2]
+    }
+}
+
+/// Errors returned by [`Eeprom24x`].
+#[derive(Debug)]
+pub enum EepromError<E> {
+    /// The underlying I2C transaction failed.
+    I2c(E),
+    /// The device never acknowledged the address byte within
+    /// [`ACK_POLL_RETRIES`] probes after a write, i.e. its internal write
+    /// cycle never finished.
+    WriteTimeout,
+}
+
+/// Blocking driver for a 24xx-series I2C EEPROM, parameterized over the
+/// device's word-address width (`A`, see [`AddressWidth`]) and page size in
+/// bytes (`PAGE_SIZE`) so both small and large parts are supported.
+pub struct Eeprom24x<I2C, A, const PAGE_SIZE: usize> {
+    i2c: I2C,
+    device_addr: u8,
+    _address_width: PhantomData<A>,
+}
+
+impl<I2C: I2c, A: AddressWidth, const PAGE_SIZE: usize> Eeprom24x<I2C, A, PAGE_SIZE> {
+    /// Wraps `i2c`, talking to the EEPROM at 7-bit address `device_addr`.
+    pub fn new(i2c: I2C, device_addr: u8) -> Self {
+        Self {
+            i2c,
+            device_addr,
+            _address_width: PhantomData,
+        }
+    }
+
+    /// Reads `data.len()` bytes starting at `addr`, via a write of the word
+    /// address followed by a read (the standard 24Cxx random-read
+    /// sequence).
+    pub fn read(&mut self, addr: u32, data: &mut [u8]) -> Result<(), EepromError<I2C::Error>> {
+        let mut addr_buf = [0u8; 2];
+        let addr_bytes = A::encode(addr, &mut addr_buf);
+        self.i2c
+            .write_read(self.device_addr, addr_bytes, data)
+            .map_err(EepromError::I2c)
+    }
+
+    /// Writes `data` starting at `addr`, splitting at `PAGE_SIZE` boundaries
+    /// (a write that crossed a page boundary in one transaction would wrap
+    /// within the page on real hardware instead of continuing into the
+    /// next one) and acknowledge-polling after each page so the call
+    /// doesn't return until the device's internal write cycle has
+    /// finished.
+    pub fn write_page(&mut self, addr: u32, data: &[u8]) -> Result<(), EepromError<I2C::Error>> {
+        let mut offset = 0;
+        while offset < data.len() {
+            let page_addr = addr + offset as u32;
+            let room_in_page = PAGE_SIZE - (page_addr as usize % PAGE_SIZE);
+            let n = room_in_page
+                .min(data.len() - offset)
+                .min(MAX_WRITE_CHUNK - 2);
+
+            let mut addr_buf = [0u8; 2];
+            let addr_bytes = A::encode(page_addr, &mut addr_buf);
+
+            let mut frame = [0u8; MAX_WRITE_CHUNK];
+            frame[..addr_bytes.len()].copy_from_slice(addr_bytes);
+            frame[addr_bytes.len()..addr_bytes.len() + n]
+                .copy_from_slice(&data[offset..offset + n]);
+
+            self.i2c
+                .write(self.device_addr, &frame[..addr_bytes.len() + n])
+                .map_err(EepromError::I2c)?;
+
+            self.ack_poll()?;
+
+            offset += n;
+        }
+        Ok(())
+    }
+
+    /// Repeatedly addresses the device for a zero-length write until it
+    /// acknowledges, which 24Cxx parts only do once a pending internal
+    /// write cycle has finished.
+    fn ack_poll(&mut self) -> Result<(), EepromError<I2C::Error>> {
+        for _ in 0..ACK_POLL_RETRIES {
+            match self.i2c.write(self.device_addr, &[]) {
+                Ok(()) => return Ok(()),
+                Err(e) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => continue,
+                Err(e) => return Err(EepromError::I2c(e)),
+            }
+        }
+        Err(EepromError::WriteTimeout)
+    }
+}
@@ -0,0 +1,440 @@
+#![cfg(feature = "flc-ram")]
+//! Log-structured key/value configuration store over the flash controller.
+//!
+//! [`ConfigStore`] persists small key/value pairs across reboots as an
+//! append-only log spread over two ping-ponged [`FLASH_PAGE_SIZE`] pages:
+//! [`ConfigStore::set`] and [`ConfigStore::erase`] never rewrite an existing
+//! record in place (flash can only be erased a whole page at a time), they
+//! just append a new one, and [`ConfigStore::get`] scans the active page for
+//! the last record matching a key. Once the active page fills up,
+//! [`ConfigStore::set`]/[`ConfigStore::erase`] compact its still-live records
+//! (the latest, non-erased record per key) into the spare page before
+//! erasing the old one, the same two-page wear-leveling scheme
+//! [`crate::peripherals::update`]'s metadata log uses.
+//!
+//! Each record is `[key_len: u8][val_len: u16 LE][key bytes][val bytes][crc32
+//! LE]`, with the CRC -- computed with a [`CrcCalculator`] over everything
+//! before it -- written last, so a reset partway through a write leaves an
+//! incomplete record that [`parse_record_at`] can tell isn't valid yet
+//! instead of a torn one being mistaken for real data. `key_len ==
+//! `[`ERASED_KEY_LEN`]`` marks the unwritten tail of a page, so scanning
+//! stops there rather than reading garbage.
+//!
+//! [`ConfigStore::open`]/[`ConfigStore::put`]/[`ConfigStore::remove`] are
+//! aliases for [`ConfigStore::new`]/[`ConfigStore::set`]/[`ConfigStore::erase`],
+//! since other flash-backed key/value stores in the wild tend to use that
+//! naming; [`ConfigStore::get_into`] is a buffer-filling counterpart to
+//! [`ConfigStore::get`] for callers that want an owned copy.
+
+use crate::peripherals::crc::{CrcAlgorithm, CrcCalculator, CrcDataU32};
+use crate::peripherals::flash_controller::{FlashController, FlashErr, FLASH_PAGE_SIZE};
+use crate::peripherals::oscillator::SystemClock;
+
+/// Length in bytes of a record's `[key_len][val_len]` header.
+const RECORD_HEADER_LEN: usize = 1 + 2;
+
+/// Length in bytes of a record's trailing CRC-32.
+const RECORD_CRC_LEN: usize = 4;
+
+/// Reserved `key_len` value marking the unwritten (erased, `0xFF`) tail of a
+/// page, i.e. where the log ends.
+const ERASED_KEY_LEN: u8 = 0xFF;
+
+/// Reserved `val_len` value marking a key as erased: the record's key bytes
+/// are valid but no value bytes follow it.
+const TOMBSTONE_VAL_LEN: u16 = u16::MAX;
+
+/// Longest key a record can hold -- `u8::MAX` is reserved as [`ERASED_KEY_LEN`].
+pub const MAX_KEY_LEN: usize = u8::MAX as usize - 1;
+
+/// Longest value a record can hold -- `u16::MAX` is reserved as [`TOMBSTONE_VAL_LEN`].
+pub const MAX_VALUE_LEN: usize = u16::MAX as usize - 1;
+
+/// The two flash pages a [`ConfigStore`] ping-pongs its append-only log
+/// across. Callers are responsible for reserving both for this store alone,
+/// not overlapping firmware slots, [`crate::peripherals::update`]'s metadata
+/// log, or anything else sharing the flash array.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigPages {
+    /// Base address of the first page.
+    pub page_a: u32,
+    /// Base address of the second page.
+    pub page_b: u32,
+}
+
+/// Failure reasons for the config store.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// A flash read, write, or erase failed.
+    Flash(FlashErr),
+    /// `key` is longer than [`MAX_KEY_LEN`], `value` is longer than
+    /// [`MAX_VALUE_LEN`], or the record they'd form can't fit in a freshly
+    /// erased page even on its own.
+    TooLarge,
+}
+
+impl From<FlashErr> for ConfigError {
+    fn from(err: FlashErr) -> Self {
+        ConfigError::Flash(err)
+    }
+}
+
+/// Parses and CRC-validates the record at `addr` within `page`, returning
+/// its key length, value length (or [`TOMBSTONE_VAL_LEN`]), and total length
+/// in bytes. Returns `None` if `addr` holds the unwritten tail of the page or
+/// a torn/corrupt record -- either way, the end of the valid log.
+fn parse_record_at(page: u32, addr: u32, crc: &mut CrcCalculator<CrcDataU32>) -> Option<(u8, u16, u32)> {
+    if addr + RECORD_HEADER_LEN as u32 > page + FLASH_PAGE_SIZE {
+        return None;
+    }
+
+    // SAFETY: internal flash is memory-mapped and readable by the CPU at all
+    // times, and `addr` was just checked to leave room for the header
+    // within this page.
+    let key_len = unsafe { core::ptr::read_volatile(addr as *const u8) };
+    if key_len == ERASED_KEY_LEN {
+        return None;
+    }
+
+    // SAFETY: as above.
+    let val_len = unsafe {
+        let mut buf = [0u8; 2];
+        buf.copy_from_slice(core::slice::from_raw_parts((addr + 1) as *const u8, 2));
+        u16::from_le_bytes(buf)
+    };
+    let value_len = if val_len == TOMBSTONE_VAL_LEN { 0 } else { u32::from(val_len) };
+    let record_len = RECORD_HEADER_LEN as u32 + u32::from(key_len) + value_len + RECORD_CRC_LEN as u32;
+    if addr + record_len > page + FLASH_PAGE_SIZE {
+        return None;
+    }
+
+    crc.configure(CrcAlgorithm::CRC32_ISO_HDLC);
+    // SAFETY: `addr..addr + record_len` was just bounds-checked against this page.
+    let payload = unsafe {
+        core::slice::from_raw_parts(addr as *const u8, (record_len - RECORD_CRC_LEN as u32) as usize)
+    };
+    let computed = crc.checksum(payload);
+
+    // SAFETY: as above.
+    let stored = unsafe {
+        let mut buf = [0u8; RECORD_CRC_LEN];
+        buf.copy_from_slice(core::slice::from_raw_parts(
+            (addr + record_len - RECORD_CRC_LEN as u32) as *const u8,
+            RECORD_CRC_LEN,
+        ));
+        u32::from_le_bytes(buf)
+    };
+    if computed != stored {
+        return None;
+    }
+
+    Some((key_len, val_len, record_len))
+}
+
+/// Walks every valid record in `page` in log order starting from `page`'s
+/// base, calling `on_record(addr, key_len, val_len, record_len)` for each.
+/// Stops at the first unwritten or torn record. Returns the address the next
+/// record should be appended at.
+fn for_each_record(
+    page: u32,
+    crc: &mut CrcCalculator<CrcDataU32>,
+    mut on_record: impl FnMut(u32, u8, u16, u32),
+) -> u32 {
+    let mut addr = page;
+    while let Some((key_len, val_len, record_len)) = parse_record_at(page, addr, crc) {
+        on_record(addr, key_len, val_len, record_len);
+        addr += record_len;
+    }
+    addr
+}
+
+/// Returns whether `key` (found at a record ending at `after`) reappears in
+/// a later valid record in `page`, i.e. whether the earlier occurrence is
+/// stale and can be dropped during compaction.
+fn key_superseded(page: u32, after: u32, key: &[u8], crc: &mut CrcCalculator<CrcDataU32>) -> bool {
+    let mut addr = after;
+    while let Some((key_len, _, record_len)) = parse_record_at(page, addr, crc) {
+        if record_key(addr, key_len) == key {
+            return true;
+        }
+        addr += record_len;
+    }
+    false
+}
+
+/// Borrows a record's key bytes directly out of flash.
+fn record_key<'a>(addr: u32, key_len: u8) -> &'a [u8] {
+    // SAFETY: internal flash is memory-mapped and readable by the CPU at all
+    // times, and `addr + RECORD_HEADER_LEN..+ key_len` was bounds-checked by
+    // the `parse_record_at` call that produced `key_len`.
+    unsafe { core::slice::from_raw_parts((addr + RECORD_HEADER_LEN as u32) as *const u8, key_len as usize) }
+}
+
+/// Borrows a record's value bytes directly out of flash (empty for a
+/// tombstoned key).
+fn record_value<'a>(addr: u32, key_len: u8, val_len: u16) -> &'a [u8] {
+    let value_len = if val_len == TOMBSTONE_VAL_LEN { 0 } else { val_len as usize };
+    // SAFETY: see `record_key`.
+    unsafe {
+        core::slice::from_raw_parts(
+            (addr + RECORD_HEADER_LEN as u32 + u32::from(key_len)) as *const u8,
+            value_len,
+        )
+    }
+}
+
+/// A log-structured key/value store over two ping-ponged flash pages. See
+/// the module documentation for the on-flash layout.
+pub struct ConfigStore {
+    pages: ConfigPages,
+}
+
+impl ConfigStore {
+    /// Creates a store over `pages`. Doesn't touch flash -- the pages are
+    /// read and written lazily as [`Self::get`]/[`Self::set`]/[`Self::erase`]
+    /// are called.
+    pub fn new(pages: ConfigPages) -> Self {
+        Self { pages }
+    }
+
+    /// Alias for [`Self::new`], matching the `open`/`put`/`get`/`remove`
+    /// naming other flash-backed key/value stores use.
+    pub fn open(pages: ConfigPages) -> Self {
+        Self::new(pages)
+    }
+
+    /// The page currently being appended to: whichever of `self.pages`
+    /// doesn't read as fully erased, falling back to `page_a` if both do
+    /// (i.e. a fresh, never-yet-written store).
+    fn active_page(&self) -> u32 {
+        // SAFETY: internal flash is memory-mapped and readable by the CPU at
+        // all times.
+        let b_first = unsafe { core::ptr::read_volatile(self.pages.page_b as *const u8) };
+        if b_first != ERASED_KEY_LEN {
+            self.pages.page_b
+        } else {
+            self.pages.page_a
+        }
+    }
+
+    /// The page other than `page`, i.e. the one [`Self::compact`] compacts
+    /// live records into.
+    fn other_page(&self, page: u32) -> u32 {
+        if page == self.pages.page_a {
+            self.pages.page_b
+        } else {
+            self.pages.page_a
+        }
+    }
+
+    /// Looks up the most recently [`Self::set`] value for `key`, or `None`
+    /// if it was never set or was [`Self::erase`]d since.
+    pub fn get(&self, key: &[u8], crc: &mut CrcCalculator<CrcDataU32>) -> Option<&[u8]> {
+        let page = self.active_page();
+        let mut latest: Option<(u32, u8, u16)> = None;
+        for_each_record(page, crc, |addr, key_len, val_len, _| {
+            if record_key(addr, key_len) == key {
+                latest = Some((addr, key_len, val_len));
+            }
+        });
+
+        let (addr, key_len, val_len) = latest?;
+        if val_len == TOMBSTONE_VAL_LEN {
+            return None;
+        }
+        Some(record_value(addr, key_len, val_len))
+    }
+
+    /// Copies the value for `key` into `buf`, returning the number of bytes
+    /// written, or `None` if it was never [`Self::set`]/[`Self::put`] (or was
+    /// erased since). A buffer-filling counterpart to [`Self::get`] for
+    /// callers that want an owned copy rather than a borrow tied to flash's
+    /// lifetime; returns `None` (rather than truncating) if `buf` is too
+    /// small.
+    pub fn get_into(&self, key: &[u8], crc: &mut CrcCalculator<CrcDataU32>, buf: &mut [u8]) -> Option<usize> {
+        let value = self.get(key, crc)?;
+        if value.len() > buf.len() {
+            return None;
+        }
+        buf[..value.len()].copy_from_slice(value);
+        Some(value.len())
+    }
+
+    /// Persists `value` under `key`, superseding any value or erasure
+    /// previously recorded for it. Appends a new record to the active page,
+    /// compacting it into the spare page first if it doesn't have room.
+    pub fn set(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), ConfigError> {
+        self.append_record(flash, sys_clk, crc, key, Some(value))
+    }
+
+    /// Alias for [`Self::set`], matching the `open`/`put`/`get`/`remove`
+    /// naming other flash-backed key/value stores use.
+    pub fn put(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), ConfigError> {
+        self.set(flash, sys_clk, crc, key, value)
+    }
+
+    /// Marks `key` as erased, so a later [`Self::get`] returns `None` for it
+    /// until it is [`Self::set`] again. A no-op key that was never set still
+    /// appends a tombstone record; callers that care can check
+    /// [`Self::get`] first.
+    pub fn erase(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        key: &[u8],
+    ) -> Result<(), ConfigError> {
+        self.append_record(flash, sys_clk, crc, key, None)
+    }
+
+    /// Alias for [`Self::erase`], matching the `open`/`put`/`get`/`remove`
+    /// naming other flash-backed key/value stores use.
+    pub fn remove(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        key: &[u8],
+    ) -> Result<(), ConfigError> {
+        self.erase(flash, sys_clk, crc, key)
+    }
+
+    fn append_record(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        key: &[u8],
+        value: Option<&[u8]>,
+    ) -> Result<(), ConfigError> {
+        if key.len() > MAX_KEY_LEN || value.is_some_and(|value| value.len() > MAX_VALUE_LEN) {
+            return Err(ConfigError::TooLarge);
+        }
+
+        let value_len = value.map_or(0, <[u8]>::len) as u32;
+        let record_len = RECORD_HEADER_LEN as u32 + key.len() as u32 + value_len + RECORD_CRC_LEN as u32;
+        if record_len > FLASH_PAGE_SIZE {
+            return Err(ConfigError::TooLarge);
+        }
+
+        let page = self.active_page();
+        let end = for_each_record(page, crc, |_, _, _, _| {});
+        let write_addr = if end + record_len <= page + FLASH_PAGE_SIZE {
+            end
+        } else {
+            self.compact(flash, sys_clk, crc, page)?
+        };
+
+        self.write_record(flash, sys_clk, crc, write_addr, key, value)
+    }
+
+    /// Copies every live (not superseded, not tombstoned) record out of
+    /// `full_page` into the spare page, erasing `full_page`'s old spare
+    /// first, then erases `full_page` itself once everything worth keeping
+    /// has a home. Returns the address the next record should be appended at
+    /// in the now-active spare page.
+    fn compact(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        full_page: u32,
+    ) -> Result<u32, ConfigError> {
+        let spare_page = self.other_page(full_page);
+
+        // SAFETY: `spare_page` is the page `active_page` didn't select, which
+        // only ever holds data right after a previous compaction wrote into
+        // it -- by construction it is fully erased here, so erasing it again
+        // up front (to guarantee a clean slate even on a first-ever
+        // compaction) cannot lose anything live.
+        unsafe {
+            flash.page_erase(spare_page, sys_clk)?;
+        }
+
+        let mut write_addr = spare_page;
+        let mut addr = full_page;
+        while let Some((key_len, val_len, record_len)) = parse_record_at(full_page, addr, crc) {
+            let key = record_key(addr, key_len);
+            let is_live =
+                val_len != TOMBSTONE_VAL_LEN && !key_superseded(full_page, addr + record_len, key, crc);
+            if is_live {
+                let value = record_value(addr, key_len, val_len);
+                self.write_record(flash, sys_clk, crc, write_addr, key, Some(value))?;
+                write_addr += record_len;
+            }
+            addr += record_len;
+        }
+
+        // SAFETY: every live record in `full_page` was just copied into
+        // `spare_page` above, so erasing `full_page` cannot lose anything
+        // that hasn't already been superseded or explicitly erased.
+        unsafe {
+            flash.page_erase(full_page, sys_clk)?;
+        }
+
+        Ok(write_addr)
+    }
+
+    /// Writes one record at `addr`, which must already be erased flash --
+    /// the next free offset in the active page, or a spare page [`Self::compact`]
+    /// just erased. The trailing CRC is written in its own flash write after
+    /// the header/key/value, so a reset in between leaves no valid CRC
+    /// behind and [`parse_record_at`] reads the record as torn.
+    fn write_record(
+        &self,
+        flash: &FlashController,
+        sys_clk: &SystemClock,
+        crc: &mut CrcCalculator<CrcDataU32>,
+        addr: u32,
+        key: &[u8],
+        value: Option<&[u8]>,
+    ) -> Result<(), ConfigError> {
+        let val_len = value.map_or(TOMBSTONE_VAL_LEN, |value| value.len() as u16);
+
+        let mut header = [0u8; RECORD_HEADER_LEN];
+        header[0] = key.len() as u8;
+        header[1..3].copy_from_slice(&val_len.to_le_bytes());
+
+        crc.configure(CrcAlgorithm::CRC32_ISO_HDLC);
+        crc.update(&header);
+        crc.update(key);
+        if let Some(value) = value {
+            crc.update(value);
+        }
+        let checksum = crc.finalize();
+
+        // SAFETY: per this function's contract, `addr` points at erased
+        // flash wide enough for the whole record -- callers computed
+        // `record_len` against the same page bounds before picking `addr`.
+        unsafe {
+            flash.write(addr, &header, sys_clk)?;
+            flash.write(addr + RECORD_HEADER_LEN as u32, key, sys_clk)?;
+            if let Some(value) = value {
+                flash.write(addr + RECORD_HEADER_LEN as u32 + key.len() as u32, value, sys_clk)?;
+            }
+        }
+
+        let crc_addr = addr + RECORD_HEADER_LEN as u32 + key.len() as u32 + value.map_or(0, <[u8]>::len) as u32;
+        // SAFETY: as above; written last so it's the one thing missing from
+        // a record left behind by a reset mid-write.
+        unsafe {
+            flash.write(crc_addr, &checksum.to_le_bytes(), sys_clk)?;
+        }
+
+        Ok(())
+    }
+}
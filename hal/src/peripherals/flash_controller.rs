@@ -30,8 +30,25 @@
 //!
 //!    assert!(u32::from_le_bytes(data_read) == test_val);
 //!    ```
+// Note: unlike the I2C FIFO (see `crate::peripherals::i2c::master::I2CMaster::with_dma`),
+// the FLC has no FIFO or DMA request line to hand a transfer off to --
+// `flc_write128_primitive`/`flc_page_erase_primitive` are RAM-resident
+// routines that poll the controller's own busy bit, and the 1MHz programming
+// clock (not bus bandwidth) is what limits their throughput. There's nothing
+// here for a DMA channel to usefully drain.
 use crate::peripherals::oscillator::SystemClock;
+#[cfg(feature = "fip")]
+use crate::peripherals::rand_chacha::ChaChaRng;
+use core::cell::Ref;
+use embedded_storage::nor_flash::{
+    check_erase, check_read, check_write, ErrorType, MultiwriteNorFlash, NorFlash, NorFlashError,
+    NorFlashErrorKind, ReadNorFlash,
+};
+#[cfg(feature = "fip")]
+use fault_injection_protection_arm::{FaultInjectionPrevention, SecureBool};
 use max78000::{FLC, GCR, ICC0};
+#[cfg(feature = "fip")]
+use subtle::ConstantTimeEq;
 
 /// Flash memory base address.
 pub const FLASH_MEM_BASE: u32 = 0x1000_0000;
@@ -42,6 +59,29 @@ pub const FLASH_MEM_SIZE: u32 = 0x0008_0000;
 /// Flash page size.
 pub const FLASH_PAGE_SIZE: u32 = 0x2000;
 
+/// Witness that the calling code is executing entirely from SRAM, not
+/// flash. Required to call [`FlashController::mass_erase`], which would
+/// otherwise immediately invalidate the program counter (and everything it
+/// will jump to) if the running program lived in the flash being erased.
+#[derive(Debug, Clone, Copy)]
+pub struct RunningFromSram(());
+
+impl RunningFromSram {
+    /// Asserts that the calling code is executing entirely from SRAM.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the currently executing program, and
+    /// everything it will jump to before and during the
+    /// [`FlashController::mass_erase`] call this is passed to, lives in
+    /// SRAM rather than flash -- e.g. via a linker script/`#[link_section]`
+    /// placement that relocates the relevant code, or a bootloader stage
+    /// that has already copied itself there.
+    pub unsafe fn new() -> Self {
+        Self(())
+    }
+}
+
 /// Error values a flash write operation throws.
 #[derive(Debug)]
 pub enum FlashErr {
@@ -51,6 +91,13 @@ pub enum FlashErr {
     PtrBoundsErr,
     /// The flash controller clock could not be set to 1MHz
     FlcClkErr,
+    /// [`FlashController::write_verified`]/[`FlashController::page_erase_verified`]
+    /// read back the affected region after programming and it didn't match
+    /// what was written (or, for erase, wasn't fully erased).
+    VerifyError,
+    /// The FLC latched its access-fail flag (`INTR.AF`) during the
+    /// operation -- e.g. a write or erase targeting a protected region.
+    AccessViolation,
 }
 
 /// Flash Controller peripheral.
@@ -84,6 +131,20 @@ unsafe extern "C" {
     /// - `sys_clk_freq` must be divisible by one million (`1_000_000`).
     pub unsafe fn flc_page_erase_primitive(address: *mut u8, sys_clk_freq: u32);
 
+    /// Erases the entirety of flash memory, via `FLC.ctrl`'s `erase_code`/`me` fields.
+    ///
+    /// Safety:
+    /// - The caller must hold a shared reference to the [`FLC`], [`ICC0`], and [`GCR`] registers.
+    /// - `sys_clk_freq` must be equal to `freq / div` where `freq` is the frequency of
+    ///   the current system clock, and `div` is the divider of the system clock.
+    /// - `sys_clk_freq` must be divisible by one million (`1_000_000`).
+    /// - The calling program must be executing entirely from SRAM: mass-erasing flash
+    ///   out from under a flash-resident program is immediate undefined behavior.
+    ///
+    /// Panics if any of the following preconditions are not true:
+    /// - `sys_clk_freq` must be divisible by one million (`1_000_000`).
+    pub unsafe fn flc_mass_erase_primitive(sys_clk_freq: u32);
+
     /// Writes a little-endian 128-bit flash word into flash memory.
     ///
     /// Safety:
@@ -130,7 +191,7 @@ impl<'gcr, 'icc> FlashController<'gcr, 'icc> {
     /// Calculates the correct `sys_clk_freq` from the passed [`SystemClock`] for FLC primitives.
     /// Returns an `Err` if the calculated frequency is not a multiple of `1_000_000`.
     fn get_clock_divisor(sys_clk: &SystemClock) -> Result<u32, FlashErr> {
-        let sys_clk_freq = sys_clk.get_freq() / sys_clk.get_div() as u32;
+        let sys_clk_freq = (sys_clk.get_freq() / u32::from(sys_clk.get_div())).to_hz();
         if sys_clk_freq % 1_000_000 != 0 {
             return Err(FlashErr::FlcClkErr);
         }
@@ -326,6 +387,10 @@ impl<'gcr, 'icc> FlashController<'gcr, 'icc> {
             flc_write128_primitive(address as *mut [u32; 4], data.as_ptr(), sys_clk_freq);
         });
 
+        if self.take_access_fault() {
+            return Err(FlashErr::AccessViolation);
+        }
+
         Ok(())
     }
 
@@ -349,16 +414,473 @@ impl<'gcr, 'icc> FlashController<'gcr, 'icc> {
             flc_page_erase_primitive(address as *mut u8, sys_clk_freq);
         });
 
+        if self.take_access_fault() {
+            return Err(FlashErr::AccessViolation);
+        }
+
         Ok(())
     }
 
     /// Erases the entire flash.
     ///
+    /// Requires a [`RunningFromSram`] witness: mass-erasing flash out from
+    /// under the program that's executing it is always undefined behavior,
+    /// so unlike [`Self::write`]/[`Self::page_erase`] (where the danger
+    /// depends on *which* addresses are touched) there is no amount of
+    /// caller care that makes this safe to call from flash at all. Making
+    /// `sram` a precondition the caller has to construct puts that
+    /// invariant in the type system instead of leaving it to prose.
+    pub fn mass_erase(&self, sys_clk: &SystemClock, _sram: RunningFromSram) -> Result<(), FlashErr> {
+        let sys_clk_freq = Self::get_clock_divisor(sys_clk)?;
+
+        // SAFETY: per the safety contract of [`flc_mass_erase_primitive`]:
+        // - we hold a reference (in `self`) to the FLC, ICC0, and GCR registers.
+        // - `sys_clk_freq` is calculated as `freq / div` of the current system clock above.
+        // - `_sram` is this function's witness that the caller is executing from SRAM.
+        critical_section::with(|_| unsafe {
+            flc_mass_erase_primitive(sys_clk_freq);
+        });
+
+        if self.take_access_fault() {
+            return Err(FlashErr::AccessViolation);
+        }
+
+        Ok(())
+    }
+
+    /// Reads and clears the FLC's access-fail interrupt flag, returning whether
+    /// an access violation was latched since it was last cleared.
+    fn take_access_fault(&self) -> bool {
+        let af = self.flc.intr().read().af().bit_is_set();
+        self.flc.intr().modify(|_, w| w.af().clear_bit());
+        af
+    }
+
+    /// Bundles `sys_clk` with this controller so it can implement
+    /// `embedded-storage`'s [`ReadNorFlash`]/[`NorFlash`]/[`MultiwriteNorFlash`]
+    /// traits, which take no clock parameter of their own -- the same way
+    /// [`crate::peripherals::i2c::master::I2CMaster::with_dma`] equips a
+    /// peripheral with an optional extra resource after construction.
+    pub fn with_system_clock<'clk>(
+        self,
+        sys_clk: Ref<'clk, SystemClock>,
+    ) -> FlashControllerNorFlash<'gcr, 'icc, 'clk> {
+        FlashControllerNorFlash {
+            controller: self,
+            sys_clk,
+        }
+    }
+
+    /// Like [`Self::write`], but re-reads the written region afterward and
+    /// returns [`FlashErr::VerifyError`] on a mismatch -- catching, e.g.,
+    /// `flc_write128_primitive` silently no-op'ing on a word that wasn't
+    /// actually in the erased state. Costs a readback pass `write` doesn't
+    /// pay, so it's opt-in rather than `write`'s default behavior.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::write`].
+    pub unsafe fn write_verified(
+        &self,
+        address: u32,
+        data: &[u8],
+        sys_clk: &SystemClock,
+    ) -> Result<(), FlashErr> {
+        // SAFETY: the caller upholds `Self::write`'s safety contract.
+        unsafe { self.write(address, data, sys_clk) }?;
+
+        if !verify_bytes(address, data)? {
+            return Err(FlashErr::VerifyError);
+        }
+
+        Ok(())
+    }
+
+    /// Like [`Self::page_erase`], but re-reads the page afterward and
+    /// returns [`FlashErr::VerifyError`] if it isn't fully erased.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::page_erase`].
+    pub unsafe fn page_erase_verified(
+        &self,
+        address: u32,
+        sys_clk: &SystemClock,
+    ) -> Result<(), FlashErr> {
+        // SAFETY: the caller upholds `Self::page_erase`'s safety contract.
+        unsafe { self.page_erase(address, sys_clk) }?;
+
+        if !verify_erased(address)? {
+            return Err(FlashErr::VerifyError);
+        }
+
+        Ok(())
+    }
+
+    /// Fault-injection-hardened counterpart to [`Self::write128`], gated
+    /// behind the `fip` feature. The FLC sits in this crate's
+    /// security-critical trust boundary, so a glitch that skips the bounds
+    /// check, skips the "is this word actually erased" precondition, or
+    /// flips the post-write readback comparison could otherwise persist
+    /// corrupted (or out-of-bounds) data in flash across reboots. Every one
+    /// of those steps is threaded through
+    /// [`FaultInjectionPrevention::critical_if`]/[`FaultInjectionPrevention::critical_write`]/
+    /// [`FaultInjectionPrevention::critical_read`] so a single glitched
+    /// branch or comparison can't cause that.
+    ///
+    /// Operates at the same 128-bit-word granularity as [`Self::write128`]
+    /// rather than [`Self::write`]'s arbitrary-length chunking -- callers
+    /// writing more than one flash word call this once per word, the same
+    /// way [`Self::write`] calls [`Self::write128`] in a loop.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::write128`]. Additionally, the flash word at `address`
+    /// must be in the *erased* state -- this is itself re-checked under
+    /// [`FaultInjectionPrevention::critical_if`], but callers must still
+    /// uphold the ordinary safety contract that lets that check run at all
+    /// (e.g. `address` pointing to mapped flash).
+    #[cfg(feature = "fip")]
+    pub unsafe fn write128_fip_hardened(
+        &self,
+        address: u32,
+        data: &[u32; 4],
+        sys_clk: &SystemClock,
+        fip: &FaultInjectionPrevention,
+        rng: &mut ChaChaRng,
+    ) -> Result<(), FlashErr> {
+        let sys_clk_freq = Self::get_clock_divisor(sys_clk)?;
+
+        fip.critical_if(
+            |_rng| SecureBool::from(address & 0xF == 0),
+            |_rng| Ok(()),
+            |_rng| Err(FlashErr::AddressNotAligned128),
+            rng,
+        )?;
+
+        fip.critical_if(
+            |_rng| SecureBool::from(check_address_bounds(address..address + 16).is_ok()),
+            |_rng| Ok(()),
+            |_rng| Err(FlashErr::PtrBoundsErr),
+            rng,
+        )?;
+
+        let mut current = [0u8; 16];
+        Self::read_bytes(address, &mut current)?;
+        fip.critical_if(
+            |_rng| SecureBool::from(current == [0xFFu8; 16]),
+            |_rng| Ok(()),
+            |_rng| Err(FlashErr::VerifyError),
+            rng,
+        )?;
+
+        // SAFETY: the caller upholds `write128`'s safety contract, which
+        // `address`/`data`'s alignment and bounds were just re-checked
+        // against above.
+        let dst: &mut [u32; 4] = unsafe { &mut *(address as *mut [u32; 4]) };
+        fip.critical_write(
+            dst,
+            *data,
+            |dst, src| {
+                // SAFETY: see `write128`'s own SAFETY comment; the same
+                // contract applies here.
+                critical_section::with(|_| unsafe {
+                    flc_write128_primitive(dst, src.as_ptr(), sys_clk_freq);
+                });
+            },
+            rng,
+        );
+
+        if self.take_access_fault() {
+            return Err(FlashErr::AccessViolation);
+        }
+
+        let mut expected = [0u8; 16];
+        for (i, word) in data.iter().enumerate() {
+            expected[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        // SAFETY: `address` was re-checked as 128-bit aligned and in-bounds above.
+        let written: &[u8; 16] = unsafe { &*(address as *const [u8; 16]) };
+
+        fip.critical_if(
+            |rng| SecureBool::from(bool::from(fip.critical_read(written, rng).ct_eq(&expected))),
+            |_rng| Ok(()),
+            |_rng| Err(FlashErr::VerifyError),
+            rng,
+        )
+    }
+
+    /// Fault-injection-hardened counterpart to [`Self::page_erase`], gated
+    /// behind the `fip` feature. See [`Self::write128_fip_hardened`] for why
+    /// this matters for the FLC specifically; here the bounds check and the
+    /// post-erase "did it actually come back all-`0xFF`" readback are each
+    /// threaded through [`FaultInjectionPrevention::critical_if`]/
+    /// [`FaultInjectionPrevention::critical_read`], checked 16 bytes at a
+    /// time the same way [`verify_erased`] reads a page back.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::page_erase`].
+    #[cfg(feature = "fip")]
+    pub unsafe fn page_erase_fip_hardened(
+        &self,
+        address: u32,
+        sys_clk: &SystemClock,
+        fip: &FaultInjectionPrevention,
+        rng: &mut ChaChaRng,
+    ) -> Result<(), FlashErr> {
+        fip.critical_if(
+            |_rng| SecureBool::from(check_address_bounds(address..address + 1).is_ok()),
+            |_rng| Ok(()),
+            |_rng| Err(FlashErr::PtrBoundsErr),
+            rng,
+        )?;
+
+        let sys_clk_freq = Self::get_clock_divisor(sys_clk)?;
+
+        // SAFETY: per the safety contract of [`flc_page_erase_primitive`];
+        // see `page_erase`'s own SAFETY comment.
+        critical_section::with(|_| unsafe {
+            flc_page_erase_primitive(address as *mut u8, sys_clk_freq);
+        });
+
+        if self.take_access_fault() {
+            return Err(FlashErr::AccessViolation);
+        }
+
+        let page_base = address & !(FLASH_PAGE_SIZE - 1);
+        let mut offset = 0u32;
+        while offset < FLASH_PAGE_SIZE {
+            // SAFETY: `address` was checked in-bounds above, and flash starts
+            // on a page boundary and holds whole pages, so the page
+            // `page_base..page_base + FLASH_PAGE_SIZE` is in bounds too.
+            let chunk: &[u8; 16] = unsafe { &*((page_base + offset) as *const [u8; 16]) };
+            fip.critical_if(
+                |rng| SecureBool::from(bool::from(fip.critical_read(chunk, rng).ct_eq(&[0xFFu8; 16]))),
+                |_rng| Ok(()),
+                |_rng| Err(FlashErr::VerifyError),
+                rng,
+            )?;
+            offset += 16;
+        }
+
+        Ok(())
+    }
+}
+
+impl NorFlashError for FlashErr {
+    fn kind(&self) -> NorFlashErrorKind {
+        match self {
+            FlashErr::PtrBoundsErr => NorFlashErrorKind::OutOfBounds,
+            FlashErr::AddressNotAligned128 => NorFlashErrorKind::NotAligned,
+            FlashErr::FlcClkErr | FlashErr::VerifyError | FlashErr::AccessViolation => {
+                NorFlashErrorKind::Other
+            }
+        }
+    }
+}
+
+impl From<NorFlashErrorKind> for FlashErr {
+    fn from(kind: NorFlashErrorKind) -> Self {
+        match kind {
+            NorFlashErrorKind::NotAligned => FlashErr::AddressNotAligned128,
+            // `check_read`/`check_write`/`check_erase` only ever report
+            // `NotAligned` or `OutOfBounds`, but the enum is
+            // `#[non_exhaustive]`.
+            _ => FlashErr::PtrBoundsErr,
+        }
+    }
+}
+
+/// [`FlashController`] bundled with the [`SystemClock`] its
+/// `embedded-storage` trait impls need; see [`FlashController::with_system_clock`].
+pub struct FlashControllerNorFlash<'gcr, 'icc, 'clk> {
+    controller: FlashController<'gcr, 'icc>,
+    sys_clk: Ref<'clk, SystemClock>,
+}
+
+impl ErrorType for FlashControllerNorFlash<'_, '_, '_> {
+    type Error = FlashErr;
+}
+
+impl ReadNorFlash for FlashControllerNorFlash<'_, '_, '_> {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        check_read(self, offset, bytes.len())?;
+        FlashController::read_bytes(FLASH_MEM_BASE + offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        FLASH_MEM_SIZE as usize
+    }
+}
+
+impl NorFlash for FlashControllerNorFlash<'_, '_, '_> {
+    const WRITE_SIZE: usize = 16;
+    const ERASE_SIZE: usize = FLASH_PAGE_SIZE as usize;
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        check_erase(self, from, to)?;
+
+        let mut address = FLASH_MEM_BASE + from;
+        while address < FLASH_MEM_BASE + to {
+            // SAFETY: `check_erase` confirmed `from..to` lies within flash
+            // and is erase-size aligned; as with `FlashController::page_erase`,
+            // the caller is responsible for rewriting any erased instruction
+            // memory before execution reaches it.
+            unsafe { self.controller.page_erase(address, &self.sys_clk) }?;
+            address += FLASH_PAGE_SIZE;
+        }
+
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        check_write(self, offset, bytes.len())?;
+        // SAFETY: as with `FlashController::write`, the caller is
+        // responsible for `bytes` being valid instructions if `offset`
+        // falls within the running program's instruction memory.
+        unsafe { self.controller.write(FLASH_MEM_BASE + offset, bytes, &self.sys_clk) }
+    }
+}
+
+impl MultiwriteNorFlash for FlashControllerNorFlash<'_, '_, '_> {}
+
+/// Errors from a [`Flash`] operation.
+///
+/// [`FlashErr`] catches precondition violations (bad alignment,
+/// out-of-bounds addresses, an unsupported clock) and hardware-reported
+/// access violations; these variants add the read-back verification
+/// [`Flash`] performs on top of that.
+#[derive(Debug)]
+pub enum FlashError {
+    /// The FLC's access-fail flag (`INTR.AF`) was set during the operation.
+    AccessViolation,
+    /// A write completed, but reading the data back did not match what was written.
+    ProgramFailed,
+    /// An erase completed, but the page did not read back as fully erased.
+    EraseFailed,
+    /// `address` was not aligned as the operation requires.
+    Unaligned,
+    /// `address`, or the range it covers, falls outside flash memory.
+    OutOfBounds,
+    /// The flash controller clock could not be set to 1 MHz.
+    ClockError,
+}
+
+impl From<FlashErr> for FlashError {
+    fn from(err: FlashErr) -> Self {
+        match err {
+            FlashErr::AddressNotAligned128 => FlashError::Unaligned,
+            FlashErr::PtrBoundsErr => FlashError::OutOfBounds,
+            FlashErr::FlcClkErr => FlashError::ClockError,
+            // `FlashError::ProgramFailed` already covers "read-back didn't
+            // match"; `write_verified`/`page_erase_verified` just perform
+            // that check directly instead of through the `Flash` wrapper.
+            FlashErr::VerifyError => FlashError::ProgramFailed,
+            FlashErr::AccessViolation => FlashError::AccessViolation,
+        }
+    }
+}
+
+/// Reads `expected.len()` bytes back from `address` and compares them, in
+/// fixed-size chunks so callers don't need to size a buffer to `expected`.
+fn verify_bytes(address: u32, expected: &[u8]) -> Result<bool, FlashErr> {
+    let mut buf = [0u8; 16];
+    let mut offset = 0u32;
+    for chunk in expected.chunks(buf.len()) {
+        FlashController::read_bytes(address + offset, &mut buf[..chunk.len()])?;
+        if &buf[..chunk.len()] != chunk {
+            return Ok(false);
+        }
+        offset += chunk.len() as u32;
+    }
+    Ok(true)
+}
+
+/// Reads back the page containing `address` and checks that every byte is
+/// in the erased state (`0xFF`).
+fn verify_erased(address: u32) -> Result<bool, FlashErr> {
+    let page_base = address & !(FLASH_PAGE_SIZE - 1);
+    let mut buf = [0u8; 16];
+    let mut offset = 0u32;
+    while offset < FLASH_PAGE_SIZE {
+        FlashController::read_bytes(page_base + offset, &mut buf)?;
+        if buf.iter().any(|&byte| byte != 0xFF) {
+            return Ok(false);
+        }
+        offset += buf.len() as u32;
+    }
+    Ok(true)
+}
+
+/// Safe, hardware-verified wrapper around [`FlashController`].
+///
+/// `FlashController`'s primitives already surface an access violation (see
+/// `FlashErr::AccessViolation`), but don't otherwise look back at the
+/// hardware once a write or erase completes, so a faulted program/erase that
+/// doesn't trip the access-fail flag is invisible to the caller. `Flash`
+/// additionally reads the result back and compares it, surfacing a mismatch
+/// as a [`FlashError`] so callers keep control flow -- mirroring how
+/// `stm32f4xx-hal`'s flash driver decodes its `SR` error bits rather than
+/// panicking. Genuine fault-injection anomalies still fall through to
+/// `FlashController`'s `never_exit!()` RAM primitives; this layer only covers
+/// failures a caller can meaningfully recover from.
+pub struct Flash<'gcr, 'icc> {
+    controller: FlashController<'gcr, 'icc>,
+}
+
+impl<'gcr, 'icc> Flash<'gcr, 'icc> {
+    /// Wraps an existing [`FlashController`] with hardware-verified writes and erases.
+    pub fn new(controller: FlashController<'gcr, 'icc>) -> Self {
+        Self { controller }
+    }
+
+    /// Reads data from flash. See [`FlashController::read_bytes`].
+    pub fn read_bytes(address: u32, data: &mut [u8]) -> Result<(), FlashError> {
+        FlashController::read_bytes(address, data)?;
+        Ok(())
+    }
+
+    /// Writes `data` to flash starting at `address`, then verifies the bytes
+    /// read back match. `FlashController::write` already surfaces an access
+    /// violation as an error, via `From<FlashErr> for FlashError`.
+    ///
     /// # Safety
     ///
-    /// Mass erase clears the whole flash. Program must be executed from SRAM.
-    pub unsafe fn mass_erase(&self) -> Result<(), FlashErr> {
-        // Make sure to disable and enable icc0 at the beginning and end of function
-        todo!()
+    /// See [`FlashController::write`].
+    pub unsafe fn write(
+        &self,
+        address: u32,
+        data: &[u8],
+        sys_clk: &SystemClock,
+    ) -> Result<(), FlashError> {
+        // SAFETY: the caller upholds `FlashController::write`'s safety contract.
+        unsafe { self.controller.write(address, data, sys_clk) }?;
+
+        if !verify_bytes(address, data)? {
+            return Err(FlashError::ProgramFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Erases the page at `address`, then verifies the page reads back fully
+    /// erased. `FlashController::page_erase` already surfaces an access
+    /// violation as an error, via `From<FlashErr> for FlashError`.
+    ///
+    /// # Safety
+    ///
+    /// See [`FlashController::page_erase`].
+    pub unsafe fn page_erase(&self, address: u32, sys_clk: &SystemClock) -> Result<(), FlashError> {
+        // SAFETY: the caller upholds `FlashController::page_erase`'s safety contract.
+        unsafe { self.controller.page_erase(address, sys_clk) }?;
+
+        if !verify_erased(address)? {
+            return Err(FlashError::EraseFailed);
+        }
+
+        Ok(())
     }
 }
@@ -91,6 +91,20 @@ pub struct GpioPort<'regs, Metadata: GpioPortMetadata<'regs> + ?Sized, const PIN
     // expressions are allowed within const generics like associated constants from generic types
     pub(crate) regs: Metadata::GpioRegs,
     pub(crate) pin_taken: [Cell<bool>; PIN_CT],
+    // Per-pin interrupt listener, at most one per pin. Stored here rather than
+    // on the pin handle so it survives the handle being dropped/retaken and so
+    // a port-level `handle_interrupt` can reach every pin's listener from the
+    // port alone. `fn()` rather than a closure type keeps registering a
+    // listener from outside an ISR, and invoking it from inside one, both
+    // just a `Cell` get/set with no locking.
+    pub(crate) listeners: [Cell<Option<fn()>>; PIN_CT],
+    // Whether the pin is configured for open-drain output, read back by
+    // `OutputPin::set_high`/`set_low` to decide whether to actively drive or
+    // release-to-high-impedance. Stored here (not on the output pin type) for
+    // the same reason as `listeners`: the hardware can't distinguish
+    // "open-drain, driving low" from "push-pull, driving low" by register
+    // state alone.
+    pub(crate) open_drain: [Cell<bool>; PIN_CT],
 }
 
 impl<'t, 'regs, Metadata: GpioPortMetadata<'regs> + ?Sized, const PIN_CT: usize>
@@ -101,6 +115,8 @@ impl<'t, 'regs, Metadata: GpioPortMetadata<'regs> + ?Sized, const PIN_CT: usize>
         Self {
             regs,
             pin_taken: array::from_fn(|_| Default::default()),
+            listeners: array::from_fn(|_| Default::default()),
+            open_drain: array::from_fn(|_| Default::default()),
         }
     }
 
@@ -149,6 +165,7 @@ pub fn new_gpio3<'a>(gpio3: &'a MCR) -> GpioPort<'a, LowPowerGpio<'a>, 2> {
 }
 
 /// Represents the I/O mode of a pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PinIoMode {
     /// Input mode (The default after power-on-reset).
     Input,
@@ -159,6 +176,7 @@ pub enum PinIoMode {
 
 /// Represents the operating mode of a pin. For a list of what each alternate function
 /// does for each pin, see page 28 of [chip datasheet](https://www.analog.com/media/en/technical-documentation/data-sheets/MAX78000.pdf).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PinOperatingMode {
     /// This operating mode allows the pin to be used for general purpose I/O. This is
     /// the default operating mode after power-on-reset for all pins except the pins
@@ -15,11 +15,13 @@
 //! assert!(pin.is_set_high().unwrap());
 //! ```
 
+use core::cell::Cell;
 use core::marker::PhantomData;
 
+use max78000::gpio0::RegisterBlock;
 use sealed::sealed;
 
-use port_num_types::GpioPortNum;
+use port_num_types::{GpioOne, GpioPortNum, GpioTwo, GpioZero};
 
 use super::pin_traits::{ErrorType, InputPin, IoPin, OutputPin, PinState, StatefulOutputPin};
 use super::private::NonConstructible;
@@ -30,6 +32,9 @@ use super::{
 
 pub mod port_num_types;
 
+/// Async, interrupt-driven `Wait` implementation for active GPIO input pins.
+pub mod asynch;
+
 // TODO FOR ASTRA:
 // [x] make input pin and output pin structs
 //     [x] pin types should implement InputPin for input pin and StatefulOutputPin for output pin
@@ -44,10 +49,10 @@ pub mod port_num_types;
 //
 // [x] see low power module for example on everything above
 //
-// [ ] add interrupt support (input mode only)
-//     [ ] just need to support adding 1 listener per pin
-//     [ ] the listener can either be low/high level triggered, rising/falling edge triggered, or dual edge triggered
-//     [ ] if developer provides another listener through same function, overwrite previous listener
+// [x] add interrupt support (input mode only)
+//     [x] the trigger can be low/high level triggered, rising/falling edge triggered, or dual edge triggered
+//     [x] just need to support adding 1 listener per pin
+//     [x] if developer provides another listener through same function, overwrite previous listener
 // [-] add documentation
 //     [x] a module-level doc comment
 //     [x] public functions within this module that aren't trait impl functions
@@ -142,20 +147,47 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> OutputPin
     for ActiveOutputPin<'_, PortNum, PIN_CT>
 {
     fn set_high(&mut self) -> Result<(), Self::Error> {
+        let bit = 1 << self.0.pin_idx;
+
         self.0
             .port
             .regs
             .out_set()
-            .write(|w| w.gpio_out_set().variant(1 << self.0.pin_idx));
+            .write(|w| w.gpio_out_set().variant(bit));
+
+        if self.0.port.open_drain[self.0.pin_idx].get() {
+            // Open-drain high: release the pad to high-impedance instead of
+            // actively driving it. A pull-up -- external, or internal via
+            // `set_pull_mode`/`PullMode::WeakPullup` on the pin before it was
+            // converted to an output -- is what pulls the line high.
+            self.0
+                .port
+                .regs
+                .outen_clr()
+                .write(|w| w.all().variant(bit));
+        }
+
         Ok(())
     }
 
     fn set_low(&mut self) -> Result<(), Self::Error> {
+        let bit = 1 << self.0.pin_idx;
+
+        if self.0.port.open_drain[self.0.pin_idx].get() {
+            // Open-drain low: actively drive it, re-enabling the output
+            // driver `set_high` may have released.
+            self.0
+                .port
+                .regs
+                .outen_set()
+                .write(|w| w.all().variant(bit));
+        }
+
         self.0
             .port
             .regs
             .out_clr()
-            .write(|w| w.gpio_out_clr().variant(1 << self.0.pin_idx));
+            .write(|w| w.gpio_out_clr().variant(bit));
         Ok(())
     }
 }
@@ -170,6 +202,296 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> StatefulOutputPin
     fn is_set_low(&mut self) -> Result<bool, Self::Error> {
         self.is_set_high().map(|x| !x)
     }
+
+    // Overridden to decide the direction from a single `out` read instead of
+    // the default `StatefulOutputPin::toggle` impl's `is_set_high` call, and
+    // go straight through `set_high`/`set_low` so open-drain pins toggle
+    // `outen` correctly too.
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        if self.0.port.regs.out().read().bits() & (1 << self.0.pin_idx) != 0 {
+            self.set_low()
+        } else {
+            self.set_high()
+        }
+    }
+}
+
+/// A pin surrendered to the analog subsystem (ADC/comparator front end), e.g.
+/// one of GPIO2's `AINx` pins. Unlike [`ActiveInputPin`]/[`ActiveOutputPin`],
+/// this implements neither `InputPin` nor `OutputPin`: the digital input
+/// buffer is disabled, so reading it back would be meaningless, and the
+/// output driver is disabled too so the pad doesn't fight whatever analog
+/// front end it's routed to. See [`ActivePinHandle::into_analog_pin`].
+pub struct ActiveAnalogPin<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize>(
+    ActivePinHandle<'a, PortNum, PIN_CT>,
+);
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    ActiveAnalogPin<'a, PortNum, PIN_CT>
+{
+    /// Hands back the underlying pin handle, e.g. to reconfigure the pin as
+    /// digital I/O again with [`IoPin::into_input_pin`]/[`IoPin::into_output_pin`].
+    pub fn into_pin_handle(self) -> ActivePinHandle<'a, PortNum, PIN_CT> {
+        self.0
+    }
+}
+
+/// Type-erased [`ActiveInputPin`], holding ownership of whichever GPIO
+/// port/pin combination it was claimed from. Lets a multi-instance
+/// peripheral (e.g. [`crate::peripherals::uart::Uart`]) be generic only over
+/// which hardware instance it drives, not also over which GPIO port backs
+/// its pins, the way [`crate::peripherals::timer::AnyTimer`] erases which
+/// `TMR`/`TMR1`/`TMR2`/`TMR3` register block backs a [`Clock`](crate::peripherals::timer::Clock).
+pub enum AnyInputPin<'a> {
+    /// Held on GPIO0.
+    Gpio0(ActiveInputPin<'a, GpioZero, 31>),
+    /// Held on GPIO1.
+    Gpio1(ActiveInputPin<'a, GpioOne, 10>),
+    /// Held on GPIO2.
+    Gpio2(ActiveInputPin<'a, GpioTwo, 8>),
+}
+
+impl<'a> From<ActiveInputPin<'a, GpioZero, 31>> for AnyInputPin<'a> {
+    fn from(pin: ActiveInputPin<'a, GpioZero, 31>) -> Self {
+        AnyInputPin::Gpio0(pin)
+    }
+}
+
+impl<'a> From<ActiveInputPin<'a, GpioOne, 10>> for AnyInputPin<'a> {
+    fn from(pin: ActiveInputPin<'a, GpioOne, 10>) -> Self {
+        AnyInputPin::Gpio1(pin)
+    }
+}
+
+impl<'a> From<ActiveInputPin<'a, GpioTwo, 8>> for AnyInputPin<'a> {
+    fn from(pin: ActiveInputPin<'a, GpioTwo, 8>) -> Self {
+        AnyInputPin::Gpio2(pin)
+    }
+}
+
+/// Type-erased [`ActiveOutputPin`]; see [`AnyInputPin`].
+pub enum AnyOutputPin<'a> {
+    /// Held on GPIO0.
+    Gpio0(ActiveOutputPin<'a, GpioZero, 31>),
+    /// Held on GPIO1.
+    Gpio1(ActiveOutputPin<'a, GpioOne, 10>),
+    /// Held on GPIO2.
+    Gpio2(ActiveOutputPin<'a, GpioTwo, 8>),
+}
+
+impl<'a> From<ActiveOutputPin<'a, GpioZero, 31>> for AnyOutputPin<'a> {
+    fn from(pin: ActiveOutputPin<'a, GpioZero, 31>) -> Self {
+        AnyOutputPin::Gpio0(pin)
+    }
+}
+
+impl<'a> From<ActiveOutputPin<'a, GpioOne, 10>> for AnyOutputPin<'a> {
+    fn from(pin: ActiveOutputPin<'a, GpioOne, 10>) -> Self {
+        AnyOutputPin::Gpio1(pin)
+    }
+}
+
+impl<'a> From<ActiveOutputPin<'a, GpioTwo, 8>> for AnyOutputPin<'a> {
+    fn from(pin: ActiveOutputPin<'a, GpioTwo, 8>) -> Self {
+        AnyOutputPin::Gpio2(pin)
+    }
+}
+
+impl ErrorType for AnyOutputPin<'_> {
+    type Error = GpioError;
+}
+
+impl OutputPin for AnyOutputPin<'_> {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        match self {
+            AnyOutputPin::Gpio0(pin) => pin.set_high(),
+            AnyOutputPin::Gpio1(pin) => pin.set_high(),
+            AnyOutputPin::Gpio2(pin) => pin.set_high(),
+        }
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        match self {
+            AnyOutputPin::Gpio0(pin) => pin.set_low(),
+            AnyOutputPin::Gpio1(pin) => pin.set_low(),
+            AnyOutputPin::Gpio2(pin) => pin.set_low(),
+        }
+    }
+}
+
+impl StatefulOutputPin for AnyOutputPin<'_> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        match self {
+            AnyOutputPin::Gpio0(pin) => pin.is_set_high(),
+            AnyOutputPin::Gpio1(pin) => pin.is_set_high(),
+            AnyOutputPin::Gpio2(pin) => pin.is_set_high(),
+        }
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        match self {
+            AnyOutputPin::Gpio0(pin) => pin.is_set_low(),
+            AnyOutputPin::Gpio1(pin) => pin.is_set_low(),
+            AnyOutputPin::Gpio2(pin) => pin.is_set_low(),
+        }
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        match self {
+            AnyOutputPin::Gpio0(pin) => pin.toggle(),
+            AnyOutputPin::Gpio1(pin) => pin.toggle(),
+            AnyOutputPin::Gpio2(pin) => pin.toggle(),
+        }
+    }
+}
+
+/// A port-and-pin-count-erased GPIO handle, so pins from different ports (or
+/// different `PIN_CT`s, which [`AnyInputPin`]/[`AnyOutputPin`] still can't mix)
+/// can be stored in the same array -- e.g. to drive an N-segment display or
+/// scan a keypad matrix. Borrows the `DynPin` idea from rp-hal: the const
+/// generics that make [`ActiveInputPin`]/[`ActiveOutputPin`] distinct types
+/// per port are traded for a runtime [`PinIoMode`] tag, checked on every
+/// digital operation and returning [`GpioError::WrongIoMode`] on a mismatch.
+pub struct DynPin<'a> {
+    regs: &'a RegisterBlock,
+    pin_taken: &'a Cell<bool>,
+    pin_idx: usize,
+    io_mode: PinIoMode,
+    operating_mode: PinOperatingMode,
+}
+
+impl Drop for DynPin<'_> {
+    fn drop(&mut self) {
+        // When the pin is dropped, allow it to be taken again, same as
+        // `ActivePinHandle::drop`.
+        self.pin_taken.set(false);
+    }
+}
+
+impl ErrorType for DynPin<'_> {
+    type Error = GpioError;
+}
+
+impl InputPin for DynPin<'_> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        if self.io_mode != PinIoMode::Input {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        Ok(self.regs.in_().read().bits() & (1 << self.pin_idx) != 0)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_high().map(|x| !x)
+    }
+}
+
+impl OutputPin for DynPin<'_> {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        if self.io_mode != PinIoMode::Output {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        self.regs
+            .out_set()
+            .write(|w| w.gpio_out_set().variant(1 << self.pin_idx));
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        if self.io_mode != PinIoMode::Output {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        self.regs
+            .out_clr()
+            .write(|w| w.gpio_out_clr().variant(1 << self.pin_idx));
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for DynPin<'_> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        if self.io_mode != PinIoMode::Output {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        Ok(self.regs.out().read().bits() & (1 << self.pin_idx) != 0)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_set_high().map(|x| !x)
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        if self.io_mode != PinIoMode::Output {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        let bit = 1 << self.pin_idx;
+
+        if self.regs.out().read().bits() & bit != 0 {
+            self.regs.out_clr().write(|w| w.gpio_out_clr().variant(bit));
+        } else {
+            self.regs.out_set().write(|w| w.gpio_out_set().variant(bit));
+        }
+
+        Ok(())
+    }
+}
+
+impl DynPin<'_> {
+    /// The I/O mode this pin was in when it was erased into a `DynPin`.
+    pub fn get_io_mode(&self) -> PinIoMode {
+        self.io_mode
+    }
+
+    /// The operating mode this pin was in when it was erased into a `DynPin`.
+    pub fn get_operating_mode(&self) -> PinOperatingMode {
+        self.operating_mode
+    }
+}
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    From<ActivePinHandle<'a, PortNum, PIN_CT>> for DynPin<'a>
+{
+    fn from(handle: ActivePinHandle<'a, PortNum, PIN_CT>) -> Self {
+        let io_mode = handle.get_io_mode();
+        let operating_mode = handle.get_operating_mode();
+        // These borrow out of `*handle.port`, not `handle` itself, so they
+        // stay valid for `'a` once `handle` is forgotten below.
+        let regs: &'a RegisterBlock = &handle.port.regs;
+        let pin_taken = &handle.port.pin_taken[handle.pin_idx];
+        let pin_idx = handle.pin_idx;
+
+        // Skip `ActivePinHandle::drop`, which would release `pin_taken`
+        // immediately -- `DynPin` takes over ownership of that slot instead.
+        core::mem::forget(handle);
+
+        Self {
+            regs,
+            pin_taken,
+            pin_idx,
+            io_mode,
+            operating_mode,
+        }
+    }
+}
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize> From<ActiveInputPin<'a, PortNum, PIN_CT>>
+    for DynPin<'a>
+{
+    fn from(pin: ActiveInputPin<'a, PortNum, PIN_CT>) -> Self {
+        pin.0.into()
+    }
+}
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize> From<ActiveOutputPin<'a, PortNum, PIN_CT>>
+    for DynPin<'a>
+{
+    fn from(pin: ActiveOutputPin<'a, PortNum, PIN_CT>) -> Self {
+        pin.0.into()
+    }
 }
 
 impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ErrorType
@@ -346,17 +668,14 @@ impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
         state: PinState,
         config: ActiveOutputPinConfig,
     ) -> Result<ActiveOutputPin<'a, PortNum, PIN_CT>, Self::Error> {
+        self.port.open_drain[self.pin_idx].set(config.drive_mode == OutputDriveMode::OpenDrain);
+
         let mut pin = ActiveOutputPin(self);
 
         pin.0.transition_operating_mode();
         pin.set_power_supply(config.power_supply);
         pin.set_drive_strength(config.drive_strength);
 
-        match state {
-            PinState::Low => pin.set_low()?,
-            PinState::High => pin.set_high()?,
-        }
-
         pin.0
             .port
             .regs
@@ -368,6 +687,14 @@ impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
             .outen_set()
             .write(|w| w.all().variant(1 << pin.0.pin_idx));
 
+        // Done last so that, for an open-drain pin going high, `set_high`'s
+        // `outen_clr` is the final word on the output-enable bit rather than
+        // being clobbered by the unconditional `outen_set` above.
+        match state {
+            PinState::Low => pin.set_low()?,
+            PinState::High => pin.set_high()?,
+        }
+
         pin.0
             .write_operating_mode(config.operating_mode, PinIoMode::Output)?;
 
@@ -492,6 +819,22 @@ pub struct ActiveOutputPinConfig {
     pub power_supply: PowerSupply,
     /// The drive strength of the pin to use when it's converted to an output pin.
     pub drive_strength: DriveStrength,
+    /// The drive mode of the pin to use when it's converted to an output pin.
+    pub drive_mode: OutputDriveMode,
+}
+
+/// Represents the output drive mode of an output pin.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum OutputDriveMode {
+    /// Actively drives both the high and low output levels (the default).
+    #[default]
+    PushPull,
+    /// Actively drives the low level, but releases the pin to
+    /// high-impedance rather than driving it for the high level, so several
+    /// open-drain outputs can share a bus (e.g. `OWM_IO`, bit-banged I2C).
+    /// A pull-up -- external, or internal via [`PullMode::WeakPullup`] -- is
+    /// what actually pulls the line high.
+    OpenDrain,
 }
 
 /// Represents the associated power supply of a pin.
@@ -504,6 +847,126 @@ pub enum PowerSupply {
     Vddioh,
 }
 
+/// Represents the condition on which a pin's interrupt fires.
+///
+/// Dual-edge triggering ignores polarity, so [`InterruptTrigger::BothEdges`]
+/// fires on either transition of the pin.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InterruptTrigger {
+    /// Fires once when the pin transitions from low to high.
+    RisingEdge,
+    /// Fires once when the pin transitions from high to low.
+    FallingEdge,
+    /// Fires once on either a rising or a falling transition.
+    BothEdges,
+    /// Fires continuously while the pin reads high.
+    HighLevel,
+    /// Fires continuously while the pin reads low.
+    LowLevel,
+}
+
+impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActivePinHandle<'_, PortNum, PIN_CT> {
+    /// Configures this pin's interrupt trigger and enables it in the port's
+    /// interrupt-enable register.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GpioError::WrongIoMode`] if the pin isn't in [`PinIoMode::Input`],
+    /// since an output pin has no input level for the interrupt logic to observe.
+    pub fn enable_interrupt(&self, trigger: InterruptTrigger) -> Result<(), GpioError> {
+        if !matches!(self.get_io_mode(), PinIoMode::Input) {
+            return Err(GpioError::WrongIoMode);
+        }
+
+        let bit = |r, bit: bool| (r & !(1 << self.pin_idx)) | ((bit as u32) << self.pin_idx);
+
+        let (edge_triggered, active_high, dual_edge) = match trigger {
+            InterruptTrigger::RisingEdge => (true, true, false),
+            InterruptTrigger::FallingEdge => (true, false, false),
+            InterruptTrigger::BothEdges => (true, false, true),
+            InterruptTrigger::HighLevel => (false, true, false),
+            InterruptTrigger::LowLevel => (false, false, false),
+        };
+
+        self.port
+            .regs
+            .int_mod()
+            .modify(|r, w| w.all().variant(bit(r.bits(), edge_triggered)));
+        self.port
+            .regs
+            .int_pol()
+            .modify(|r, w| w.all().variant(bit(r.bits(), active_high)));
+        self.port
+            .regs
+            .dual_edge()
+            .modify(|r, w| w.all().variant(bit(r.bits(), dual_edge)));
+
+        self.port
+            .regs
+            .inten_set()
+            .write(|w| w.all().variant(1 << self.pin_idx));
+
+        Ok(())
+    }
+
+    /// Disables this pin's interrupt in the port's interrupt-enable register.
+    /// The trigger configuration set by [`Self::enable_interrupt`] is left untouched.
+    pub fn disable_interrupt(&self) {
+        self.port
+            .regs
+            .inten_clr()
+            .write(|w| w.all().variant(1 << self.pin_idx));
+    }
+
+    /// Returns whether this pin's interrupt is currently pending.
+    pub fn is_pending(&self) -> bool {
+        self.port.regs.intfl().read().bits() & (1 << self.pin_idx) != 0
+    }
+
+    /// Clears this pin's pending interrupt flag.
+    pub fn clear_pending(&self) {
+        self.port
+            .regs
+            .intfl()
+            .write(|w| w.all().variant(1 << self.pin_idx));
+    }
+
+    /// Registers `callback` to be run from [`GpioPort::handle_interrupt`] when
+    /// this pin's interrupt fires. There's exactly one listener slot per pin;
+    /// registering a new callback overwrites whatever was registered before.
+    pub fn set_listener(&self, callback: fn()) {
+        self.port.listeners[self.pin_idx].set(Some(callback));
+    }
+
+    /// Deregisters this pin's listener, if any. After this, [`GpioPort::handle_interrupt`]
+    /// still clears the pin's pending flag when it fires, it just doesn't call anything.
+    pub fn clear_listener(&self) {
+        self.port.listeners[self.pin_idx].set(None);
+    }
+}
+
+impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    GpioPort<'static, ActiveGpio<PortNum>, PIN_CT>
+{
+    /// Call this from the port's NVIC handler (there's one interrupt line per
+    /// port, covering every pin on it). Reads the interrupt-status register,
+    /// calls the listener registered with [`ActivePinHandle::set_listener`]
+    /// for each pending pin, then clears the flags it just served.
+    pub fn handle_interrupt(&self) {
+        let pending = self.regs.intfl().read().bits();
+
+        for (pin_idx, listener) in self.listeners.iter().enumerate() {
+            if pending & (1 << pin_idx) != 0 {
+                if let Some(callback) = listener.get() {
+                    callback();
+                }
+            }
+        }
+
+        self.regs.intfl().write(|w| w.all().variant(pending));
+    }
+}
+
 impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActivePinHandle<'_, PortNum, PIN_CT> {
     /// Sets the pin's associated power supply.
     pub fn set_power_supply(&self, ps: PowerSupply) {
@@ -523,6 +986,49 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActivePinHandle<'_, Po
     }
 }
 
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActivePinHandle<'a, PortNum, PIN_CT> {
+    /// Surrenders this pin to the analog subsystem, e.g. to route one of
+    /// GPIO2's `AINx` pins to the ADC/comparator front end. Clears the
+    /// digital input-enable bit, disables the output driver, and sets
+    /// high-impedance pull mode, so the pad is left floating rather than
+    /// fighting whatever analog signal it's connected to.
+    pub fn into_analog_pin(self) -> ActiveAnalogPin<'a, PortNum, PIN_CT> {
+        let clear_bit = |r: u32| r & !(1 << self.pin_idx);
+
+        self.port
+            .regs
+            .inen()
+            .modify(|r, w| w.gpio_inen().variant(clear_bit(r.bits())));
+        self.port
+            .regs
+            .outen_clr()
+            .write(|w| w.all().variant(1 << self.pin_idx));
+
+        // High-impedance pull mode: clear both pad-control bits and the
+        // pull-select bit. See `ActiveInputPin::set_pull_mode`.
+        self.port
+            .regs
+            .padctrl0()
+            .modify(|r, w| w.gpio_padctrl0().variant(clear_bit(r.bits())));
+        self.port
+            .regs
+            .padctrl1()
+            .modify(|r, w| w.gpio_padctrl1().variant(clear_bit(r.bits())));
+        self.port
+            .regs
+            .ps()
+            .modify(|r, w| w.all().variant(clear_bit(r.bits())));
+
+        ActiveAnalogPin(self)
+    }
+
+    /// Erases this pin's port and pin-count generics into a [`DynPin`], so it
+    /// can be stored in a uniform array alongside pins from other ports.
+    pub fn into_dyn(self) -> DynPin<'a> {
+        self.into()
+    }
+}
+
 /// Represents the pull mode of an input pin.
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
 pub enum PullMode {
@@ -597,6 +1103,49 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveInputPin<'_, Por
     pub fn get_power_supply(&self) -> PowerSupply {
         self.0.get_power_supply()
     }
+
+    /// Configures this pin's interrupt trigger and enables it in the port's
+    /// interrupt-enable register. See [`ActivePinHandle::enable_interrupt`].
+    pub fn enable_interrupt(&self, trigger: InterruptTrigger) -> Result<(), GpioError> {
+        self.0.enable_interrupt(trigger)
+    }
+
+    /// Disables this pin's interrupt. See [`ActivePinHandle::disable_interrupt`].
+    pub fn disable_interrupt(&self) {
+        self.0.disable_interrupt();
+    }
+
+    /// Returns whether this pin's interrupt is currently pending.
+    pub fn is_pending(&self) -> bool {
+        self.0.is_pending()
+    }
+
+    /// Clears this pin's pending interrupt flag.
+    pub fn clear_pending(&self) {
+        self.0.clear_pending();
+    }
+
+    /// Registers this pin's interrupt listener. See [`ActivePinHandle::set_listener`].
+    pub fn set_listener(&self, callback: fn()) {
+        self.0.set_listener(callback);
+    }
+
+    /// Deregisters this pin's interrupt listener. See [`ActivePinHandle::clear_listener`].
+    pub fn clear_listener(&self) {
+        self.0.clear_listener();
+    }
+}
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveInputPin<'a, PortNum, PIN_CT> {
+    /// Surrenders this pin to the analog subsystem. See [`ActivePinHandle::into_analog_pin`].
+    pub fn into_analog_pin(self) -> ActiveAnalogPin<'a, PortNum, PIN_CT> {
+        self.0.into_analog_pin()
+    }
+
+    /// Erases this pin's port and pin-count generics. See [`ActivePinHandle::into_dyn`].
+    pub fn into_dyn(self) -> DynPin<'a> {
+        self.into()
+    }
 }
 
 /// Represents the drive strength of an output pin.
@@ -652,6 +1201,20 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveOutputPin<'_, Po
         }
     }
 
+    /// Sets the pin's output drive mode. See [`OutputDriveMode`].
+    pub fn set_drive_mode(&self, mode: OutputDriveMode) {
+        self.0.port.open_drain[self.0.pin_idx].set(mode == OutputDriveMode::OpenDrain);
+    }
+
+    /// Gets the pin's output drive mode. See [`OutputDriveMode`].
+    pub fn get_drive_mode(&self) -> OutputDriveMode {
+        if self.0.port.open_drain[self.0.pin_idx].get() {
+            OutputDriveMode::OpenDrain
+        } else {
+            OutputDriveMode::PushPull
+        }
+    }
+
     /// Sets the pin's associated power supply.
     pub fn set_power_supply(&self, ps: PowerSupply) {
         self.0.set_power_supply(ps);
@@ -662,3 +1225,15 @@ impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveOutputPin<'_, Po
         self.0.get_power_supply()
     }
 }
+
+impl<'a, PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveOutputPin<'a, PortNum, PIN_CT> {
+    /// Surrenders this pin to the analog subsystem. See [`ActivePinHandle::into_analog_pin`].
+    pub fn into_analog_pin(self) -> ActiveAnalogPin<'a, PortNum, PIN_CT> {
+        self.0.into_analog_pin()
+    }
+
+    /// Erases this pin's port and pin-count generics. See [`ActivePinHandle::into_dyn`].
+    pub fn into_dyn(self) -> DynPin<'a> {
+        self.into()
+    }
+}
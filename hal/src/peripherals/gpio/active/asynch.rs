@@ -0,0 +1,108 @@
+//! Async wait-for-level/edge support for active GPIO input pins.
+//!
+//! Instead of busy-looping on [`ActivePinHandle::is_pending`], the futures
+//! here register a per-pin [`AtomicWaker`] and return [`Poll::Pending`] until
+//! the port's interrupt handler wakes them, mirroring the approach
+//! embassy-rp takes for its peripheral drivers (and this crate's own
+//! [`crate::peripherals::i2c::asynch`]). Callers are responsible for routing
+//! the GPIOn interrupt to [`on_interrupt`] from their `#[interrupt]` handler.
+
+use core::future::poll_fn;
+use core::task::Poll;
+
+use embassy_sync::waker::AtomicWaker;
+use embedded_hal_async::digital::Wait;
+
+use super::port_num_types::GpioPortNum;
+use super::{ActiveInputPin, InterruptTrigger};
+use crate::peripherals::gpio::GpioError;
+
+/// Number of pins across GPIO0 (31), GPIO1 (10), and GPIO2 (8) that need a waker slot.
+const NUM_GPIO_PINS: usize = 31 + 10 + 8;
+
+static GPIO_WAKERS: [AtomicWaker; NUM_GPIO_PINS] = [const { AtomicWaker::new() }; NUM_GPIO_PINS];
+
+/// The first waker-table index used by a given port's pins.
+const fn waker_base(port_num: usize) -> usize {
+    match port_num {
+        0 => 0,
+        1 => 31,
+        _ => 31 + 10,
+    }
+}
+
+/// Call this from the `GPIOn` NVIC handler. Clears every interrupt flag
+/// latched in `regs`, disables those pins' interrupts, and wakes the future
+/// waiting on each one.
+pub fn on_interrupt<PortNum: GpioPortNum>(regs: &PortNum::Peripheral) {
+    let pending = regs.intfl().read().bits();
+    if pending == 0 {
+        return;
+    }
+
+    regs.intfl().write(|w| w.all().variant(pending));
+    regs.inten_clr().write(|w| w.all().variant(pending));
+
+    let base = waker_base(PortNum::PORT_NUM);
+    for pin_idx in 0..32 {
+        if pending & (1 << pin_idx) != 0 {
+            GPIO_WAKERS[base + pin_idx].wake();
+        }
+    }
+}
+
+impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> ActiveInputPin<'_, PortNum, PIN_CT> {
+    fn waker_idx(&self) -> usize {
+        waker_base(PortNum::PORT_NUM) + self.0.pin_idx
+    }
+
+    /// Arms `trigger` and awaits its pending flag, mirroring
+    /// [`crate::peripherals::i2c::asynch`]'s `poll_fn`-based wait: the
+    /// future registers its waker, then on every poll checks whether the
+    /// pending flag is set or [`on_interrupt`] has already cleared it and
+    /// disabled the pin's interrupt.
+    async fn wait_for_trigger(&mut self, trigger: InterruptTrigger) -> Result<(), GpioError> {
+        self.clear_pending();
+        self.enable_interrupt(trigger)?;
+
+        poll_fn(|cx| {
+            GPIO_WAKERS[self.waker_idx()].register(cx.waker());
+
+            let fired = self.0.port.regs.inten().read().bits() & (1 << self.0.pin_idx) == 0;
+            if fired || self.is_pending() {
+                self.clear_pending();
+                self.disable_interrupt();
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await;
+
+        Ok(())
+    }
+}
+
+impl<PortNum: GpioPortNum + 'static, const PIN_CT: usize> Wait
+    for ActiveInputPin<'_, PortNum, PIN_CT>
+{
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_trigger(InterruptTrigger::HighLevel).await
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_trigger(InterruptTrigger::LowLevel).await
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_trigger(InterruptTrigger::RisingEdge).await
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_trigger(InterruptTrigger::FallingEdge).await
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_trigger(InterruptTrigger::BothEdges).await
+    }
+}
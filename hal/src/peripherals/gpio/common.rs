@@ -373,3 +373,131 @@ impl<'a, Port: GpioPortNum + 'static, const PIN_CT: usize>
         self.0.get_io_mode()
     }
 }
+
+// The impls below are fully qualified rather than going through the
+// `InputPin`/`OutputPin`/`StatefulOutputPin` names already imported above,
+// since those names track whatever `embedded_hal::digital` version
+// `pin_traits` re-exports; gating a second, explicit `embedded_hal` 1.0 impl
+// behind its own feature lets the crate add a differently-versioned
+// `embedded_hal` surface later without the two colliding.
+
+#[cfg(feature = "eh1-digital")]
+impl<Port: GpioPortNum + 'static, const PIN_CT: usize> embedded_hal::digital::ErrorType
+    for CommonInputPin<'_, Port, PIN_CT>
+{
+    type Error = Infallible;
+}
+
+#[cfg(feature = "eh1-digital")]
+impl<Port: GpioPortNum + 'static, const PIN_CT: usize> embedded_hal::digital::InputPin
+    for CommonInputPin<'_, Port, PIN_CT>
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.0.port.regs.in_().read().bits() & (1 << self.0.pin_idx) != 0)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_high().map(|x| !x)
+    }
+}
+
+#[cfg(feature = "eh1-digital")]
+impl<Port: GpioPortNum + 'static, const PIN_CT: usize> embedded_hal::digital::ErrorType
+    for CommonOutputPin<'_, Port, PIN_CT>
+{
+    type Error = Infallible;
+}
+
+#[cfg(feature = "eh1-digital")]
+impl<Port: GpioPortNum + 'static, const PIN_CT: usize> embedded_hal::digital::OutputPin
+    for CommonOutputPin<'_, Port, PIN_CT>
+{
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0
+            .port
+            .regs
+            .out_set()
+            .write(|w| unsafe { w.bits(1 << self.0.pin_idx) });
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0
+            .port
+            .regs
+            .out_clr()
+            .write(|w| unsafe { w.bits(1 << self.0.pin_idx) });
+        Ok(())
+    }
+}
+
+#[cfg(feature = "eh1-digital")]
+impl<Port: GpioPortNum + 'static, const PIN_CT: usize> embedded_hal::digital::StatefulOutputPin
+    for CommonOutputPin<'_, Port, PIN_CT>
+{
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.0.port.regs.out().read().bits() & (1 << self.0.pin_idx) != 0)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_set_high().map(|x| !x)
+    }
+}
+
+/// Aggregates `N` [`CommonOutputPin`]s on the same port so they can be
+/// driven together, instead of one `out_set`/`out_clr` write per pin. This
+/// borrows the `OutPort` concept from the stm32f4xx HAL's `gpio/outport`
+/// module: every pin in the set changes from a single register write, so
+/// (unlike calling `set_high`/`set_low` on each pin in a loop) a bus of
+/// several lines can't be observed half-updated.
+pub struct OutPort<'a, Port: GpioPortNum + 'static, const PIN_CT: usize, const N: usize> {
+    pins: [CommonOutputPin<'a, Port, PIN_CT>; N],
+    mask: u32,
+}
+
+impl<'a, Port: GpioPortNum + 'static, const PIN_CT: usize, const N: usize>
+    OutPort<'a, Port, PIN_CT, N>
+{
+    /// Takes ownership of `pins` to drive them as a single unit.
+    pub fn new(pins: [CommonOutputPin<'a, Port, PIN_CT>; N]) -> Self {
+        let mask = pins.iter().fold(0u32, |mask, pin| mask | (1 << pin.0.pin_idx));
+        Self { pins, mask }
+    }
+
+    /// Sets every bit in `mask & value` high and every bit in `mask & !value`
+    /// low, restricted to the pins owned by this `OutPort`, with one
+    /// read-modify-write of `out` inside a critical section.
+    pub fn write(&mut self, mask: u32, value: u32) {
+        let mask = mask & self.mask;
+        let regs = &self.pins[0].0.port.regs;
+        critical_section::with(|_| {
+            regs.out()
+                .modify(|r, w| unsafe { w.bits((r.bits() & !mask) | (mask & value)) });
+        });
+    }
+
+    /// Sets every owned pin high in a single write.
+    pub fn set_all(&mut self) {
+        self.pins[0]
+            .0
+            .port
+            .regs
+            .out_set()
+            .write(|w| unsafe { w.bits(self.mask) });
+    }
+
+    /// Sets every owned pin low in a single write.
+    pub fn clear_all(&mut self) {
+        self.pins[0]
+            .0
+            .port
+            .regs
+            .out_clr()
+            .write(|w| unsafe { w.bits(self.mask) });
+    }
+
+    /// Releases the individual pins this `OutPort` was built from.
+    pub fn release(self) -> [CommonOutputPin<'a, Port, PIN_CT>; N] {
+        self.pins
+    }
+}
@@ -30,6 +30,8 @@ use super::{
     __seal_gpio_port_metadata, __seal_pin_handle,
 };
 
+pub mod asynch;
+
 // TODO for arelyx:
 // - implement functions with todo!() in them (see LowPowerPinHandle::set_operating_mode for example)
 // - add documentation
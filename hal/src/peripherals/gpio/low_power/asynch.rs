@@ -0,0 +1,146 @@
+//! Async wait-for-level/edge support for low-power GPIO3 input pins.
+//!
+//! GPIO3's wake-up detect logic only arms on a single configured edge (see
+//! the `P3x_WUD`/`P3x_WUPOL` fields of `MCR_GPIO3_CTRL`), so unlike
+//! [`crate::peripherals::gpio::active::asynch`] there's no separate
+//! high/low-level trigger to select: `wait_for_high`/`wait_for_low` are
+//! synthesized by checking the current level first and, if it doesn't
+//! already hold, arming the edge that would produce it. [`EdgeWait`] enables
+//! wake-up detect for its edge on construction and disables it again on
+//! `Drop`, whether it completed normally or was cancelled mid-poll, so a
+//! dropped wait never leaves the pin armed. Callers are responsible for
+//! routing the GPIO3 wake-up interrupt to [`on_interrupt`] from their
+//! `#[interrupt]` handler.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use embassy_sync::waker::AtomicWaker;
+use embedded_hal_async::digital::Wait;
+use max78000::MCR;
+
+use super::LowPowerInputPin;
+use crate::peripherals::gpio::pin_traits::InputPin;
+
+/// One [`AtomicWaker`] per GPIO3 pin (P3.0, P3.1), woken from [`on_interrupt`].
+static GPIO3_WAKERS: [AtomicWaker; 2] = [const { AtomicWaker::new() }; 2];
+
+/// Which edge an [`EdgeWait`] is armed for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Rising,
+    Falling,
+}
+
+/// Arms (or disarms) wake-up detect for `pin_idx` to fire on `edge`.
+fn set_wakeup_detect(regs: &MCR, pin_idx: usize, edge: Edge, enable: bool) {
+    let reg = regs.gpio3_ctrl();
+    let rising = edge == Edge::Rising;
+
+    match pin_idx == 0 {
+        true => reg.modify(|_, w| w.p30_wupol().bit(rising).p30_wud().bit(enable)),
+        false => reg.modify(|_, w| w.p31_wupol().bit(rising).p31_wud().bit(enable)),
+    };
+}
+
+/// Whether `pin_idx`'s wake-up detect has fired, i.e. hardware has
+/// auto-cleared the enable bit [`set_wakeup_detect`] set.
+fn wakeup_fired(regs: &MCR, pin_idx: usize) -> bool {
+    let reg = regs.gpio3_ctrl().read();
+
+    match pin_idx == 0 {
+        true => reg.p30_wud().bit_is_clear(),
+        false => reg.p31_wud().bit_is_clear(),
+    }
+}
+
+/// Call this from the GPIO3 wake-up NVIC handler. Wakes the future (if any)
+/// waiting on each pin whose wake-up detect just fired.
+pub fn on_interrupt(regs: &MCR) {
+    for (pin_idx, waker) in GPIO3_WAKERS.iter().enumerate() {
+        if wakeup_fired(regs, pin_idx) {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [`LowPowerInputPin`]'s [`Wait`] impl. Arms wake-up
+/// detect for `edge` on construction and disarms it on `Drop`, so an
+/// in-flight wait that gets cancelled (eg. by a `select!`) doesn't leave the
+/// pin armed to wake on a stale edge.
+struct EdgeWait<'mcr> {
+    regs: &'mcr MCR,
+    pin_idx: usize,
+    edge: Edge,
+}
+
+impl<'mcr> EdgeWait<'mcr> {
+    fn new(regs: &'mcr MCR, pin_idx: usize, edge: Edge) -> Self {
+        set_wakeup_detect(regs, pin_idx, edge, true);
+        Self { regs, pin_idx, edge }
+    }
+}
+
+impl Future for EdgeWait<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        GPIO3_WAKERS[self.pin_idx].register(cx.waker());
+
+        if wakeup_fired(self.regs, self.pin_idx) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for EdgeWait<'_> {
+    fn drop(&mut self) {
+        set_wakeup_detect(self.regs, self.pin_idx, self.edge, false);
+    }
+}
+
+impl<'a, 'mcr, const PIN_CT: usize> LowPowerInputPin<'a, 'mcr, PIN_CT> {
+    fn wait_for_edge(&mut self, edge: Edge) -> EdgeWait<'mcr> {
+        EdgeWait::new(self.0.port.regs, self.0.pin_idx, edge)
+    }
+}
+
+impl<'a, 'mcr, const PIN_CT: usize> Wait for LowPowerInputPin<'a, 'mcr, PIN_CT> {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        if self.is_high()? {
+            return Ok(());
+        }
+
+        self.wait_for_edge(Edge::Rising).await;
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        if self.is_low()? {
+            return Ok(());
+        }
+
+        self.wait_for_edge(Edge::Falling).await;
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_edge(Edge::Rising).await;
+        Ok(())
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_edge(Edge::Falling).await;
+        Ok(())
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        let high = self.is_high()?;
+        self.wait_for_edge(if high { Edge::Falling } else { Edge::Rising })
+            .await;
+        Ok(())
+    }
+}
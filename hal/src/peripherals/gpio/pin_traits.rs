@@ -1,7 +1,8 @@
 //! Contains traits used by pins in the GPIO peripherals API.
-//! Some traits in this module are re-exports from `embedded_hal` for GPIO pins.
+//! Some traits in this module are re-exports from `embedded_hal`/`embedded_hal_async` for GPIO pins.
 
 pub use embedded_hal::digital::*;
+pub use embedded_hal_async::digital::Wait;
 
 use super::{GpioError, PinIoMode, PinOperatingMode};
 
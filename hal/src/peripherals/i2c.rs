@@ -5,12 +5,20 @@ use core::ops::Deref;
 use max78000::i2c0;
 use max78000::{I2C0, I2C1, I2C2};
 
+use crate::peripherals::dma::{DmaChannel, DmaRequest};
+use crate::peripherals::gpio::active::port_num_types::GpioZero;
+use crate::peripherals::gpio::active::ActivePinHandle;
+
+/// Async, interrupt-driven master/slave transactions
+pub mod asynch;
 /// Implementation of the comm stack traits
 pub mod comm;
 /// Implementation of master mode
 pub mod master;
 /// Implementation of slave mode
 pub mod slave;
+/// Interrupt-driven (non-async) slave reception
+pub mod slave_interrupt;
 
 /// Auxiliary trait that only the I2C0, I2C1, and I2C2 registers can implement;
 /// Allows peripheral toggle and reset functionality to said peripherals if GCR regs
@@ -34,10 +42,22 @@ pub trait GCRI2C: Deref<Target = i2c0::RegisterBlock> {
     fn bus_timeout(&self) -> bool;
     /// Is there a bus error?
     fn bus_error(&self) -> bool;
+    /// Decodes the currently-latched error flags into a single [`I2cError`],
+    /// preferring the more specific reasons over the catch-all `Other`.
+    fn abort_reason(&self) -> Option<I2cError>;
+    /// Enables/disables this instance asserting its receive/transmit FIFO
+    /// DMA request lines, letting a [`DmaChannel`] gated on
+    /// [`Self::dma_rx_request`]/[`Self::dma_tx_request`] move bytes in and
+    /// out of the FIFO without CPU polling.
+    fn set_dma_enabled(&mut self, rx: bool, tx: bool);
+    /// The [`DmaRequest`] that gates a channel on this instance's receive FIFO.
+    fn dma_rx_request(&self) -> DmaRequest;
+    /// The [`DmaRequest`] that gates a channel on this instance's transmit FIFO.
+    fn dma_tx_request(&self) -> DmaRequest;
 }
 
 macro_rules! gen_impl_gcri2c {
-    ($register:ty, $lowercaseName:ident, $rstReg:ident, $pclkdisReg:ident) => {
+    ($register:ty, $lowercaseName:ident, $rstReg:ident, $pclkdisReg:ident, $dmaRx:ident, $dmaTx:ident) => {
         impl GCRI2C for $register {
             fn flush_fifo(&mut self) {
                 self.rxctrl0().modify(|_, w| w.flush().bit(true));
@@ -110,34 +130,318 @@ macro_rules! gen_impl_gcri2c {
                         .bit(true)
                 });
             }
+            fn abort_reason(&self) -> Option<I2cError> {
+                let flags = self.intfl0().read();
+                if flags.addr_nack_err().bit() {
+                    Some(I2cError::NoAcknowledge)
+                } else if flags.arb_err().bit() {
+                    Some(I2cError::ArbitrationLoss)
+                } else if flags.to_err().bit() {
+                    Some(I2cError::Timeout)
+                } else if flags.data_err().bit() {
+                    Some(I2cError::DataNoAcknowledge)
+                } else if flags.stop_err().bit() || flags.start_err().bit() || flags.dnr_err().bit()
+                {
+                    Some(I2cError::Other(flags.bits()))
+                } else {
+                    None
+                }
+            }
+            fn set_dma_enabled(&mut self, rx: bool, tx: bool) {
+                self.rxctrl0().modify(|_, w| w.dma().bit(rx));
+                self.txctrl0().modify(|_, w| w.dma().bit(tx));
+            }
+            fn dma_rx_request(&self) -> DmaRequest {
+                DmaRequest::$dmaRx
+            }
+            fn dma_tx_request(&self) -> DmaRequest {
+                DmaRequest::$dmaTx
+            }
         }
     };
 }
 
-gen_impl_gcri2c!(I2C0, i2c0, rst0, pclkdis0);
-gen_impl_gcri2c!(I2C1, i2c1, rst1, pclkdis0);
-gen_impl_gcri2c!(I2C2, i2c2, rst1, pclkdis1);
+gen_impl_gcri2c!(I2C0, i2c0, rst0, pclkdis0, I2C0Rx, I2C0Tx);
+gen_impl_gcri2c!(I2C1, i2c1, rst1, pclkdis0, I2C1Rx, I2C1Tx);
+gen_impl_gcri2c!(I2C2, i2c2, rst1, pclkdis1, I2C2Rx, I2C2Tx);
 
 /// The result of calling slave_poll, Received indicates how many bytes have been read,
 /// and if bytes had to be dropped due to exceeding the buffer size
 ///
 /// TransmitNeeded indicates you need to call slave_send with the data needed
 pub enum SlavePollResult {
-    /// Received #bytes and if given read buffer length was exceeded
-    IncomingTransmission,
+    /// A master wants to write to us. `general_call` is set if the master
+    /// addressed the reserved general-call address (`0x00`) rather than our
+    /// own slave address, as reported by the `gc_addr_match` interrupt flag.
+    IncomingTransmission {
+        /// Whether this transmission was addressed via the general call
+        /// address rather than our own slave address.
+        general_call: bool,
+    },
     /// The peripheral is currently clock stretching and a transmit operation
     /// is required ASAP
     TransmitNeeded,
 }
 
-/// Various I2C bus speeds
+/// Structured I2C failure reasons, replacing the single opaque
+/// `embedded_hal::i2c::ErrorKind::Bus` previously returned for every
+/// hardware fault.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum I2cError {
+    /// The target did not acknowledge its address (`addr_nack_err`).
+    NoAcknowledge,
+    /// The target acknowledged its address but then NACKed a data byte
+    /// during the write phase (`data_err`), distinct from an address NACK.
+    DataNoAcknowledge,
+    /// Another controller won arbitration on the bus (`arb_err`).
+    ArbitrationLoss,
+    /// The bus timed out waiting for a clock stretch to release
+    /// (`to_err`).
+    Timeout,
+    /// The requested 7-bit address falls in the reserved range
+    /// `0x00..=0x07` or `0x78..=0x7F`.
+    AddressReserved,
+    /// The requested address does not fit in 7 bits.
+    AddressOutOfRange,
+    /// A start/stop/DNR framing error occurred; the raw `INTFL0` bits are
+    /// preserved for diagnostics.
+    Other(u32),
+}
+
+impl embedded_hal::i2c::Error for I2cError {
+    fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+        embedded_hal::i2c::ErrorKind::from(*self)
+    }
+}
+
+impl From<I2cError> for embedded_hal::i2c::ErrorKind {
+    fn from(err: I2cError) -> Self {
+        use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+        match err {
+            I2cError::NoAcknowledge => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+            I2cError::DataNoAcknowledge => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data),
+            I2cError::ArbitrationLoss => ErrorKind::ArbitrationLoss,
+            I2cError::Timeout
+            | I2cError::AddressReserved
+            | I2cError::AddressOutOfRange
+            | I2cError::Other(_) => ErrorKind::Bus,
+        }
+    }
+}
+
+impl From<embedded_hal::i2c::ErrorKind> for I2cError {
+    fn from(kind: embedded_hal::i2c::ErrorKind) -> Self {
+        use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+        match kind {
+            ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => I2cError::DataNoAcknowledge,
+            ErrorKind::NoAcknowledge(_) => I2cError::NoAcknowledge,
+            ErrorKind::ArbitrationLoss => I2cError::ArbitrationLoss,
+            _ => I2cError::Other(0),
+        }
+    }
+}
+
+/// Validates a 7-bit target address before any bus activity is started,
+/// rejecting the addresses reserved by the I2C specification
+/// (`0x00..=0x07` and `0x78..=0x7F`) and anything that doesn't fit in 7
+/// bits.
+pub fn validate_seven_bit_address(addr: u8) -> Result<(), I2cError> {
+    if addr > 0x7F {
+        Err(I2cError::AddressOutOfRange)
+    } else if addr <= 0x07 || addr >= 0x78 {
+        Err(I2cError::AddressReserved)
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates a 10-bit target address, rejecting anything that doesn't fit
+/// in 10 bits.
+pub fn validate_ten_bit_address(addr: u16) -> Result<(), I2cError> {
+    if addr > 0x3FF {
+        Err(I2cError::AddressOutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// An I2C slave address, either 7-bit or 10-bit. Masters emit the 10-bit
+/// variant as the two-byte `11110xx0` sequence described in the I2C
+/// specification; see [`I2CMaster`]'s `embedded_hal::i2c::I2c<TenBitAddress>`
+/// impl.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SlaveAddress {
+    /// A standard 7-bit slave address.
+    SevenBit(u8),
+    /// An extended 10-bit slave address.
+    TenBit(u16),
+}
+
+impl SlaveAddress {
+    /// Validates the wrapped address, dispatching to
+    /// [`validate_seven_bit_address`] or [`validate_ten_bit_address`].
+    pub fn validate(&self) -> Result<(), I2cError> {
+        match *self {
+            SlaveAddress::SevenBit(addr) => validate_seven_bit_address(addr),
+            SlaveAddress::TenBit(addr) => validate_ten_bit_address(addr),
+        }
+    }
+}
+
+/// Various I2C bus speeds, carrying the bus's rise time so
+/// [`compute_timings`] can compensate for it the way the STM32 v2 I2C
+/// driver's `Timings` does: boards with weaker pull-ups see a slower SCL
+/// rise and should report a larger `rise_time_ns` to keep `tHIGH` within
+/// spec.
 pub enum BusSpeed {
     /// Standard mode - 100kbps or 100khz
-    Standard100kbps,
+    Standard100kbps {
+        /// Measured or datasheet SCL rise time, in nanoseconds.
+        rise_time_ns: u32,
+    },
     /// Fast mode - 400kbps or 400khz
-    Fast400kbps,
+    Fast400kbps {
+        /// Measured or datasheet SCL rise time, in nanoseconds.
+        rise_time_ns: u32,
+    },
     /// Fast plus mode - 1mbps or 1mhz
-    FastPlus1mbps,
+    FastPlus1mbps {
+        /// Measured or datasheet SCL rise time, in nanoseconds.
+        rise_time_ns: u32,
+    },
+    /// High-speed mode - 3.4mbps. Per the I2C specification, a High-Speed
+    /// transfer still starts with a Fast-mode "master code" addressing
+    /// phase before switching the bus over to the HS clock, so `CLKHI`/
+    /// `CLKLO` are programmed for that Fast-mode preamble and `HSCLK.hi`/
+    /// `HSCLK.lo` for the 3.4mbps phase itself. Once `CTRL.hs_en` is set,
+    /// the controller re-arbitrates with the master-code preamble and
+    /// switches to `HSCLK` on every transaction's START, then drops back to
+    /// the Fast-mode preamble speed on its own after each STOP -- no
+    /// per-transaction software reconfiguration is needed.
+    HighSpeed3_4mbps {
+        /// Measured or datasheet SCL rise time, in nanoseconds.
+        rise_time_ns: u32,
+    },
+}
+
+impl BusSpeed {
+    /// The bus frequency `CLKHI`/`CLKLO` should be programmed for: the
+    /// bus's own speed for Standard/Fast/Fast-Plus, or the 400kbps
+    /// Fast-mode master-code preamble for [`BusSpeed::HighSpeed3_4mbps`].
+    fn clkhi_clklo_target_hz(&self) -> u32 {
+        match self {
+            BusSpeed::Standard100kbps { .. } => 100_000,
+            BusSpeed::Fast400kbps { .. } | BusSpeed::HighSpeed3_4mbps { .. } => 400_000,
+            BusSpeed::FastPlus1mbps { .. } => 1_000_000,
+        }
+    }
+
+    /// The 3.4mbps `HSCLK` target, for [`BusSpeed::HighSpeed3_4mbps`] only.
+    fn hsclk_target_hz(&self) -> Option<u32> {
+        matches!(self, BusSpeed::HighSpeed3_4mbps { .. }).then_some(3_400_000)
+    }
+
+    /// The configured SCL rise time, in nanoseconds.
+    fn rise_time_ns(&self) -> u32 {
+        match *self {
+            BusSpeed::Standard100kbps { rise_time_ns }
+            | BusSpeed::Fast400kbps { rise_time_ns }
+            | BusSpeed::FastPlus1mbps { rise_time_ns }
+            | BusSpeed::HighSpeed3_4mbps { rise_time_ns } => rise_time_ns,
+        }
+    }
+
+    /// Whether `tHIGH`/`tLOW` should split 1/3:2/3 (Fast mode and faster,
+    /// whose tight `tHIGH` minimum needs the shorter share) rather than the
+    /// roughly 1:1 split Standard mode's looser timing allows.
+    fn fast_mode_duty(&self) -> bool {
+        !matches!(self, BusSpeed::Standard100kbps { .. })
+    }
+}
+
+/// SCL high/low counts, in peripheral-clock cycles, for one bus-speed tier.
+/// Returned separately from `CLKHI`/`CLKLO` so the same helper can also
+/// derive `HSCLK.hi`/`HSCLK.lo` for [`BusSpeed::HighSpeed3_4mbps`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct I2cTimings {
+    /// Value to program into `CLKHI.hi`/`HSCLK.hi`.
+    pub hi: u16,
+    /// Value to program into `CLKLO.lo`/`HSCLK.lo`.
+    pub lo: u16,
+}
+
+/// Computes `CLKHI`/`CLKLO` (or `HSCLK.hi`/`HSCLK.lo`) counts for a target
+/// bus frequency, the way the STM32 v2 I2C driver's `Timings` derives its
+/// register fields from the PCLK and target frequency: the nominal SCL
+/// period is shortened by the bus's rise time before being split between
+/// high and low, since the pins spend `rise_time_ns` coasting up through
+/// the input-high threshold rather than actively being driven high.
+/// `fast_mode_duty` selects a 1/3 high : 2/3 low split (Fast mode's tighter
+/// `tHIGH` minimum) instead of Standard mode's roughly 1:1 split.
+pub(crate) fn compute_timings(
+    pclk_hz: u32,
+    target_hz: u32,
+    rise_time_ns: u32,
+    fast_mode_duty: bool,
+) -> I2cTimings {
+    let period_cycles = pclk_hz / target_hz;
+    let rise_cycles = ((u64::from(rise_time_ns) * u64::from(pclk_hz)) / 1_000_000_000) as u32;
+
+    let (hi_num, hi_den) = if fast_mode_duty { (1, 3) } else { (1, 2) };
+    let driven_cycles = period_cycles.saturating_sub(rise_cycles);
+    let hi_cycles = (driven_cycles * hi_num / hi_den).max(1);
+    let lo_cycles = driven_cycles.saturating_sub(hi_cycles).max(1);
+
+    I2cTimings {
+        hi: (hi_cycles - 1).min(u32::from(u16::MAX)) as u16,
+        lo: (lo_cycles - 1).min(u32::from(u16::MAX)) as u16,
+    }
+}
+
+/// How an [`I2CSlave`] or [`I2CMaster`] behaves when its transmit FIFO
+/// underruns mid-transaction -- for a slave, the controller is still
+/// clocking in reads but we have no more data staged; for a master feeding
+/// [`I2CMaster::send_raw`] from a slow producer iterator, the FIFO can drain
+/// faster than the iterator yields bytes.
+pub enum FifoEmptyMode {
+    /// Stretch SCL low until software refills the FIFO (`clkstr_dis = 0`).
+    /// This is what `send_raw`'s zero-padding fallback currently assumes.
+    ClockStretch,
+    /// Let the controller end the transaction itself rather than stretching
+    /// the clock (`clkstr_dis = 1`).
+    AutoStop,
+}
+
+impl FifoEmptyMode {
+    pub(crate) fn clkstr_dis(&self) -> bool {
+        matches!(self, FifoEmptyMode::AutoStop)
+    }
+}
+
+/// The SCL high/low timing actually programmed into `CLKHI`/`CLKLO`,
+/// read back from hardware so callers can cross-check it against the
+/// datasheet for their chosen [`BusSpeed`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct I2cClockTiming {
+    /// Value of `CLKHI.hi`: SCL high period, in peripheral clock cycles.
+    pub hi: u16,
+    /// Value of `CLKLO.lo`: SCL low period, in peripheral clock cycles.
+    pub lo: u16,
+}
+
+/// Whether [`I2CMaster::recv_raw`]/[`I2CMaster::send_raw`] release the bus
+/// with a STOP condition or hold it with a repeated START, so
+/// [`embedded_hal::i2c::I2c::transaction`] can turn the bus around between
+/// operations without releasing it -- many register-addressed sensors only
+/// latch their register pointer across a repeated START, not a
+/// STOP-then-START.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Terminate {
+    /// End the transfer with a STOP condition (`mstctrl.stop`).
+    Stop,
+    /// End the transfer with a repeated START condition (`mstctrl.restart`),
+    /// keeping the bus held for a following operation.
+    Restart,
 }
 
 /// An I2C peripheral operating as a master.
@@ -146,9 +450,16 @@ pub enum BusSpeed {
 pub struct I2CMaster<'a, T: GCRI2C> {
     i2c_regs: RefMut<'a, T>,
     target_addr: u8,
+    scl_pin: ActivePinHandle<'a, GpioZero, 31>,
+    sda_pin: ActivePinHandle<'a, GpioZero, 31>,
+    dma_channel: Option<DmaChannel<'a>>,
+    fifo_empty_mode: FifoEmptyMode,
 }
 
 /// An I2C peripheral operating as a slave.
 pub struct I2CSlave<'a, T: GCRI2C> {
     i2c_regs: RefMut<'a, T>,
+    scl_pin: ActivePinHandle<'a, GpioZero, 31>,
+    sda_pin: ActivePinHandle<'a, GpioZero, 31>,
+    dma_channel: Option<DmaChannel<'a>>,
 }
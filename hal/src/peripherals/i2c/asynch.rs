@@ -0,0 +1,507 @@
+//! Async, interrupt-driven wrappers around [`I2CMaster`]/[`I2CSlave`].
+//!
+//! Instead of spinning on the FIFO/status flags the way the blocking
+//! `recv_raw`/`send_raw` do, the futures here register a per-peripheral
+//! [`AtomicWaker`] and return [`Poll::Pending`] until the corresponding
+//! interrupt handler wakes them, mirroring the approach embassy-rp takes
+//! for its I2C driver. Callers are responsible for routing the I2Cn
+//! interrupt to [`on_interrupt`] from their `#[interrupt]` handler.
+//!
+//! [`I2CMasterAsync::recv_dma`]/[`I2CMasterAsync::send_dma`] (and the
+//! `embedded-hal-async` [`I2c`] impl built on them) go a step further: the
+//! FIFO itself is drained/filled by a
+//! [`DmaChannel`](crate::peripherals::dma::DmaChannel) rather than the CPU,
+//! so callers using those also need to route the channel's `DMAn` interrupt
+//! to [`crate::peripherals::dma::on_interrupt`].
+
+use core::future::poll_fn;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Poll;
+
+use embassy_sync::waker::AtomicWaker;
+use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress};
+use embedded_hal_async::i2c::I2c;
+
+use crate::communication::InfTimeout;
+use crate::peripherals::i2c::slave::I2cTargetTransaction;
+use crate::peripherals::i2c::{I2CMaster, I2CSlave, I2cError, SlavePollResult, Terminate, GCRI2C};
+
+/// Number of I2C instances (I2C0, I2C1, I2C2) that need a waker slot.
+const NUM_I2C_INSTANCES: usize = 3;
+
+static I2C_WAKERS: [AtomicWaker; NUM_I2C_INSTANCES] =
+    [const { AtomicWaker::new() }; NUM_I2C_INSTANCES];
+static I2C_ERROR: [AtomicBool; NUM_I2C_INSTANCES] = [const { AtomicBool::new(false) }; NUM_I2C_INSTANCES];
+
+/// Identifies which of the three I2C register blocks a caller is operating
+/// on, used to index into the waker/error tables.
+#[derive(Copy, Clone)]
+pub enum I2CInstance {
+    /// I2C0
+    I2C0 = 0,
+    /// I2C1
+    I2C1 = 1,
+    /// I2C2
+    I2C2 = 2,
+}
+
+fn enable_fifo_interrupts<T: GCRI2C>(i2c_regs: &T) {
+    i2c_regs
+        .inten0()
+        .modify(|_, w| w.tx_thd().bit(true).rx_thd().bit(true).done().bit(true));
+}
+
+fn disable_fifo_interrupts<T: GCRI2C>(i2c_regs: &T) {
+    i2c_regs
+        .inten0()
+        .modify(|_, w| w.tx_thd().bit(false).rx_thd().bit(false).done().bit(false));
+}
+
+/// Call this from the `I2Cn` NVIC handler. Masks the FIFO-threshold/`done`
+/// interrupts back off (the future re-arms them on its next poll) and wakes
+/// whichever future is waiting on this instance.
+pub fn on_interrupt<T: GCRI2C>(instance: I2CInstance, i2c_regs: &T) {
+    disable_fifo_interrupts(i2c_regs);
+
+    if i2c_regs.bus_error() || i2c_regs.bus_timeout() {
+        I2C_ERROR[instance as usize].store(true, Ordering::Release);
+    }
+
+    I2C_WAKERS[instance as usize].wake();
+}
+
+/// Async extension for [`I2CMaster`] built on [`GCRI2C`] interrupts.
+pub struct I2CMasterAsync<'a, T: GCRI2C> {
+    inner: I2CMaster<'a, T>,
+    instance: I2CInstance,
+}
+
+impl<'a, T: GCRI2C> I2CMasterAsync<'a, T> {
+    /// Wraps an existing blocking [`I2CMaster`] to add the async transaction API.
+    pub fn new(inner: I2CMaster<'a, T>, instance: I2CInstance) -> Self {
+        Self { inner, instance }
+    }
+
+    /// Asynchronously writes `write` to `address`, refilling the TX FIFO
+    /// on every wake until the controller reports `done`.
+    pub async fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), I2cError> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+        self.inner.set_target_addr(address);
+
+        let mut iter = write.iter().copied();
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            enable_fifo_interrupts(&*self.inner.i2c_regs);
+
+            if I2C_ERROR[self.instance as usize].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(self
+                    .inner
+                    .i2c_regs
+                    .abort_reason()
+                    .unwrap_or(I2cError::Other(0))));
+            }
+
+            match self.inner.send_raw(&mut iter, Terminate::Stop) {
+                Ok(()) => Poll::Ready(Ok(())),
+                Err(I2cError::NoAcknowledge) => Poll::Ready(Err(I2cError::NoAcknowledge)),
+                Err(_) => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// Asynchronously reads from `address` into `read`, draining the RX
+    /// FIFO on every wake until it is full.
+    pub async fn read(
+        &mut self,
+        address: SevenBitAddress,
+        read: &mut [u8],
+    ) -> Result<(), I2cError> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+        self.inner.set_target_addr(address);
+        let len = read.len();
+
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            enable_fifo_interrupts(&*self.inner.i2c_regs);
+
+            if I2C_ERROR[self.instance as usize].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(self
+                    .inner
+                    .i2c_regs
+                    .abort_reason()
+                    .unwrap_or(I2cError::Other(0))));
+            }
+
+            match self
+                .inner
+                .recv_raw(read, &mut InfTimeout::new(), false, len, Terminate::Stop)
+            {
+                Ok(()) => Poll::Ready(Ok(())),
+                Err(I2cError::NoAcknowledge) => Poll::Ready(Err(I2cError::NoAcknowledge)),
+                Err(_) => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// DMA-driven, interrupt-backed equivalent of [`Self::read`]: the
+    /// payload moves from the receive FIFO into `read` via the
+    /// [`DmaChannel`](crate::peripherals::dma::DmaChannel) passed to
+    /// [`I2CMaster::with_dma`], parking the task on
+    /// [`DmaChannel::wait_async`](crate::peripherals::dma::DmaChannel::wait_async)
+    /// instead of spinning on either the FIFO or the DMA controller.
+    /// Returns [`I2cError::Other`] if no channel has been configured.
+    pub async fn recv_dma(
+        &mut self,
+        address: SevenBitAddress,
+        read: &mut [u8],
+    ) -> Result<(), I2cError> {
+        if self.inner.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        self.inner.set_target_addr(address);
+        self.inner.i2c_regs.clear_interrupt_flags();
+        self.inner.i2c_regs.flush_fifo();
+
+        let bytes_to_read = if read.len() >= 256 { 256 } else { read.len() };
+
+        self.inner
+            .i2c_regs
+            .rxctrl1()
+            .modify(|_, w| w.cnt().variant(bytes_to_read as u8));
+        self.inner
+            .i2c_regs
+            .fifo()
+            .write(|w| w.data().variant((address << 1) | 1));
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.inner.i2c_regs.intfl0().read().addr_ack().bit()
+            && !self.inner.i2c_regs.bus_error()
+        {}
+
+        if self.inner.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.inner
+                .i2c_regs
+                .mstctrl()
+                .modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.inner.i2c_regs.bus_error() {
+            self.inner
+                .i2c_regs
+                .mstctrl()
+                .modify(|_, w| w.stop().bit(true));
+            return Err(self
+                .inner
+                .i2c_regs
+                .abort_reason()
+                .unwrap_or(I2cError::Other(0)));
+        }
+
+        let fifo_addr = self.inner.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.inner.i2c_regs.dma_rx_request();
+        self.inner.i2c_regs.set_dma_enabled(true, false);
+
+        let channel = self
+            .inner
+            .dma_channel
+            .as_mut()
+            .expect("checked for Some above");
+        // SAFETY: `read` outlives the transfer, which we await below before
+        // touching it again; `fifo_addr` is a fixed peripheral register so
+        // it doesn't need to stay valid for `bytes_to_read` bytes, just the
+        // one address.
+        unsafe {
+            channel.start(
+                fifo_addr,
+                false,
+                read.as_mut_ptr(),
+                true,
+                bytes_to_read,
+                request,
+            );
+        }
+        let dma_result = channel.wait_async().await.map_err(|_| I2cError::Other(0));
+
+        self.inner.i2c_regs.set_dma_enabled(false, false);
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.stop().bit(true));
+
+        dma_result
+    }
+
+    /// DMA-driven, interrupt-backed equivalent of [`Self::write`]: pushes
+    /// `write` out through the
+    /// [`DmaChannel`](crate::peripherals::dma::DmaChannel) passed to
+    /// [`I2CMaster::with_dma`] one 256-byte chunk at a time, parking the
+    /// task on
+    /// [`DmaChannel::wait_async`](crate::peripherals::dma::DmaChannel::wait_async)
+    /// between chunks instead of spinning. Returns [`I2cError::Other`] if no
+    /// channel has been configured.
+    pub async fn send_dma(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), I2cError> {
+        if self.inner.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        self.inner.set_target_addr(address);
+        self.inner.i2c_regs.clear_interrupt_flags();
+        self.inner.i2c_regs.flush_fifo();
+        self.inner
+            .i2c_regs
+            .intfl0()
+            .modify(|_, w| w.tx_lockout().bit(true));
+
+        self.inner
+            .i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(address << 1));
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.inner.i2c_regs.intfl0().read().addr_ack().bit()
+            && !self.inner.i2c_regs.bus_error()
+        {}
+
+        if self.inner.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.inner
+                .i2c_regs
+                .mstctrl()
+                .modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.inner.i2c_regs.bus_error() {
+            self.inner
+                .i2c_regs
+                .mstctrl()
+                .modify(|_, w| w.stop().bit(true));
+            return Err(self
+                .inner
+                .i2c_regs
+                .abort_reason()
+                .unwrap_or(I2cError::Other(0)));
+        }
+
+        let fifo_addr = self.inner.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.inner.i2c_regs.dma_tx_request();
+
+        for chunk in write.chunks(256) {
+            self.inner.i2c_regs.set_dma_enabled(false, true);
+            let channel = self
+                .inner
+                .dma_channel
+                .as_mut()
+                .expect("checked for Some above");
+            // SAFETY: `chunk` borrows from `write`, which outlives the
+            // transfer we await below before moving to the next chunk;
+            // `fifo_addr` is a fixed peripheral register so it doesn't need
+            // to stay valid for `chunk.len()` bytes, just the one address.
+            unsafe {
+                channel.start(chunk.as_ptr(), true, fifo_addr, false, chunk.len(), request);
+            }
+            let dma_result = channel.wait_async().await.map_err(|_| I2cError::Other(0));
+            self.inner.i2c_regs.set_dma_enabled(false, false);
+            dma_result?;
+
+            if self.inner.i2c_regs.bus_error() {
+                self.inner
+                    .i2c_regs
+                    .mstctrl()
+                    .modify(|_, w| w.stop().bit(true));
+                return Err(self
+                    .inner
+                    .i2c_regs
+                    .abort_reason()
+                    .unwrap_or(I2cError::Other(0)));
+            }
+        }
+
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.stop().bit(true));
+
+        while !self.inner.i2c_regs.intfl0().read().done().bit() && !self.inner.i2c_regs.bus_error()
+        {}
+
+        if self.inner.i2c_regs.bus_error() {
+            return Err(self
+                .inner
+                .i2c_regs
+                .abort_reason()
+                .unwrap_or(I2cError::Other(0)));
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: GCRI2C> ErrorType for I2CMasterAsync<'_, T> {
+    type Error = I2cError;
+}
+
+/// Implements the real `embedded-hal-async` I2C trait on top of
+/// [`I2CMasterAsync::recv_dma`]/[`I2CMasterAsync::send_dma`], so the core is
+/// free for the duration of a large transaction instead of pinned polling
+/// the FIFO the way [`I2CMasterAsync::read`]/[`I2CMasterAsync::write`] do.
+/// Requires a [`DmaChannel`](crate::peripherals::dma::DmaChannel) configured
+/// via [`I2CMaster::with_dma`] before wrapping it in [`I2CMasterAsync::new`].
+impl<T: GCRI2C> I2c for I2CMasterAsync<'_, T> {
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations.iter_mut() {
+            match operation {
+                Operation::Read(read) => self.recv_dma(address, read).await?,
+                Operation::Write(write) => self.send_dma(address, write).await?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Async extension for [`I2CSlave`] built on [`GCRI2C`] interrupts.
+pub struct I2CSlaveAsync<'a, T: GCRI2C> {
+    inner: I2CSlave<'a, T>,
+    instance: I2CInstance,
+}
+
+impl<'a, T: GCRI2C> I2CSlaveAsync<'a, T> {
+    /// Wraps an existing blocking [`I2CSlave`] to add the async poll API.
+    pub fn new(inner: I2CSlave<'a, T>, instance: I2CInstance) -> Self {
+        Self { inner, instance }
+    }
+
+    /// Awaits the next [`SlavePollResult`] without busy-looping `slave_poll`.
+    pub async fn poll(&mut self) -> Result<SlavePollResult, I2cError> {
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            self.inner
+                .i2c_regs
+                .inten0()
+                .modify(|_, w| w.addr_match().bit(true).done().bit(true));
+
+            match self.inner.slave_poll(&mut InfTimeout::new()) {
+                Ok(result) => Poll::Ready(Ok(result)),
+                Err(_) => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// Asynchronously receives a master write into `buffer`, the async
+    /// counterpart to [`I2CSlave::recv_raw`]. Registers this instance's
+    /// waker and arms `rx_thd`/`done` before each attempt, so the core isn't
+    /// woken to drain the FIFO until [`on_interrupt`] observes one of those
+    /// flags firing.
+    pub async fn recv_raw(
+        &mut self,
+        buffer: &mut [u8],
+        rst_on_byte: bool,
+    ) -> Result<(u32, bool), I2cError> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            self.inner
+                .i2c_regs
+                .inten0()
+                .modify(|_, w| w.rx_thd().bit(true).done().bit(true));
+
+            if I2C_ERROR[self.instance as usize].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(self
+                    .inner
+                    .i2c_regs
+                    .abort_reason()
+                    .unwrap_or(I2cError::Other(0))));
+            }
+
+            match self.inner.recv_raw(buffer, &mut InfTimeout::new(), rst_on_byte) {
+                Ok(result) => Poll::Ready(Ok(result)),
+                Err(_) => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// Asynchronously sends `buffer` to the master, the async counterpart
+    /// to [`I2CSlave::send_raw`]. Registers this instance's waker and arms
+    /// `tx_thd`/`done` before each attempt, the transmit-side mirror of
+    /// [`Self::recv_raw`].
+    pub async fn send_raw<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<u32, I2cError> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            self.inner
+                .i2c_regs
+                .inten0()
+                .modify(|_, w| w.tx_thd().bit(true).done().bit(true));
+
+            if I2C_ERROR[self.instance as usize].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(self
+                    .inner
+                    .i2c_regs
+                    .abort_reason()
+                    .unwrap_or(I2cError::Other(0))));
+            }
+
+            match self.inner.send_raw(buffer) {
+                Ok(n) => Poll::Ready(Ok(n)),
+                Err(_) => Poll::Pending,
+            }
+        })
+        .await
+    }
+}
+
+impl<T: GCRI2C> embedded_hal::i2c::ErrorType for I2CSlaveAsync<'_, T> {
+    type Error = I2cError;
+}
+
+/// Async counterpart to [`crate::peripherals::i2c::slave::I2cTarget`], built
+/// on the interrupt-driven
+/// [`I2CSlaveAsync::poll`]/[`I2CSlaveAsync::recv_raw`]/[`I2CSlaveAsync::send_raw`]
+/// instead of busy-polling `slave_poll`/`recv_raw`/`send_raw`. `embedded-hal-async`
+/// has no standard target-mode trait either, so this is crate-local for the
+/// same reason [`I2cTarget`] is.
+pub trait I2cTargetAsync: embedded_hal::i2c::ErrorType {
+    /// Awaits the next transaction addressed to us and services it: a write
+    /// is drained into `write_buf`, a read is served from `read_buf`.
+    async fn listen(
+        &mut self,
+        write_buf: &mut [u8],
+        read_buf: &[u8],
+    ) -> Result<I2cTargetTransaction, Self::Error>;
+}
+
+impl<T: GCRI2C> I2cTargetAsync for I2CSlaveAsync<'_, T> {
+    async fn listen(
+        &mut self,
+        write_buf: &mut [u8],
+        read_buf: &[u8],
+    ) -> Result<I2cTargetTransaction, Self::Error> {
+        match self.poll().await? {
+            SlavePollResult::IncomingTransmission { .. } => {
+                let (len, truncated) = self.recv_raw(write_buf, false).await?;
+                Ok(I2cTargetTransaction::Write { len, truncated })
+            }
+            SlavePollResult::TransmitNeeded => {
+                let len = self.send_raw(&mut read_buf.iter().copied()).await?;
+                Ok(I2cTargetTransaction::Read { len })
+            }
+        }
+    }
+}
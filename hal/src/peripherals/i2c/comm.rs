@@ -28,17 +28,18 @@ impl<T: GCRI2C> CommStackRx for I2CSlave<'_, T> {
         tmr: &mut TMR,
         rst_on_data: bool,
     ) -> crate::communication::Result<usize> {
-        if let Ok(SlavePollResult::IncomingTransmission) = self.slave_poll(tmr) {
+        if let Ok(SlavePollResult::IncomingTransmission { .. }) = self.slave_poll(tmr) {
             tmr.reset();
             let mut bytes_sent_buf = [0u8; 4];
-            if let Ok((n, _)) = self.recv_raw(&mut bytes_sent_buf, tmr, rst_on_data) {
+            if let Ok((n, _)) = self.recv_raw_auto(&mut bytes_sent_buf, tmr, rst_on_data) {
                 if n != 4 {
                     return Err(CommunicationError::RecvError(0));
                 }
                 let expected_to_recv = u32::from_le_bytes(bytes_sent_buf);
-                return if let Ok(SlavePollResult::IncomingTransmission) = self.slave_poll(tmr) {
+                return if let Ok(SlavePollResult::IncomingTransmission { .. }) = self.slave_poll(tmr)
+                {
                     let (n, _) = self
-                        .recv_raw(dest, tmr, true)
+                        .recv_raw_auto(dest, tmr, true)
                         .map_err(|_| CommunicationError::RecvError(0))?;
                     if n != expected_to_recv {
                         return Err(CommunicationError::RecvError(n as usize));
@@ -69,6 +70,14 @@ impl<T: GCRI2C> RxChannel for I2CSlave<'_, T> {
     ) -> crate::communication::Result<usize> {
         self.rx_channel_recv(dest, tmr, false)
     }
+
+    fn try_recv(&mut self, _dest: &mut [u8]) -> crate::communication::Result<usize> {
+        // Unlike UART's byte-stream FIFO, an I2C slave transaction only
+        // exists once the master actively initiates one via `slave_poll`,
+        // so there's never anything "immediately available" to drain
+        // without blocking on the bus.
+        Ok(0)
+    }
 }
 
 impl<T: GCRI2C> CommStackRx for I2CMaster<'_, T> {
@@ -80,18 +89,19 @@ impl<T: GCRI2C> CommStackRx for I2CMaster<'_, T> {
     ) -> crate::communication::Result<usize> {
         let mut bytes_sent_buf = [0u8; 4];
         delay(MASTER_DELAY);
-        if let Ok(()) = self.recv_raw(&mut bytes_sent_buf, tmr, rst_on_data, 4) {
+        if let Ok(()) = self.recv_raw_auto(&mut bytes_sent_buf, tmr, rst_on_data, 4) {
             let bytes_to_read = u32::from_le_bytes(bytes_sent_buf);
             for i in 0..(bytes_to_read / 256) as usize {
                 delay(MASTER_DELAY); // TODO: mitigate these delays bc this is... a lot
-                let Ok(_) = self.recv_raw(&mut dest[i * 256..], tmr, rst_on_data, 256) else {
+                let Ok(_) = self.recv_raw_auto(&mut dest[i * 256..], tmr, rst_on_data, 256) else {
                     return Err(CommunicationError::RecvError(i * 256));
                 };
             }
             delay(MASTER_DELAY);
             let leftover = dest.len() - (dest.len() % 256);
             let leftover_len = dest.len() % 256;
-            let Ok(_) = self.recv_raw(&mut dest[leftover..], tmr, rst_on_data, leftover_len) else {
+            let Ok(_) = self.recv_raw_auto(&mut dest[leftover..], tmr, rst_on_data, leftover_len)
+            else {
                 return Err(CommunicationError::RecvError(leftover));
             };
             return Ok(bytes_to_read as usize);
@@ -116,6 +126,13 @@ impl<T: GCRI2C> RxChannel for I2CMaster<'_, T> {
     ) -> crate::communication::Result<usize> {
         self.rx_channel_recv(dest, tmr, false)
     }
+
+    fn try_recv(&mut self, _dest: &mut [u8]) -> crate::communication::Result<usize> {
+        // I2C master reads require actively driving the bus to request
+        // data, so there's nothing to opportunistically drain without
+        // blocking the way there is on UART's FIFO.
+        Ok(0)
+    }
 }
 
 impl<T: GCRI2C> FramedTxChannel for I2CSlave<'_, T> {
@@ -127,13 +144,13 @@ impl<T: GCRI2C> FramedTxChannel for I2CSlave<'_, T> {
         let mut iter = frame.into_byte_iter();
         let len = iter.length();
         if let Ok(SlavePollResult::TransmitNeeded) = self.slave_poll(&mut InfTimeout::new()) {
-            let Ok(_) = self.send_raw(&mut u32::to_le_bytes(len as u32).into_iter()) else {
+            let Ok(_) = self.send_raw_auto(&mut u32::to_le_bytes(len as u32).into_iter()) else {
                 return Err(CommunicationError::SendError);
             };
             for _ in 0..len.div_ceil(256) {
                 if let Ok(SlavePollResult::TransmitNeeded) = self.slave_poll(&mut InfTimeout::new())
                 {
-                    let Ok(_) = self.send_raw(&mut iter) else {
+                    let Ok(_) = self.send_raw_auto(&mut iter) else {
                         return Err(CommunicationError::SendError);
                     };
                 } else {
@@ -158,7 +175,7 @@ impl<T: GCRI2C> FramedTxChannel for I2CMaster<'_, T> {
             return Err(CommunicationError::SendError);
         };
         delay(MASTER_DELAY);
-        let Ok(_) = self.send_raw(&mut iter) else {
+        let Ok(_) = self.send_raw_auto(&mut iter) else {
             return Err(CommunicationError::SendError);
         };
         Ok(())
@@ -1,12 +1,46 @@
 use crate::communication::{InfTimeout, Timeout};
+use crate::peripherals::dma::DmaChannel;
 use crate::peripherals::gpio::active::port_num_types::GpioZero;
-use crate::peripherals::gpio::active::ActivePinHandle;
-use crate::peripherals::gpio::pin_traits::IoPin;
+use crate::peripherals::gpio::active::{
+    ActiveInputPinConfig, ActiveOutputPinConfig, ActivePinHandle, DriveStrength, OutputDriveMode,
+    PowerSupply, PullMode,
+};
+use crate::peripherals::gpio::pin_traits::{GeneralIoPin, InputPin, IoPin, OutputPin, PinState};
 use crate::peripherals::gpio::{GpioError, PinOperatingMode};
-use crate::peripherals::i2c::{BusSpeed, I2CMaster, GCRI2C};
+use crate::peripherals::i2c::{
+    compute_timings, validate_seven_bit_address, validate_ten_bit_address, BusSpeed,
+    FifoEmptyMode, I2CMaster, I2cClockTiming, I2cError, Terminate, GCRI2C,
+};
 use crate::peripherals::oscillator::SystemClock;
 use core::cell::{Ref, RefMut};
-use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation, SevenBitAddress};
+use cortex_m::asm::delay;
+use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress, TenBitAddress};
+
+/// Cycles to hold each half of a manually-clocked SCL pulse during
+/// [`I2CMaster::recover_bus`], long enough to be well under even the
+/// slowest [`BusSpeed`] this driver supports.
+const RECOVERY_PULSE_DELAY: u32 = 1000;
+
+/// Digital-I/O, push-pull config [`I2CMaster::recover_bus`] drives SCL/SDA
+/// with while bit-banging the bus clear -- matches the reset-state power
+/// supply/drive settings [`I2CMaster::new`] leaves SCL/SDA in.
+fn recovery_output_config() -> ActiveOutputPinConfig {
+    ActiveOutputPinConfig {
+        operating_mode: PinOperatingMode::DigitalIo,
+        power_supply: PowerSupply::Vddio,
+        drive_strength: DriveStrength::S0,
+        drive_mode: OutputDriveMode::PushPull,
+    }
+}
+
+/// Digital-I/O input config [`I2CMaster::recover_bus`] reads SDA with.
+fn recovery_input_config() -> ActiveInputPinConfig {
+    ActiveInputPinConfig {
+        operating_mode: PinOperatingMode::DigitalIo,
+        power_supply: PowerSupply::Vddio,
+        pull_mode: PullMode::HighImpedance,
+    }
+}
 
 impl<'a, T: GCRI2C> I2CMaster<'a, T> {
     pub(crate) fn new(
@@ -38,20 +72,122 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
                 .bit(false)
         });
 
-        let target_speed = match bus_speed {
-            BusSpeed::Standard100kbps => 100_000,
-            BusSpeed::Fast400kbps => 400_000,
-            BusSpeed::FastPlus1mbps => 1_000_000,
-        };
+        let pclk_speed = (system_clock.get_freq() / u32::from(system_clock.get_div()) / 2).to_hz();
+
+        let fs_timings = compute_timings(
+            pclk_speed,
+            bus_speed.clkhi_clklo_target_hz(),
+            bus_speed.rise_time_ns(),
+            bus_speed.fast_mode_duty(),
+        );
+        i2c_regs.clkhi().write(|w| w.hi().variant(fs_timings.hi));
+        i2c_regs.clklo().write(|w| w.lo().variant(fs_timings.lo));
+
+        if let Some(hs_target_hz) = bus_speed.hsclk_target_hz() {
+            let hs_timings =
+                compute_timings(pclk_speed, hs_target_hz, bus_speed.rise_time_ns(), true);
+            i2c_regs.hsclk().write(|w| {
+                w.hsclk_hi()
+                    .variant(hs_timings.hi)
+                    .hsclk_lo()
+                    .variant(hs_timings.lo)
+            });
+            i2c_regs.ctrl().modify(|_, w| w.hs_en().bit(true));
+        }
+
+        i2c_regs.ctrl().modify(|_, w| w.en().bit(true));
+
+        Ok(Self {
+            i2c_regs,
+            target_addr,
+            scl_pin,
+            sda_pin,
+            dma_channel: None,
+            fifo_empty_mode: FifoEmptyMode::ClockStretch,
+        })
+    }
+
+    /// Equips this master with a DMA channel, switching
+    /// [`Self::recv_raw_dma`]/[`Self::send_raw_dma`] -- and the
+    /// `CommStackRx`/`FramedTxChannel` impls in
+    /// [`crate::peripherals::i2c::comm`] -- from CPU-polled FIFO access to
+    /// DMA-driven transfers.
+    pub fn with_dma(mut self, channel: DmaChannel<'a>) -> Self {
+        self.dma_channel = Some(channel);
+        self
+    }
+
+    /// Unwedges a bus left with SDA stuck low by a target that was cut off
+    /// mid-byte (e.g. by an aborted transfer), the same "clock out of it"
+    /// recovery embassy-rp's I2C driver performs. Takes over `scl_pin`/
+    /// `sda_pin` as plain digital I/O -- SCL push-pull output, SDA input --
+    /// and pulses SCL up to 9 times, checking SDA after each pulse; a target
+    /// only ever holds SDA low to finish shifting out a byte it owns the bus
+    /// for, so it eventually releases SDA on one of these artificial clocks.
+    /// Once SDA reads high, synthesizes a STOP condition by driving SDA low
+    /// then releasing it while SCL is held high, then restores both pins to
+    /// [`PinOperatingMode::AltFunction1`] and re-enables the controller.
+    ///
+    /// Consumes and returns `self` because the pins are temporarily
+    /// reconfigured away from the [`ActivePinHandle`] type this master
+    /// otherwise stores them as.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`I2cError::Other`] if SDA is still stuck low after 9 pulses,
+    /// or if reconfiguring either pin fails.
+    pub fn recover_bus(self) -> Result<Self, I2cError> {
+        let Self {
+            i2c_regs,
+            target_addr,
+            scl_pin,
+            sda_pin,
+            dma_channel,
+            fifo_empty_mode,
+        } = self;
+
+        let mut scl = scl_pin
+            .into_output_pin(PinState::High, recovery_output_config())
+            .map_err(|_| I2cError::Other(0))?;
+        let mut sda = sda_pin
+            .into_input_pin(recovery_input_config())
+            .map_err(|_| I2cError::Other(0))?;
+
+        let mut released = sda.is_high().map_err(|_| I2cError::Other(0))?;
+        for _ in 0..9 {
+            if released {
+                break;
+            }
+
+            scl.set_low().map_err(|_| I2cError::Other(0))?;
+            delay(RECOVERY_PULSE_DELAY);
+            scl.set_high().map_err(|_| I2cError::Other(0))?;
+            delay(RECOVERY_PULSE_DELAY);
 
-        // calculations pulled from msdk
-        let pclk_speed = system_clock.get_freq() / (system_clock.get_div() as u32) / 2;
+            released = sda.is_high().map_err(|_| I2cError::Other(0))?;
+        }
 
-        let multiplier = pclk_speed / target_speed;
-        let val = multiplier / 2 - 1;
+        if !released {
+            return Err(I2cError::Other(0));
+        }
 
-        i2c_regs.clkhi().write(|w| w.hi().variant(val as u16));
-        i2c_regs.clklo().write(|w| w.lo().variant(val as u16));
+        // Synthesize a STOP condition: SCL is already high, so drive SDA low
+        // then release it back to high.
+        let mut sda = sda
+            .into_output_pin(PinState::Low, recovery_output_config())
+            .map_err(|_| I2cError::Other(0))?;
+        delay(RECOVERY_PULSE_DELAY);
+        sda.set_high().map_err(|_| I2cError::Other(0))?;
+        delay(RECOVERY_PULSE_DELAY);
+
+        let mut scl_pin = scl.into_analog_pin().into_pin_handle();
+        let mut sda_pin = sda.into_analog_pin().into_pin_handle();
+        scl_pin
+            .set_operating_mode(PinOperatingMode::AltFunction1)
+            .map_err(|_| I2cError::Other(0))?;
+        sda_pin
+            .set_operating_mode(PinOperatingMode::AltFunction1)
+            .map_err(|_| I2cError::Other(0))?;
 
         i2c_regs.ctrl().modify(|_, w| w.en().bit(true));
 
@@ -60,6 +196,8 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
             target_addr,
             scl_pin,
             sda_pin,
+            dma_channel,
+            fifo_empty_mode,
         })
     }
 
@@ -73,14 +211,52 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
         self.target_addr
     }
 
-    /// Reads up to 256 bytes to read slice, in single i2c transaction
+    /// Selects how [`Self::send_raw`] behaves when its `buffer` iterator
+    /// can't keep up with the transmit FIFO draining: [`FifoEmptyMode::ClockStretch`]
+    /// (the default) holds SCL low until `buffer` yields another byte,
+    /// which is fine for a producer that's merely slow but can wedge a
+    /// watchdog-sensitive bus if `buffer` stalls for a long time;
+    /// [`FifoEmptyMode::AutoStop`] lets the controller end the transaction
+    /// itself instead of stretching the clock. Programs `ctrl.clkstr_dis`
+    /// immediately, so it's safe to call between transfers.
+    pub fn set_fifo_empty_mode(&mut self, mode: FifoEmptyMode) {
+        self.i2c_regs
+            .ctrl()
+            .modify(|_, w| w.clkstr_dis().bit(mode.clkstr_dis()));
+        self.fifo_empty_mode = mode;
+    }
+
+    /// Reads back the SCL high/low timing actually programmed for the
+    /// [`BusSpeed`] this master was constructed with.
+    pub fn clock_timing(&self) -> I2cClockTiming {
+        I2cClockTiming {
+            hi: self.i2c_regs.clkhi().read().hi().bits(),
+            lo: self.i2c_regs.clklo().read().lo().bits(),
+        }
+    }
+
+    /// Sets `mstctrl.stop` or `mstctrl.restart` per `terminate`, called once
+    /// a transfer's payload has been fully shifted in/out.
+    fn apply_terminate(&self, terminate: Terminate) {
+        match terminate {
+            Terminate::Stop => self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true)),
+            Terminate::Restart => self.i2c_regs.mstctrl().modify(|_, w| w.restart().bit(true)),
+        }
+    }
+
+    /// Reads up to 256 bytes to read slice, in single i2c transaction.
+    /// `terminate` selects whether the transfer ends in a STOP (releasing
+    /// the bus) or a repeated START (holding it for a following operation).
     pub fn recv_raw<TMT: Timeout>(
         &mut self,
         read: &mut [u8],
         tmt: &mut TMT,
         rst_on_byte: bool,
         num_to_read: usize,
-    ) -> Result<(), ErrorKind> {
+        terminate: Terminate,
+    ) -> Result<(), I2cError> {
+        validate_seven_bit_address(self.target_addr)?;
+
         // Let's flush the FIFO buffers
         self.i2c_regs.clear_interrupt_flags();
         self.i2c_regs.flush_fifo();
@@ -112,12 +288,12 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
 
         if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
             self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+            return Err(I2cError::NoAcknowledge);
         }
 
         if self.i2c_regs.bus_error() || tmt.poll() {
             self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-            return Err(ErrorKind::Bus);
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
         }
         // The I2C controller receives data from the slave and automatically ACKs each byte. The software must retrieve this
         // data by reading the I2Cn_FIFO register.
@@ -125,7 +301,7 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
             while self.i2c_regs.is_rx_fifo_empty() && !self.i2c_regs.bus_error() && !tmt.poll() {}
             if self.i2c_regs.bus_error() || tmt.poll() {
                 self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
             }
             *cell = self.i2c_regs.fifo().read().data().bits();
             num_read += 1;
@@ -139,7 +315,7 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
             while self.i2c_regs.is_rx_fifo_empty() && !self.i2c_regs.bus_error() && !tmt.poll() {}
             if self.i2c_regs.bus_error() || tmt.poll() {
                 self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
             }
             self.i2c_regs.fifo().read();
             num_read += 1;
@@ -148,16 +324,21 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
             }
         }
 
-        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+        self.apply_terminate(terminate);
 
         Ok(())
     }
 
-    /// Sends bytes from slice to slave specified by address.
-    #[allow(clippy::while_let_on_iterator)]
-    // while let is needed as this relies on only partially consuming an iterator
-    // for .. in appears to consume the entire iterator
-    pub fn send_raw<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<(), ErrorKind> {
+    /// Sends bytes from slice to slave specified by address. `terminate`
+    /// selects whether the transfer ends in a STOP (releasing the bus) or a
+    /// repeated START (holding it for a following operation).
+    pub fn send_raw<I: Iterator<Item = u8>>(
+        &mut self,
+        buffer: &mut I,
+        terminate: Terminate,
+    ) -> Result<(), I2cError> {
+        validate_seven_bit_address(self.target_addr)?;
+
         // Let's flush the FIFO buffers
         self.i2c_regs.clear_interrupt_flags();
         self.i2c_regs.flush_fifo();
@@ -199,27 +380,51 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
 
         if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
             self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+            return Err(I2cError::NoAcknowledge);
         }
 
         if self.i2c_regs.bus_error() {
             self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-            return Err(ErrorKind::Bus);
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
         }
 
-        while let Some(byte) = buffer.next() {
+        loop {
+            // In `AutoStop` mode the controller ends the transaction itself
+            // as soon as the FIFO underruns, rather than stretching SCL
+            // waiting for us to refill it (`ctrl.clkstr_dis`, set by
+            // `set_fifo_empty_mode`). Check for that *before* pulling the
+            // next byte out of `buffer`, since for a slow producer that call
+            // can block for a while -- there's no point waiting on more
+            // data for a transaction the hardware has already closed out.
+            if matches!(self.fifo_empty_mode, FifoEmptyMode::AutoStop)
+                && self.i2c_regs.intfl0().read().done().bit()
+            {
+                return Ok(());
+            }
+
+            let Some(byte) = buffer.next() else {
+                break;
+            };
+
             while self.i2c_regs.status().read().tx_full().bit() && !self.i2c_regs.bus_error() {}
             if self.i2c_regs.bus_error() {
                 self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
             }
             self.i2c_regs.fifo().write(|w| w.data().variant(byte));
+            // Check for a data-phase NACK right after writing the byte that
+            // triggered it, rather than waiting for the next iteration's
+            // generic `bus_error` poll.
+            if self.i2c_regs.intfl0().read().data_err().bit() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(I2cError::DataNoAcknowledge);
+            }
         }
 
         // Once the software writes all the desired bytes to the I2Cn_FIFO register; the software should set either
         // I2Cn_MSTCTRL.restart or I2Cn_MSTCTRL.stop.
 
-        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+        self.apply_terminate(terminate);
 
         // Once the controller sends all the remaining bytes and empties the transmit FIFO, the hardware sets
         // I2Cn_INTFL0.done and proceeds to send out either a RESTART condition if I2Cn_MSTCTRL.restart was set, or a
@@ -228,15 +433,376 @@ impl<'a, T: GCRI2C> I2CMaster<'a, T> {
         while !self.i2c_regs.intfl0().read().done().bit() && !self.i2c_regs.bus_error() {}
 
         if self.i2c_regs.bus_error() {
-            return Err(ErrorKind::Bus);
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        Ok(())
+    }
+
+    /// DMA-driven equivalent of [`Self::recv_raw`]: still one transaction of
+    /// up to 256 bytes, but the payload is moved from the receive FIFO into
+    /// `read` by the [`DmaChannel`] passed to [`Self::with_dma`] instead of a
+    /// CPU polling loop. Returns [`I2cError::Other`] if no channel has been
+    /// configured.
+    pub fn recv_raw_dma<TMT: Timeout>(
+        &mut self,
+        read: &mut [u8],
+        tmt: &mut TMT,
+        num_to_read: usize,
+    ) -> Result<(), I2cError> {
+        if self.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        validate_seven_bit_address(self.target_addr)?;
+
+        self.i2c_regs.clear_interrupt_flags();
+        self.i2c_regs.flush_fifo();
+
+        let bytes_to_read = if num_to_read >= 256 { 256 } else { num_to_read };
+
+        self.i2c_regs
+            .rxctrl1()
+            .modify(|_, w| w.cnt().variant(bytes_to_read as u8));
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant((self.target_addr << 1) | 1));
+        self.i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.i2c_regs.intfl0().read().addr_ack().bit()
+            && !self.i2c_regs.bus_error()
+            && !tmt.poll()
+        {}
+
+        if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.i2c_regs.bus_error() || tmt.poll() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
+        }
+
+        let fifo_addr = self.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.i2c_regs.dma_rx_request();
+        self.i2c_regs.set_dma_enabled(true, false);
+
+        // Guarded by the `dma_channel.is_none()` check above.
+        let channel = self.dma_channel.as_mut().expect("checked for Some above");
+        // SAFETY: `read` outlives the transfer, which we wait on below
+        // before touching it again; `fifo_addr` is a fixed peripheral
+        // register so it doesn't need to stay valid for `bytes_to_read`
+        // bytes, just the one address.
+        unsafe {
+            channel.start(
+                fifo_addr,
+                false,
+                read.as_mut_ptr(),
+                true,
+                bytes_to_read,
+                request,
+            );
+        }
+        let dma_result = channel.wait().map_err(|_| I2cError::Other(0));
+
+        self.i2c_regs.set_dma_enabled(false, false);
+        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+
+        dma_result
+    }
+
+    /// DMA-driven equivalent of [`Self::send_raw`]: drains `buffer` through a
+    /// fixed-size scratch array one 256-byte chunk at a time, pushing each
+    /// chunk out through the [`DmaChannel`] passed to [`Self::with_dma`]
+    /// instead of a CPU polling loop. Returns [`I2cError::Other`] if no
+    /// channel has been configured.
+    #[allow(clippy::while_let_on_iterator)]
+    pub fn send_raw_dma<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<(), I2cError> {
+        if self.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        validate_seven_bit_address(self.target_addr)?;
+
+        self.i2c_regs.clear_interrupt_flags();
+        self.i2c_regs.flush_fifo();
+
+        self.i2c_regs
+            .intfl0()
+            .modify(|_, w| w.tx_lockout().bit(true));
+
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(self.target_addr << 1));
+
+        self.i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.i2c_regs.intfl0().read().addr_ack().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.i2c_regs.bus_error() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        let fifo_addr = self.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.i2c_regs.dma_tx_request();
+        let mut chunk = [0u8; 256];
+
+        loop {
+            let mut chunk_len = 0;
+            while chunk_len < chunk.len() {
+                match buffer.next() {
+                    Some(byte) => {
+                        chunk[chunk_len] = byte;
+                        chunk_len += 1;
+                    }
+                    None => break,
+                }
+            }
+            if chunk_len == 0 {
+                break;
+            }
+
+            self.i2c_regs.set_dma_enabled(false, true);
+            // Guarded by the `dma_channel.is_none()` check above this loop.
+            let channel = self.dma_channel.as_mut().expect("checked for Some above");
+            // SAFETY: `chunk` outlives the transfer, which we wait on below
+            // before reusing it; `fifo_addr` is a fixed peripheral register
+            // so it doesn't need to stay valid for `chunk_len` bytes, just
+            // the one address.
+            unsafe {
+                channel.start(chunk.as_ptr(), true, fifo_addr, false, chunk_len, request);
+            }
+            let dma_result = channel.wait().map_err(|_| I2cError::Other(0));
+            self.i2c_regs.set_dma_enabled(false, false);
+            dma_result?;
+
+            if self.i2c_regs.bus_error() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+        }
+
+        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+
+        while !self.i2c_regs.intfl0().read().done().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.bus_error() {
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        Ok(())
+    }
+
+    /// Reads via [`Self::recv_raw_dma`] if a DMA channel has been configured
+    /// ([`Self::with_dma`]), falling back to the CPU-polled [`Self::recv_raw`]
+    /// otherwise.
+    pub(crate) fn recv_raw_auto<TMT: Timeout>(
+        &mut self,
+        read: &mut [u8],
+        tmt: &mut TMT,
+        rst_on_byte: bool,
+        num_to_read: usize,
+    ) -> Result<(), I2cError> {
+        if self.dma_channel.is_some() {
+            self.recv_raw_dma(read, tmt, num_to_read)
+        } else {
+            self.recv_raw(read, tmt, rst_on_byte, num_to_read, Terminate::Stop)
+        }
+    }
+
+    /// Sends via [`Self::send_raw_dma`] if a DMA channel has been configured
+    /// ([`Self::with_dma`]), falling back to the CPU-polled [`Self::send_raw`]
+    /// otherwise.
+    pub(crate) fn send_raw_auto<I: Iterator<Item = u8>>(
+        &mut self,
+        buffer: &mut I,
+    ) -> Result<(), I2cError> {
+        if self.dma_channel.is_some() {
+            self.send_raw_dma(buffer)
+        } else {
+            self.send_raw(buffer, Terminate::Stop)
+        }
+    }
+
+    /// First byte of the two-byte `11110xx0` 10-bit address header: the
+    /// `11110` prefix, the top two bits of `addr`, and a R/W bit of 0.
+    fn ten_bit_header_byte(addr: u16) -> u8 {
+        0b1111_0000 | (((addr >> 8) & 0b11) as u8) << 1
+    }
+
+    /// Sends `buffer` to the 10-bit target `addr`, emitting the two-byte
+    /// `11110xx0` address header before the data bytes.
+    #[allow(clippy::while_let_on_iterator)]
+    fn send_raw_ten_bit<I: Iterator<Item = u8>>(
+        &mut self,
+        addr: u16,
+        buffer: &mut I,
+    ) -> Result<(), I2cError> {
+        validate_ten_bit_address(addr)?;
+
+        self.i2c_regs.clear_interrupt_flags();
+        self.i2c_regs.flush_fifo();
+
+        self.i2c_regs
+            .intfl0()
+            .modify(|_, w| w.tx_lockout().bit(true));
+
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(Self::ten_bit_header_byte(addr)));
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant((addr & 0xFF) as u8));
+
+        while !self.i2c_regs.status().read().tx_full().bit() {
+            if let Some(byte) = buffer.next() {
+                self.i2c_regs.fifo().write(|w| w.data().variant(byte));
+            } else {
+                break;
+            }
+        }
+
+        self.i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.i2c_regs.intfl0().read().addr_ack().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.i2c_regs.bus_error() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        while let Some(byte) = buffer.next() {
+            while self.i2c_regs.status().read().tx_full().bit() && !self.i2c_regs.bus_error() {}
+            if self.i2c_regs.bus_error() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+            self.i2c_regs.fifo().write(|w| w.data().variant(byte));
+            if self.i2c_regs.intfl0().read().data_err().bit() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(I2cError::DataNoAcknowledge);
+            }
+        }
+
+        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+
+        while !self.i2c_regs.intfl0().read().done().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.bus_error() {
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
         }
 
         Ok(())
     }
+
+    /// Reads up to 256 bytes from the 10-bit target `addr`.
+    ///
+    /// Per the I2C specification, a 10-bit read is a write of the address
+    /// header (R/W = 0) followed by a repeated START and a second copy of
+    /// the header byte with R/W = 1, rather than a single header with the
+    /// R/W bit set like the 7-bit case.
+    fn recv_raw_ten_bit(&mut self, addr: u16, read: &mut [u8]) -> Result<(), I2cError> {
+        validate_ten_bit_address(addr)?;
+
+        self.i2c_regs.clear_interrupt_flags();
+        self.i2c_regs.flush_fifo();
+
+        let bytes_to_read = read.len().min(256);
+        self.i2c_regs
+            .rxctrl1()
+            .modify(|_, w| w.cnt().variant(bytes_to_read as u8));
+
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(Self::ten_bit_header_byte(addr)));
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant((addr & 0xFF) as u8));
+
+        self.i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        while !self.i2c_regs.intfl0().read().addr_ack().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.i2c_regs.bus_error() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        // Repeated START, then re-send the header byte with R/W = 1 to turn
+        // the bus around into a read.
+        self.i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.restart().bit(true));
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(Self::ten_bit_header_byte(addr) | 1));
+
+        while !self.i2c_regs.intfl0().read().addr_ack().bit() && !self.i2c_regs.bus_error() {}
+
+        if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(I2cError::NoAcknowledge);
+        }
+
+        if self.i2c_regs.bus_error() {
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+            return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+        }
+
+        let mut num_read = 0;
+        for cell in read.iter_mut().take(bytes_to_read) {
+            while self.i2c_regs.is_rx_fifo_empty() && !self.i2c_regs.bus_error() {}
+            if self.i2c_regs.bus_error() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+            *cell = self.i2c_regs.fifo().read().data().bits();
+            num_read += 1;
+        }
+
+        while num_read < bytes_to_read {
+            while self.i2c_regs.is_rx_fifo_empty() && !self.i2c_regs.bus_error() {}
+            if self.i2c_regs.bus_error() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+            self.i2c_regs.fifo().read();
+            num_read += 1;
+        }
+
+        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+
+        Ok(())
+    }
 }
 
 impl<T: GCRI2C> ErrorType for I2CMaster<'_, T> {
-    type Error = ErrorKind;
+    type Error = I2cError;
 }
 
 impl<T: GCRI2C> embedded_hal::i2c::I2c for I2CMaster<'_, T> {
@@ -245,7 +811,13 @@ impl<T: GCRI2C> embedded_hal::i2c::I2c for I2CMaster<'_, T> {
         let old_addr = self.get_target_addr();
         self.set_target_addr(address);
         for i in 0..bytes_to_read / 256 {
-            self.recv_raw(&mut read[i * 256..], &mut InfTimeout::new(), false, 256)?;
+            self.recv_raw(
+                &mut read[i * 256..],
+                &mut InfTimeout::new(),
+                false,
+                256,
+                Terminate::Restart,
+            )?;
         }
         let leftover = read.len() - (read.len() % 256);
         let leftover_len = read.len() % 256;
@@ -254,6 +826,7 @@ impl<T: GCRI2C> embedded_hal::i2c::I2c for I2CMaster<'_, T> {
             &mut InfTimeout::new(),
             false,
             leftover_len,
+            Terminate::Stop,
         )?;
         self.set_target_addr(old_addr);
         Ok(())
@@ -262,36 +835,144 @@ impl<T: GCRI2C> embedded_hal::i2c::I2c for I2CMaster<'_, T> {
     fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
         let old_addr = self.get_target_addr();
         self.set_target_addr(address);
-        self.send_raw(&mut write.iter().copied())?;
+        self.send_raw(&mut write.iter().copied(), Terminate::Stop)?;
         self.set_target_addr(old_addr);
         Ok(())
     }
 
+    /// Implemented in terms of [`Self::transaction`] so the write and read
+    /// halves are joined by a real repeated START (`mstctrl.restart`)
+    /// instead of a STOP followed by a fresh START -- many register-addressed
+    /// sensors only latch their register pointer across a repeated START.
     fn write_read(
         &mut self,
         address: SevenBitAddress,
         write: &[u8],
         read: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.write(address, write)?;
-        self.read(address, read)?;
-        Ok(())
+        self.transaction(address, &mut [Operation::Write(write), Operation::Read(read)])
     }
 
+    /// Runs consecutive writes as a single hardware transfer (one address
+    /// byte, one `send_raw` call per run). Consecutive reads are not merged:
+    /// each read operation is its own `recv_raw` transfer (several for a
+    /// read over 256 bytes), with its own address byte. Every transfer but
+    /// the very last ends with a repeated START (`mstctrl.restart`) rather
+    /// than a STOP, per the `embedded-hal` contract that `transaction` only
+    /// releases the bus after its final operation.
     fn transaction(
         &mut self,
         address: SevenBitAddress,
         operations: &mut [Operation<'_>],
     ) -> Result<(), Self::Error> {
-        for operation in operations.iter_mut() {
-            match operation {
-                Operation::Read(read) => {
-                    self.read(address, read)?;
+        let old_addr = self.get_target_addr();
+        self.set_target_addr(address);
+
+        let mut i = 0;
+        while i < operations.len() {
+            let mut j = i + 1;
+            while j < operations.len()
+                && core::mem::discriminant(&operations[i]) == core::mem::discriminant(&operations[j])
+            {
+                j += 1;
+            }
+            let is_last_run = j == operations.len();
+
+            match &operations[i] {
+                Operation::Write(_) => {
+                    let mut chained = operations[i..j].iter().flat_map(|op| {
+                        let Operation::Write(w) = op else {
+                            unreachable!("grouped by discriminant")
+                        };
+                        w.iter().copied()
+                    });
+                    self.send_raw(
+                        &mut chained,
+                        if is_last_run {
+                            Terminate::Stop
+                        } else {
+                            Terminate::Restart
+                        },
+                    )?;
                 }
-                Operation::Write(write) => {
-                    self.write(address, write)?;
+                Operation::Read(_) => {
+                    let run_len = j - i;
+                    for (k, op) in operations[i..j].iter_mut().enumerate() {
+                        let Operation::Read(read) = op else {
+                            unreachable!("grouped by discriminant")
+                        };
+                        let terminate = if is_last_run && k + 1 == run_len {
+                            Terminate::Stop
+                        } else {
+                            Terminate::Restart
+                        };
+
+                        let bytes_to_read = read.len();
+                        for c in 0..bytes_to_read / 256 {
+                            self.recv_raw(
+                                &mut read[c * 256..],
+                                &mut InfTimeout::new(),
+                                false,
+                                256,
+                                Terminate::Restart,
+                            )?;
+                        }
+                        let leftover = bytes_to_read - (bytes_to_read % 256);
+                        let leftover_len = bytes_to_read % 256;
+                        self.recv_raw(
+                            &mut read[leftover..],
+                            &mut InfTimeout::new(),
+                            false,
+                            leftover_len,
+                            terminate,
+                        )?;
+                    }
                 }
             }
+
+            i = j;
+        }
+
+        self.set_target_addr(old_addr);
+        Ok(())
+    }
+}
+
+/// 10-bit addressing support, emitting the two-byte `11110xx0` address
+/// header described by the I2C specification instead of the single
+/// 7-bit address byte used by the [`SevenBitAddress`] impl above.
+impl<T: GCRI2C> embedded_hal::i2c::I2c<TenBitAddress> for I2CMaster<'_, T> {
+    fn read(&mut self, address: TenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        for chunk in read.chunks_mut(256) {
+            self.recv_raw_ten_bit(address, chunk)?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, address: TenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        self.send_raw_ten_bit(address, &mut write.iter().copied())
+    }
+
+    fn write_read(
+        &mut self,
+        address: TenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.write(address, write)?;
+        self.read(address, read)
+    }
+
+    fn transaction(
+        &mut self,
+        address: TenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations.iter_mut() {
+            match operation {
+                Operation::Read(read) => self.read(address, read)?,
+                Operation::Write(write) => self.write(address, write)?,
+            }
         }
         Ok(())
     }
@@ -1,38 +1,93 @@
+//! Target (slave) mode, the sibling of [`super::master::I2CMaster`]'s
+//! controller mode.
+//!
+//! [`I2CSlave::new`] configures `mst_mode().bit(false)` and programs the
+//! local address (7-bit or 10-bit, with optional general-call matching via
+//! `gc_addr_en`) into the slave-address register instead of driving the bus
+//! as a controller. [`I2CSlave::slave_poll`] is the blocking "wait for a
+//! controller to address us" primitive, returning a [`SlavePollResult`] that
+//! tells the caller whether the controller wants to write to us or read
+//! from us; [`I2CSlave::recv_raw`]/[`I2CSlave::send_raw`] then drain/fill
+//! the FIFO for that transaction. [`I2cTarget::listen`] bundles all
+//! three into a single blocking call shaped like embassy/rp-hal's
+//! target-mode traits, for callers who don't need to act on the
+//! read-vs-write distinction before committing to a buffer.
 use crate::communication::Timeout;
+use crate::peripherals::dma::DmaChannel;
 use crate::peripherals::gpio::active::port_num_types::GpioZero;
 use crate::peripherals::gpio::active::ActivePinHandle;
-use crate::peripherals::gpio::pin_traits::IoPin;
+use crate::peripherals::gpio::pin_traits::{GeneralIoPin, IoPin};
 use crate::peripherals::gpio::{GpioError, PinOperatingMode};
-use crate::peripherals::i2c::{BusSpeed, I2CSlave, SlavePollResult, GCRI2C};
+use crate::peripherals::i2c::{
+    compute_timings, BusSpeed, FifoEmptyMode, I2CSlave, I2cClockTiming, I2cError, SlaveAddress,
+    SlavePollResult, GCRI2C,
+};
 use crate::peripherals::oscillator::SystemClock;
 use core::cell::{Ref, RefMut};
-use embedded_hal::i2c::{ErrorKind, SevenBitAddress};
+use embedded_hal::i2c::ErrorType;
+
+/// Failure constructing an [`I2CSlave`]: either the requested address was
+/// rejected before any bus activity started, or the SCL/SDA pins could not
+/// be switched into the I2C alternate function.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum I2CSlaveNewError {
+    /// The requested address is reserved or out of range; see [`I2cError`].
+    Address(I2cError),
+    /// The SCL or SDA pin handle could not be reconfigured.
+    Gpio(GpioError),
+}
+
+impl From<I2cError> for I2CSlaveNewError {
+    fn from(err: I2cError) -> Self {
+        I2CSlaveNewError::Address(err)
+    }
+}
+
+impl From<GpioError> for I2CSlaveNewError {
+    fn from(err: GpioError) -> Self {
+        I2CSlaveNewError::Gpio(err)
+    }
+}
 
 impl<'a, T: GCRI2C> I2CSlave<'a, T> {
-    /// Creates a new instance of an I2C slave
+    /// Creates a new instance of an I2C slave.
+    ///
+    /// `address` may be a 7-bit or 10-bit address; if `respond_to_general_call`
+    /// is set, the slave additionally ACKs the reserved general-call address
+    /// (`0x00`), which is then reported back through
+    /// [`SlavePollResult::IncomingTransmission`]'s `general_call` flag.
     pub(crate) fn new(
-        address: SevenBitAddress,
+        address: SlaveAddress,
+        respond_to_general_call: bool,
+        fifo_empty_mode: FifoEmptyMode,
         bus_speed: BusSpeed,
         system_clock: Ref<SystemClock>,
         i2c_regs: RefMut<'a, T>,
         mut scl_pin: ActivePinHandle<'a, GpioZero, 31>,
         mut sda_pin: ActivePinHandle<'a, GpioZero, 31>,
-    ) -> Result<Self, GpioError> {
+    ) -> Result<Self, I2CSlaveNewError> {
+        address.validate()?;
+
         scl_pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
         sda_pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
         i2c_regs.ctrl().modify(|_, w| w.en().bit(true));
 
+        let ten_bit = matches!(address, SlaveAddress::TenBit(_));
+
         i2c_regs.ctrl().modify(|_, w| {
             w.mst_mode()
                 .bit(false)
                 .gc_addr_en()
-                .bit(false)
+                .bit(respond_to_general_call)
                 .irxm_en()
                 .bit(false)
                 .clkstr_dis()
-                .bit(false)
+                .bit(fifo_empty_mode.clkstr_dis())
                 .hs_en()
                 .bit(false)
+                // MAX78000 I2C slave address-format select: 0 = 7-bit, 1 = 10-bit
+                .format()
+                .bit(ten_bit)
         });
 
         i2c_regs.rxctrl0().modify(|_, w| {
@@ -53,25 +108,36 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         });
 
         // Configure clock speed values
-        let target_speed = match bus_speed {
-            BusSpeed::Standard100kbps => 100_000,
-            BusSpeed::Fast400kbps => 400_000,
-            BusSpeed::FastPlus1mbps => 1_000_000,
-        };
+        let pclk_speed = (system_clock.get_freq() / u32::from(system_clock.get_div()) / 2).to_hz();
 
-        // Calculations copied from the msdk
+        let fs_timings = compute_timings(
+            pclk_speed,
+            bus_speed.clkhi_clklo_target_hz(),
+            bus_speed.rise_time_ns(),
+            bus_speed.fast_mode_duty(),
+        );
+        i2c_regs.clkhi().write(|w| w.hi().variant(fs_timings.hi));
+        i2c_regs.clklo().write(|w| w.lo().variant(fs_timings.lo));
 
-        let pclk_speed = system_clock.get_freq() / (system_clock.get_div() as u32) / 2;
-
-        let multiplier = pclk_speed / target_speed;
-        let val = multiplier / 2 - 1;
-
-        i2c_regs.clkhi().write(|w| w.hi().variant(val as u16));
-        i2c_regs.clklo().write(|w| w.lo().variant(val as u16));
+        if let Some(hs_target_hz) = bus_speed.hsclk_target_hz() {
+            let hs_timings =
+                compute_timings(pclk_speed, hs_target_hz, bus_speed.rise_time_ns(), true);
+            i2c_regs.hsclk().write(|w| {
+                w.hsclk_hi()
+                    .variant(hs_timings.hi)
+                    .hsclk_lo()
+                    .variant(hs_timings.lo)
+            });
+            i2c_regs.ctrl().modify(|_, w| w.hs_en().bit(true));
+        }
 
+        let addr_bits = match address {
+            SlaveAddress::SevenBit(addr) => addr as u16,
+            SlaveAddress::TenBit(addr) => addr,
+        };
         i2c_regs
             .slave_multi(0)
-            .write(|w| w.addr().variant(address as u16));
+            .write(|w| w.addr().variant(addr_bits));
 
         i2c_regs.ctrl().modify(|_, w| w.en().bit(true));
 
@@ -79,14 +145,31 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
             i2c_regs,
             scl_pin,
             sda_pin,
+            dma_channel: None,
         })
     }
 
+    /// Equips this slave with a DMA channel, switching
+    /// [`Self::recv_raw_dma`]/[`Self::send_raw_dma`] -- and the
+    /// `CommStackRx`/`FramedTxChannel` impls in
+    /// [`crate::peripherals::i2c::comm`] -- from CPU-polled FIFO access to
+    /// DMA-driven transfers.
+    pub fn with_dma(mut self, channel: DmaChannel<'a>) -> Self {
+        self.dma_channel = Some(channel);
+        self
+    }
+
+    /// Reads back the SCL high/low timing actually programmed for the
+    /// [`BusSpeed`] this slave was constructed with.
+    pub fn clock_timing(&self) -> I2cClockTiming {
+        I2cClockTiming {
+            hi: self.i2c_regs.clkhi().read().hi().bits(),
+            lo: self.i2c_regs.clklo().read().lo().bits(),
+        }
+    }
+
     /// Poll for either a master read or write operation. Optional timeout
-    pub fn slave_poll<TMT: Timeout>(
-        &mut self,
-        tmt: &mut TMT,
-    ) -> Result<SlavePollResult, ErrorKind> {
+    pub fn slave_poll<TMT: Timeout>(&mut self, tmt: &mut TMT) -> Result<SlavePollResult, I2cError> {
         self.i2c_regs.clear_interrupt_flags();
         self.i2c_regs.flush_rx_fifo();
         // Wait for I2Cn_INTFL0.addr_match = 1
@@ -94,11 +177,12 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
 
         while !self.i2c_regs.intfl0().read().addr_match().bit() && !tmt.poll() {}
         if tmt.poll() {
-            return Err(ErrorKind::Bus);
+            return Err(I2cError::Timeout);
         }
 
         if !self.i2c_regs.ctrl().read().read().bit() {
-            return Ok(SlavePollResult::IncomingTransmission);
+            let general_call = self.i2c_regs.intfl0().read().gc_addr_match().bit();
+            return Ok(SlavePollResult::IncomingTransmission { general_call });
         }
 
         Ok(SlavePollResult::TransmitNeeded)
@@ -110,7 +194,7 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         buffer: &mut [u8],
         tmt: &mut TMT,
         rst_on_byte: bool,
-    ) -> Result<(u32, bool), ErrorKind> {
+    ) -> Result<(u32, bool), I2cError> {
         let mut num_read = 0;
         let capacity = buffer.len();
 
@@ -121,11 +205,11 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         // read to fill read buffer
         while num_read < capacity {
             if self.i2c_regs.bus_error() || tmt.poll() {
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
             }
             while !self.i2c_regs.is_rx_fifo_empty() {
                 if self.i2c_regs.bus_error() || tmt.poll() {
-                    return Err(ErrorKind::Bus);
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
                 }
                 if num_read < capacity {
                     buffer[num_read] = self.i2c_regs.fifo().read().data().bits();
@@ -145,11 +229,11 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         // discard remaining bytes that we can't put in the read buffer
         while !self.i2c_regs.intfl0().read().done().bit() {
             if self.i2c_regs.bus_error() || tmt.poll() {
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
             }
             while !self.i2c_regs.is_rx_fifo_empty() {
                 if self.i2c_regs.bus_error() || tmt.poll() {
-                    return Err(ErrorKind::Bus);
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
                 }
                 self.i2c_regs.fifo().read().data().bits();
                 num_read += 1;
@@ -165,9 +249,109 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         Ok((num_read as u32, was_it_truncated))
     }
 
+    /// DMA-driven equivalent of [`Self::recv_raw`]: the payload is drained
+    /// from the receive FIFO into `buffer` by the [`DmaChannel`] passed to
+    /// [`Self::with_dma`] instead of the CPU polling loop. Unlike the master
+    /// side, the slave doesn't choose how many bytes a transaction carries,
+    /// so whichever of `buffer` filling or the transaction's `done` flag
+    /// happens first ends the DMA phase; if `buffer` fills first, any
+    /// further bytes are discarded the same way [`Self::recv_raw`] discards
+    /// an overflow. Returns [`I2cError::Other`] if no channel has been
+    /// configured.
+    pub fn recv_raw_dma<TMT: Timeout>(
+        &mut self,
+        buffer: &mut [u8],
+        tmt: &mut TMT,
+    ) -> Result<(u32, bool), I2cError> {
+        if self.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        self.i2c_regs
+            .intfl0()
+            .modify(|_, w| w.addr_match().bit(true));
+
+        let fifo_addr = self.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.i2c_regs.dma_rx_request();
+        self.i2c_regs.set_dma_enabled(true, false);
+
+        // Guarded by the `dma_channel.is_none()` check above.
+        let channel = self.dma_channel.as_mut().expect("checked for Some above");
+        // SAFETY: `buffer` outlives the transfer, which we wait on below
+        // before touching it again; `fifo_addr` is a fixed peripheral
+        // register so it doesn't need to stay valid for `buffer.len()`
+        // bytes, just the one address.
+        unsafe {
+            channel.start(
+                fifo_addr,
+                false,
+                buffer.as_mut_ptr(),
+                true,
+                buffer.len(),
+                request,
+            );
+        }
+
+        let was_it_truncated = loop {
+            if self.i2c_regs.bus_error() || tmt.poll() {
+                self.i2c_regs.set_dma_enabled(false, false);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
+            }
+            if self.i2c_regs.intfl0().read().done().bit() {
+                break false;
+            }
+            match channel.is_done() {
+                Ok(true) => break true,
+                Ok(false) => {}
+                Err(_) => {
+                    self.i2c_regs.set_dma_enabled(false, false);
+                    return Err(I2cError::Other(0));
+                }
+            }
+        };
+
+        self.i2c_regs.set_dma_enabled(false, false);
+        let num_read = buffer.len() as u32 - channel.bytes_remaining();
+
+        // Buffer filled before the master was done; discard the remainder
+        // the same way `recv_raw` does, so the FIFO doesn't back up.
+        while was_it_truncated && !self.i2c_regs.intfl0().read().done().bit() {
+            if self.i2c_regs.bus_error() || tmt.poll() {
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
+            }
+            while !self.i2c_regs.is_rx_fifo_empty() {
+                if self.i2c_regs.bus_error() || tmt.poll() {
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Timeout));
+                }
+                self.i2c_regs.fifo().read().data().bits();
+            }
+        }
+
+        self.i2c_regs.intfl0().modify(|_, w| w.done().bit(true));
+        self.i2c_regs.ctrl().modify(|_, w| w.en().bit(false));
+
+        Ok((num_read, was_it_truncated))
+    }
+
+    /// Receives via [`Self::recv_raw_dma`] if a DMA channel has been
+    /// configured ([`Self::with_dma`]), falling back to the CPU-polled
+    /// [`Self::recv_raw`] otherwise.
+    pub(crate) fn recv_raw_auto<TMT: Timeout>(
+        &mut self,
+        buffer: &mut [u8],
+        tmt: &mut TMT,
+        rst_on_byte: bool,
+    ) -> Result<(u32, bool), I2cError> {
+        if self.dma_channel.is_some() {
+            self.recv_raw_dma(buffer, tmt)
+        } else {
+            self.recv_raw(buffer, tmt, rst_on_byte)
+        }
+    }
+
     /// Respond to master on i2c buf using buffer as the message to send
     /// sends a chain of 0s if bus exceeded but master still wants more
-    pub fn send_raw<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<u32, ErrorKind> {
+    pub fn send_raw<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<u32, I2cError> {
         // With I2Cn_CTRL.en = 0, initialize all relevant registers, including specifically for this mode I2Cn_CTRL. clkstr_dis = 0,
         // I2Cn_TXCTRL0[5:2] = 0x8 and I2Cn_TXCTRL0.preload_mode = 0. Don't forget to program I2Cn_CLKHI.hi and
         // I2Cn_HSCLK.hsclk_hi with appropriate values satisfying tSU;DAT (and HS tSU;DAT).
@@ -184,11 +368,11 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         let mut done = false;
         while !self.i2c_regs.intfl0().read().done().bit() && !done {
             if self.i2c_regs.bus_error() {
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
             }
             while !self.i2c_regs.is_tx_fifo_full() && !self.i2c_regs.intfl0().read().done().bit() {
                 if self.i2c_regs.bus_error() {
-                    return Err(ErrorKind::Bus);
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
                 }
                 // important: we must only pull out of the iterator if we know the master needs it
                 if num_written >= 256 {
@@ -208,11 +392,11 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
         // write zeros if we've exceeded buffer but master still wants more
         while !self.i2c_regs.intfl0().read().done().bit() {
             if self.i2c_regs.bus_error() {
-                return Err(ErrorKind::Bus);
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
             }
             while !self.i2c_regs.is_tx_fifo_full() && !self.i2c_regs.intfl0().read().done().bit() {
                 if self.i2c_regs.bus_error() {
-                    return Err(ErrorKind::Bus);
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
                 }
                 self.i2c_regs.fifo().write(|w| w.data().variant(0));
             }
@@ -225,4 +409,176 @@ impl<'a, T: GCRI2C> I2CSlave<'a, T> {
 
         Ok(num_written as u32)
     }
+
+    /// DMA-driven equivalent of [`Self::send_raw`]: drains `buffer` through a
+    /// fixed-size scratch array one chunk at a time, pushing each chunk out
+    /// through the [`DmaChannel`] passed to [`Self::with_dma`] instead of the
+    /// CPU polling loop, stopping early if `done` latches mid-chunk and
+    /// observing the same 256-byte transfer cap as [`Self::send_raw`].
+    /// Returns [`I2cError::Other`] if no channel has been configured.
+    pub fn send_raw_dma<I: Iterator<Item = u8>>(&mut self, buffer: &mut I) -> Result<u32, I2cError> {
+        if self.dma_channel.is_none() {
+            return Err(I2cError::Other(0));
+        }
+
+        self.i2c_regs
+            .intfl0()
+            .modify(|_, w| w.addr_match().bit(true));
+        self.i2c_regs
+            .intfl0()
+            .modify(|_, w| w.tx_lockout().variant(true));
+
+        let fifo_addr = self.i2c_regs.fifo().as_ptr() as *mut u8;
+        let request = self.i2c_regs.dma_tx_request();
+        let mut chunk = [0u8; 256];
+        let mut num_written = 0u32;
+
+        while !self.i2c_regs.intfl0().read().done().bit() && (num_written as usize) < 256 {
+            if self.i2c_regs.bus_error() {
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+
+            let mut chunk_len = 0;
+            while chunk_len < chunk.len() && (num_written as usize + chunk_len) < 256 {
+                match buffer.next() {
+                    Some(byte) => {
+                        chunk[chunk_len] = byte;
+                        chunk_len += 1;
+                    }
+                    None => break,
+                }
+            }
+            if chunk_len == 0 {
+                break;
+            }
+
+            self.i2c_regs.set_dma_enabled(false, true);
+            // Guarded by the `dma_channel.is_none()` check above this loop.
+            let channel = self.dma_channel.as_mut().expect("checked for Some above");
+            // SAFETY: `chunk` outlives the transfer, which we wait on below
+            // before reusing it; `fifo_addr` is a fixed peripheral register
+            // so it doesn't need to stay valid for `chunk_len` bytes, just
+            // the one address.
+            unsafe {
+                channel.start(chunk.as_ptr(), true, fifo_addr, false, chunk_len, request);
+            }
+            let dma_result = loop {
+                if self.i2c_regs.intfl0().read().done().bit() {
+                    break Ok(());
+                }
+                match channel.is_done() {
+                    Ok(true) => break Ok(()),
+                    Ok(false) => {}
+                    Err(_) => break Err(I2cError::Other(0)),
+                }
+            };
+            self.i2c_regs.set_dma_enabled(false, false);
+            dma_result?;
+
+            num_written += chunk_len as u32 - channel.bytes_remaining();
+
+            if self.i2c_regs.bus_error() {
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+        }
+
+        // write zeros if we've exceeded buffer but master still wants more
+        while !self.i2c_regs.intfl0().read().done().bit() {
+            if self.i2c_regs.bus_error() {
+                return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+            }
+            while !self.i2c_regs.is_tx_fifo_full() && !self.i2c_regs.intfl0().read().done().bit() {
+                if self.i2c_regs.bus_error() {
+                    return Err(self.i2c_regs.abort_reason().unwrap_or(I2cError::Other(0)));
+                }
+                self.i2c_regs.fifo().write(|w| w.data().variant(0));
+            }
+        }
+
+        self.i2c_regs.intfl0().modify(|_, w| w.done().bit(true));
+        self.i2c_regs.inten0().modify(|_, w| w.tx_thd().bit(true));
+        self.i2c_regs.ctrl().modify(|_, w| w.en().bit(false));
+
+        Ok(num_written)
+    }
+
+    /// Sends via [`Self::send_raw_dma`] if a DMA channel has been configured
+    /// ([`Self::with_dma`]), falling back to the CPU-polled [`Self::send_raw`]
+    /// otherwise.
+    pub(crate) fn send_raw_auto<I: Iterator<Item = u8>>(
+        &mut self,
+        buffer: &mut I,
+    ) -> Result<u32, I2cError> {
+        if self.dma_channel.is_some() {
+            self.send_raw_dma(buffer)
+        } else {
+            self.send_raw(buffer)
+        }
+    }
+}
+
+impl<T: GCRI2C> ErrorType for I2CSlave<'_, T> {
+    type Error = I2cError;
+}
+
+/// The outcome of one transaction serviced through [`I2cTarget::listen`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum I2cTargetTransaction {
+    /// The controller wrote `len` bytes to the buffer passed to `listen`.
+    /// `truncated` mirrors [`I2CSlave::recv_raw`]'s `was_it_truncated`: the
+    /// controller sent more than the buffer could hold.
+    Write {
+        /// Number of bytes the controller wrote, counting any that did not
+        /// fit; only the first `write_buf.len()` of them are in the buffer.
+        len: u32,
+        /// Whether the controller sent more bytes than the buffer could hold.
+        truncated: bool,
+    },
+    /// The controller read `len` bytes out of the buffer passed to `listen`.
+    Read {
+        /// Number of bytes actually sent to the controller.
+        len: u32,
+    },
+}
+
+/// Target-mode (slave) I2C transaction handling -- the counterpart to
+/// `embedded_hal::i2c::I2c` for the controller side that `embedded-hal`
+/// doesn't standardize yet (unlike its SPI `Operation`/`Device` split, there
+/// is currently no upstream target-mode I2C trait). This crate-local trait
+/// wraps [`I2CSlave::slave_poll`]/[`I2CSlave::recv_raw`]/[`I2CSlave::send_raw`]'s
+/// FIFO state machine behind a single blocking call, shaped the same way as
+/// the existing `embedded_hal::i2c::I2c` impl on [`super::master::I2CMaster`]
+/// so it's a drop-in swap if/when an upstream trait stabilizes.
+pub trait I2cTarget: ErrorType {
+    /// Waits for the controller to start a transaction addressed to us, then
+    /// services it: a write is drained into `write_buf`, a read is served
+    /// from `read_buf`. A read observes the same 256-byte transfer cap as
+    /// [`I2CSlave::send_raw`]; a write is bounded only by `write_buf`'s
+    /// length, as in [`I2CSlave::recv_raw`].
+    fn listen<TMT: Timeout>(
+        &mut self,
+        write_buf: &mut [u8],
+        read_buf: &[u8],
+        tmt: &mut TMT,
+    ) -> Result<I2cTargetTransaction, Self::Error>;
+}
+
+impl<T: GCRI2C> I2cTarget for I2CSlave<'_, T> {
+    fn listen<TMT: Timeout>(
+        &mut self,
+        write_buf: &mut [u8],
+        read_buf: &[u8],
+        tmt: &mut TMT,
+    ) -> Result<I2cTargetTransaction, Self::Error> {
+        match self.slave_poll(tmt)? {
+            SlavePollResult::IncomingTransmission { .. } => {
+                let (len, truncated) = self.recv_raw(write_buf, tmt, false)?;
+                Ok(I2cTargetTransaction::Write { len, truncated })
+            }
+            SlavePollResult::TransmitNeeded => {
+                let len = self.send_raw(&mut read_buf.iter().copied())?;
+                Ok(I2cTargetTransaction::Read { len })
+            }
+        }
+    }
 }
@@ -0,0 +1,171 @@
+//! Interrupt-driven (non-async) [`I2CSlave`] reception.
+//!
+//! `I2CSlave::slave_poll` busy-spins on `INTFL0.addr_match`, so the
+//! `CommStackRx`/`FramedTxChannel` impls built on it in
+//! [`crate::peripherals::i2c::comm`] burn cycles the entire time they're
+//! waiting for a master to start a transaction. This module enables the
+//! address-match/RX-threshold/`done` interrupts and has [`on_interrupt`]
+//! stash the poll result into a per-instance slot guarded by a
+//! `critical_section::Mutex`, so [`InterruptI2CSlave::wait_for_poll`] can
+//! park the core with `wfi()` between interrupts instead of spinning.
+//! Callers are responsible for routing the I2Cn interrupt to
+//! [`on_interrupt`] from their `#[interrupt]` handler.
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+
+use crate::communication::{CommunicationError, RxChannel, Timeout};
+use crate::peripherals::i2c::asynch::I2CInstance;
+use crate::peripherals::i2c::{I2CSlave, SlavePollResult, GCRI2C};
+
+/// Number of I2C instances (I2C0, I2C1, I2C2) that need a poll-result slot.
+const NUM_I2C_INSTANCES: usize = 3;
+
+/// Slot [`on_interrupt`] (the producer) fills in and
+/// [`InterruptI2CSlave::wait_for_poll`] (the consumer) drains, one per I2C
+/// instance.
+static SLAVE_POLL_RESULT: [Mutex<RefCell<Option<SlavePollResult>>>; NUM_I2C_INSTANCES] =
+    [const { Mutex::new(RefCell::new(None)) }; NUM_I2C_INSTANCES];
+
+fn enable_poll_interrupts<T: GCRI2C>(i2c_regs: &T) {
+    i2c_regs
+        .inten0()
+        .modify(|_, w| w.addr_match().bit(true).rx_thd().bit(true).done().bit(true));
+}
+
+fn disable_poll_interrupts<T: GCRI2C>(i2c_regs: &T) {
+    i2c_regs
+        .inten0()
+        .modify(|_, w| w.addr_match().bit(false).rx_thd().bit(false).done().bit(false));
+}
+
+/// Call this from the `I2Cn` NVIC handler. Masks the address-match/RX-
+/// threshold/`done` interrupts back off ([`InterruptI2CSlave::wait_for_poll`]
+/// re-arms them before its next `wfi()`) and, if this wakeup was for a new
+/// address match, decodes it into a [`SlavePollResult`] and stashes it in
+/// `instance`'s slot.
+pub fn on_interrupt<T: GCRI2C>(instance: I2CInstance, i2c_regs: &T) {
+    disable_poll_interrupts(i2c_regs);
+
+    if i2c_regs.intfl0().read().addr_match().bit() {
+        let result = if i2c_regs.ctrl().read().read().bit() {
+            SlavePollResult::TransmitNeeded
+        } else {
+            SlavePollResult::IncomingTransmission {
+                general_call: i2c_regs.intfl0().read().gc_addr_match().bit(),
+            }
+        };
+
+        critical_section::with(|cs| {
+            SLAVE_POLL_RESULT[instance as usize]
+                .borrow_ref_mut(cs)
+                .replace(result);
+        });
+    }
+}
+
+/// Interrupt-driven extension for [`I2CSlave`]. Implements [`RxChannel`]
+/// itself, parking on [`Self::wait_for_poll`] instead of [`I2CSlave`]'s
+/// busy-spinning `slave_poll`.
+pub struct InterruptI2CSlave<'a, T: GCRI2C> {
+    inner: I2CSlave<'a, T>,
+    instance: I2CInstance,
+}
+
+impl<'a, T: GCRI2C> InterruptI2CSlave<'a, T> {
+    /// Wraps an existing blocking [`I2CSlave`] to replace its busy-spun
+    /// `slave_poll` with an interrupt-driven, `wfi`-parked wait.
+    pub fn new(inner: I2CSlave<'a, T>, instance: I2CInstance) -> Self {
+        Self { inner, instance }
+    }
+
+    /// Parks the core (`wfi`) until the next master transaction starts,
+    /// instead of busy-spinning the way [`I2CSlave::slave_poll`] does.
+    /// Clears any stale result left over from before this call, then loops
+    /// re-arming the poll interrupts and sleeping until [`on_interrupt`]
+    /// fills the slot back in.
+    pub fn wait_for_poll(&mut self) -> SlavePollResult {
+        critical_section::with(|cs| {
+            *SLAVE_POLL_RESULT[self.instance as usize].borrow_ref_mut(cs) = None;
+        });
+
+        loop {
+            enable_poll_interrupts(&*self.inner.i2c_regs);
+            cortex_m::asm::wfi();
+
+            let result = critical_section::with(|cs| {
+                SLAVE_POLL_RESULT[self.instance as usize]
+                    .borrow_ref_mut(cs)
+                    .take()
+            });
+            if let Some(result) = result {
+                return result;
+            }
+        }
+    }
+
+    fn rx_channel_recv<TMR: Timeout>(
+        &mut self,
+        dest: &mut [u8],
+        tmr: &mut TMR,
+        rst_on_data: bool,
+    ) -> crate::communication::Result<usize> {
+        if let SlavePollResult::IncomingTransmission { .. } = self.wait_for_poll() {
+            tmr.reset();
+            let mut bytes_sent_buf = [0u8; 4];
+            if let Ok((n, _)) = self.inner.recv_raw(&mut bytes_sent_buf, tmr, rst_on_data) {
+                if n != 4 {
+                    return Err(CommunicationError::RecvError(0));
+                }
+                let expected_to_recv = u32::from_le_bytes(bytes_sent_buf);
+                return if let SlavePollResult::IncomingTransmission { .. } = self.wait_for_poll() {
+                    let (n, _) = self
+                        .inner
+                        .recv_raw(dest, tmr, true)
+                        .map_err(|_| CommunicationError::RecvError(0))?;
+                    if n != expected_to_recv {
+                        return Err(CommunicationError::RecvError(n as usize));
+                    }
+                    Ok(n as usize)
+                } else {
+                    Err(CommunicationError::RecvError(0))
+                };
+            }
+        }
+        Err(CommunicationError::RecvError(0))
+    }
+
+    /// Disables the poll interrupts and hands back the underlying
+    /// [`I2CSlave`].
+    pub fn release(self) -> I2CSlave<'a, T> {
+        disable_poll_interrupts(&*self.inner.i2c_regs);
+        self.inner
+    }
+}
+
+impl<T: GCRI2C> RxChannel for InterruptI2CSlave<'_, T> {
+    fn recv_with_data_timeout<R: Timeout>(
+        &mut self,
+        dest: &mut [u8],
+        tmr: &mut R,
+    ) -> crate::communication::Result<usize> {
+        self.rx_channel_recv(dest, tmr, true)
+    }
+
+    fn recv_with_timeout<R: Timeout>(
+        &mut self,
+        dest: &mut [u8],
+        tmr: &mut R,
+    ) -> crate::communication::Result<usize> {
+        self.rx_channel_recv(dest, tmr, false)
+    }
+
+    fn try_recv(&mut self, _dest: &mut [u8]) -> crate::communication::Result<usize> {
+        // Unlike UART's byte-stream FIFO, an I2C slave transaction only
+        // exists once the master actively initiates one, so there's never
+        // anything "immediately available" to drain without blocking on
+        // the bus.
+        Ok(0)
+    }
+}
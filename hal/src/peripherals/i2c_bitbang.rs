@@ -1,6 +1,8 @@
 //! uh oh
 
-use crate::peripherals::i2c::SlavePollResult::{Received, TransmitNeeded};
+/// Async, interrupt-driven wrapper around [`I2CMaster`]'s hardware-FIFO mode.
+pub mod asynch;
+
 use core::ops::Deref;
 use cortex_m::asm::delay;
 use embedded_hal;
@@ -96,14 +98,46 @@ pub enum SlavePollResult {
     TransmitNeeded,
 }
 
-pub struct I2CMaster<'a, T: Deref<Target=i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target=tmr::RegisterBlock> + TimerPeripheralGCR> {
+use SlavePollResult::{Received, TransmitNeeded};
+
+/// Selects whether an [`I2CMaster`] drives SCL/SDA itself one bit at a time
+/// or hands the transaction off to the peripheral's native master state
+/// machine and FIFOs.
+enum I2CMasterMode {
+    /// [`I2CMaster::new`]: manual bit-banging via [`BBGCRI2C`].
+    BitBang,
+    /// [`I2CMaster::new_hw_fifo`]: the controller's own FIFO/state machine.
+    HwFifo,
+}
+
+pub struct I2CMaster<'a, 'b, T: Deref<Target=i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target=tmr::RegisterBlock> + TimerPeripheralGCR> {
     i2c_regs: T,
-    timer: Timer<'a, R>,
-    started: bool
+    timer: Timer<'a, 'b, R>,
+    started: bool,
+    mode: I2CMasterMode,
 }
 
+/// Number of bytes an [`I2CSlave`] can buffer on either side of a
+/// transaction: bytes a master writes to it before the next [`I2CSlave::poll`]
+/// call drains them, and bytes queued by [`I2CSlave::slave_send`] before the
+/// master reads them.
+const SLAVE_BUF_LEN: usize = 32;
+
 pub struct I2CSlave<T: Deref<Target=i2c0::RegisterBlock> + BBGCRI2C> {
-    i2c_regs: T
+    i2c_regs: T,
+    address: u8,
+    rx_buf: [u8; SLAVE_BUF_LEN],
+    rx_len: usize,
+    tx_buf: [u8; SLAVE_BUF_LEN],
+    tx_len: usize,
+    /// Set when `poll` returned [`SlavePollResult::TransmitNeeded`] and is
+    /// now stretching the clock until `slave_send` has had a chance to fill
+    /// `tx_buf`.
+    awaiting_transmit: bool,
+    /// Whether SCL is currently being held low (clock stretch) to give the
+    /// caller time to act on the last `poll` result; released at the start
+    /// of the next call.
+    stretching: bool,
 }
 
 impl<T: Deref<Target=i2c0::RegisterBlock> + BBGCRI2C> I2CSlave<T> {
@@ -119,18 +153,213 @@ impl<T: Deref<Target=i2c0::RegisterBlock> + BBGCRI2C> I2CSlave<T> {
                 .sda_out().bit(true)
         });
 
-        Self {i2c_regs}
+        Self {
+            i2c_regs,
+            address,
+            rx_buf: [0; SLAVE_BUF_LEN],
+            rx_len: 0,
+            tx_buf: [0; SLAVE_BUF_LEN],
+            tx_len: 0,
+            awaiting_transmit: false,
+            stretching: false,
+        }
+    }
+
+    fn delay(&mut self) {
+        delay(100);
+    }
+
+    /// Releases a clock stretch left over from the previous `poll` call, if
+    /// any, letting the master resume clocking.
+    fn release_stretch(&mut self) {
+        if self.stretching {
+            self.i2c_regs.set_scl();
+            self.stretching = false;
+        }
+    }
+
+    /// Holds SCL low until the next `poll` call, so the master waits while
+    /// the application consumes `Received` data or supplies `slave_send`
+    /// data in response to `TransmitNeeded`.
+    fn stretch(&mut self) {
+        self.i2c_regs.clear_scl();
+        self.stretching = true;
+    }
+
+    /// Samples one bit the master is driving onto SDA: waits for SCL to
+    /// rise, reads SDA, then waits for SCL to fall again before returning.
+    fn sample_bit(&mut self) -> bool {
+        while !self.i2c_regs.read_scl() {}
+        let bit = self.i2c_regs.read_sda();
+        while self.i2c_regs.read_scl() {}
+        bit
+    }
+
+    /// Like [`Self::sample_bit`], but for the first bit of a byte that
+    /// follows an ACK, where the master may instead be issuing a STOP
+    /// condition (SDA rising while SCL stays high) rather than continuing
+    /// the transaction. Returns `None` in that case.
+    fn first_bit_or_stop(&mut self) -> Option<bool> {
+        while !self.i2c_regs.read_scl() {}
+        let initial = self.i2c_regs.read_sda();
+        if initial {
+            // A '1' bit is driven high before SCL rises and never changes
+            // mid-pulse in this protocol, so a high initial sample can only
+            // be a data bit; just wait out the clock pulse.
+            while self.i2c_regs.read_scl() {}
+        } else {
+            // A '0' bit stays low for the whole pulse; a STOP condition
+            // instead rises back to high while SCL is still high. Watch for
+            // that to tell the two apart.
+            while self.i2c_regs.read_scl() {
+                if self.i2c_regs.read_sda() {
+                    return None;
+                }
+            }
+        }
+        Some(initial)
+    }
+
+    /// Drives one bit onto SDA for the master to sample: sets SDA while SCL
+    /// is low, waits out the clock pulse the master generates, then
+    /// releases SDA.
+    fn drive_bit(&mut self, bit: bool) {
+        if bit {
+            self.i2c_regs.set_sda();
+        } else {
+            self.i2c_regs.clear_sda();
+        }
+        self.delay();
+        while !self.i2c_regs.read_scl() {}
+        while self.i2c_regs.read_scl() {}
+        self.i2c_regs.set_sda();
+    }
+
+    /// Shifts in a byte the master is writing, least-significant bit first
+    /// (matching [`I2CMaster::write_byte`]'s bit order).
+    fn recv_byte(&mut self) -> u8 {
+        let mut byte = 0u8;
+        for i in 0..8 {
+            if self.sample_bit() {
+                byte |= 1 << i;
+            }
+        }
+        byte
+    }
+
+    /// Drives a byte the master is reading, most-significant bit first
+    /// (matching [`I2CMaster::read_byte`]'s assembly order), then samples
+    /// the (N)ACK bit the master drives back. Returns `true` on NACK.
+    fn send_byte(&mut self, byte: u8) -> bool {
+        for i in (0..8).rev() {
+            self.drive_bit(byte & (1 << i) != 0);
+        }
+        self.sample_bit()
     }
 
-    pub fn poll(&mut self) {
+    /// Blocks until a START condition -- SDA falling while SCL is high -- is
+    /// observed on an otherwise-idle bus.
+    fn wait_for_start(&mut self) {
+        loop {
+            while !(self.i2c_regs.read_sda() && self.i2c_regs.read_scl()) {}
+            while self.i2c_regs.read_scl() {
+                if !self.i2c_regs.read_sda() {
+                    return;
+                }
+            }
+        }
+    }
 
+    /// Returns the bytes most recently buffered by a [`SlavePollResult::Received`].
+    pub fn received_data(&self) -> &[u8] {
+        &self.rx_buf[..self.rx_len]
+    }
+
+    /// Supplies the bytes to transmit in response to the
+    /// [`SlavePollResult::TransmitNeeded`] `poll` just returned. Extra bytes
+    /// past [`SLAVE_BUF_LEN`] are dropped.
+    pub fn slave_send(&mut self, data: &[u8]) {
+        let n = data.len().min(self.tx_buf.len());
+        self.tx_buf[..n].copy_from_slice(&data[..n]);
+        self.tx_len = n;
+    }
+
+    /// Waits for the next I2C transaction addressed to this slave and either
+    /// buffers the bytes the master writes (returning
+    /// [`SlavePollResult::Received`]) or signals that the master wants to
+    /// read (returning [`SlavePollResult::TransmitNeeded`], to be answered
+    /// with [`Self::slave_send`] before the next call). Clock-stretches
+    /// (holds SCL low) between the two, so the master waits while the
+    /// application does either.
+    pub fn poll(&mut self) -> SlavePollResult {
+        if self.awaiting_transmit {
+            self.awaiting_transmit = false;
+            self.release_stretch();
+
+            for i in 0..self.tx_len {
+                if self.send_byte(self.tx_buf[i]) {
+                    break;
+                }
+            }
+            self.tx_len = 0;
+        } else {
+            self.release_stretch();
+        }
+
+        self.rx_len = 0;
+
+        loop {
+            self.wait_for_start();
+
+            let addr_byte = self.recv_byte();
+            let read = addr_byte & 1 != 0;
+            let addr = addr_byte >> 1;
+
+            if addr != self.address {
+                // Not for us: leave SDA released (NACK) and wait for the
+                // next START.
+                continue;
+            }
+
+            self.drive_bit(false); // ACK the address.
+
+            if read {
+                self.awaiting_transmit = true;
+                self.stretch();
+                return TransmitNeeded;
+            }
+
+            let mut overflow = false;
+            loop {
+                let Some(first_bit) = self.first_bit_or_stop() else {
+                    break;
+                };
+
+                let mut byte = first_bit as u8;
+                for i in 1..8 {
+                    byte |= (self.sample_bit() as u8) << i;
+                }
+
+                if self.rx_len < self.rx_buf.len() {
+                    self.rx_buf[self.rx_len] = byte;
+                    self.rx_len += 1;
+                } else {
+                    overflow = true;
+                }
+
+                self.drive_bit(false); // ACK the data byte.
+            }
+
+            self.stretch();
+            return Received(self.rx_len as u32, overflow);
+        }
     }
 }
 
 // TODO: write code to initialize relevant registers for both master and slave operation
 
-impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> I2CMaster<'a, T, R> {
-    pub fn new(gcr_regs: &GCR, i2c_regs: T, timer: Timer<'a, R>) -> Self {
+impl<'a, 'b, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> I2CMaster<'a, 'b, T, R> {
+    pub fn new(gcr_regs: &GCR, i2c_regs: T, timer: Timer<'a, 'b, R>) -> Self {
         T::reset_peripheral(gcr_regs);
         T::peripheral_clock_enable(gcr_regs);
 
@@ -142,12 +371,17 @@ impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Tar
                 .sda_out().bit(true)
         });
 
-        Self { i2c_regs, timer, started: false }
+        Self {
+            i2c_regs,
+            timer,
+            started: false,
+            mode: I2CMasterMode::BitBang,
+        }
     }
 
+    /// Busy-waits for one timer period, used as the per-bit setup/hold delay
+    /// between driving a line and sampling or toggling the clock.
     fn delay(&mut self) {
-        delay(100);
-        return;
         self.timer.reset();
         while !self.timer.poll() {}
     }
@@ -214,13 +448,43 @@ impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Tar
         Ok(())
     }
 
+    /// Waits for a slave holding SCL low (clock stretching) to release it,
+    /// bounded by the held timer so a slave that jams the clock low forever
+    /// can't hang the caller.
     fn clock_stretch(&mut self) -> Result<(), ErrorKind> {
+        self.timer.reset();
         while !self.i2c_regs.read_scl() {
-            // TODO: add timeout
+            if self.timer.poll() {
+                return Err(ErrorKind::Bus);
+            }
         }
         Ok(())
     }
 
+    /// Standard I2C bus-recovery sequence. If SDA is stuck low (a slave left
+    /// mid-byte, still holding it down), toggles SCL up to 9 times -- enough
+    /// to clock out any partial byte a confused slave might be holding --
+    /// watching SDA between pulses, then issues a STOP. A no-op if SDA is
+    /// already released.
+    pub fn recover_bus(&mut self) -> Result<(), ErrorKind> {
+        if self.i2c_regs.read_sda() {
+            return Ok(());
+        }
+
+        for _ in 0..9 {
+            self.i2c_regs.set_scl();
+            self.delay();
+            self.i2c_regs.clear_scl();
+            self.delay();
+
+            if self.i2c_regs.read_sda() {
+                break;
+            }
+        }
+
+        self.stop_cond()
+    }
+
     fn read_bit(&mut self) -> Result<bool, ErrorKind> {
         self.i2c_regs.set_sda();
         self.delay();
@@ -258,6 +522,24 @@ impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Tar
     }
 
     fn master_recv(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), ErrorKind> {
+        match self.mode {
+            I2CMasterMode::BitBang => self.master_recv_bitbang(address, read),
+            I2CMasterMode::HwFifo => self.master_recv_hw(address, read),
+        }
+    }
+
+    fn master_send(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), ErrorKind> {
+        match self.mode {
+            I2CMasterMode::BitBang => self.master_send_bitbang(address, write),
+            I2CMasterMode::HwFifo => self.master_send_hw(address, write),
+        }
+    }
+
+    fn master_recv_bitbang(
+        &mut self,
+        address: SevenBitAddress,
+        read: &mut [u8],
+    ) -> Result<(), ErrorKind> {
         self.start_cond()?;
         self.write_byte((address << 1) | 1)?;
 
@@ -270,7 +552,11 @@ impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Tar
         Ok(())
     }
 
-    fn master_send(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), ErrorKind> {
+    fn master_send_bitbang(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+    ) -> Result<(), ErrorKind> {
         self.start_cond()?;
 
         self.write_byte(address << 1)?;
@@ -285,11 +571,209 @@ impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Tar
     }
 }
 
-impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> ErrorType for I2CMaster<'a, T, R> {
+/// Largest read the native I2C controller's receive-count field
+/// (`rxctrl1.cnt`, an 8-bit field where 0 means 256) can be told to expect
+/// in one START/STOP transaction. [`I2CMaster::master_recv_hw`] splits
+/// longer reads into this many bytes per transaction.
+const HW_MAX_READ_CHUNK: usize = 256;
+
+impl<
+        'a,
+        'b,
+        T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C,
+        R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR,
+    > I2CMaster<'a, 'b, T, R>
+{
+    /// Builds an [`I2CMaster`] that drives the peripheral's native master
+    /// state machine and TX/RX FIFOs instead of bit-banging SCL/SDA, for
+    /// transfers that shouldn't block the core per-bit. `target_freq_hz` is
+    /// the desired SCL frequency and `pclk_hz` the peripheral clock actually
+    /// feeding the controller (e.g. from [`crate::peripherals::oscillator::SystemClock`]),
+    /// used to program the clock high/low timing registers.
+    ///
+    /// The returned master still presents the same `embedded_hal::i2c::I2c`
+    /// surface as one built with [`Self::new`]; callers don't need to
+    /// change anything beyond which constructor they call.
+    pub fn new_hw_fifo(gcr_regs: &GCR, i2c_regs: T, timer: Timer<'a, 'b, R>, target_freq_hz: u32, pclk_hz: u32) -> Self {
+        T::reset_peripheral(gcr_regs);
+        T::peripheral_clock_enable(gcr_regs);
+
+        i2c_regs.ctrl().modify(|_, w| {
+            w.mst_mode().bit(true)
+                .bb_mode().bit(false)
+                .gc_addr_en().bit(false)
+                .irxm_en().bit(false)
+                .clkstr_dis().bit(false)
+                .hs_en().bit(false)
+                .en().bit(true)
+        });
+
+        // Splits the SCL period evenly between clkhi and clklo, with no
+        // rise-time compensation (unlike `peripherals::i2c::compute_timings`).
+        let multiplier = pclk_hz / target_freq_hz;
+        let val = (multiplier / 2 - 1) as u16;
+        i2c_regs.clkhi().write(|w| w.hi().variant(val));
+        i2c_regs.clklo().write(|w| w.lo().variant(val));
+
+        Self {
+            i2c_regs,
+            timer,
+            started: false,
+            mode: I2CMasterMode::HwFifo,
+        }
+    }
+
+    /// `true` if any of the controller's latched bus-error interrupt flags
+    /// are set.
+    fn hw_bus_error(&self) -> bool {
+        let flags = self.i2c_regs.intfl0().read();
+        flags.data_err().bit()
+            || flags.addr_nack_err().bit()
+            || flags.stop_err().bit()
+            || flags.start_err().bit()
+            || flags.dnr_err().bit()
+            || flags.arb_err().bit()
+    }
+
+    /// Clears every latched interrupt flag so a stale flag from a prior
+    /// transaction can't be mistaken for one belonging to the next.
+    fn hw_clear_interrupt_flags(&mut self) {
+        self.i2c_regs.intfl0().modify(|_, w| {
+            w.wr_addr_match().bit(true)
+                .rd_addr_match().bit(true)
+                .tx_lockout().bit(true)
+                .stop_err().bit(true)
+                .start_err().bit(true)
+                .dnr_err().bit(true)
+                .data_err().bit(true)
+                .addr_nack_err().bit(true)
+                .to_err().bit(true)
+                .arb_err().bit(true)
+                .addr_ack().bit(true)
+                .stop().bit(true)
+                .done().bit(true)
+        });
+    }
+
+    /// Flushes both FIFOs so neither holds bytes left over from a prior
+    /// transaction.
+    fn hw_flush_fifo(&mut self) {
+        self.i2c_regs.rxctrl0().modify(|_, w| w.flush().bit(true));
+        self.i2c_regs.txctrl0().modify(|_, w| w.flush().bit(true));
+        while self.i2c_regs.rxctrl0().read().flush().bit()
+            || self.i2c_regs.txctrl0().read().flush().bit()
+        {}
+    }
+
+    /// Sends `write` to `address` using the native FIFO/state-machine path,
+    /// bounded by the held timer so a stuck bus returns [`ErrorKind::Bus`]
+    /// instead of hanging.
+    fn master_send_hw(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), ErrorKind> {
+        self.hw_clear_interrupt_flags();
+        self.hw_flush_fifo();
+
+        self.i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(address << 1));
+
+        let mut bytes = write.iter();
+        while !self.i2c_regs.status().read().tx_full().bit() {
+            match bytes.next() {
+                Some(byte) => self.i2c_regs.fifo().write(|w| w.data().variant(*byte)),
+                None => break,
+            }
+        }
+
+        self.i2c_regs.mstctrl().modify(|_, w| w.start().variant(true));
+
+        self.timer.reset();
+        while !self.i2c_regs.intfl0().read().addr_ack().bit() {
+            if self.hw_bus_error() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+            }
+            if self.timer.poll() {
+                self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                return Err(ErrorKind::Bus);
+            }
+        }
+
+        for byte in bytes {
+            self.timer.reset();
+            while self.i2c_regs.status().read().tx_full().bit() {
+                if self.hw_bus_error() || self.timer.poll() {
+                    self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                    return Err(ErrorKind::Bus);
+                }
+            }
+            self.i2c_regs.fifo().write(|w| w.data().variant(*byte));
+        }
+
+        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+
+        self.timer.reset();
+        while !self.i2c_regs.intfl0().read().done().bit() {
+            if self.hw_bus_error() || self.timer.poll() {
+                return Err(ErrorKind::Bus);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads `read.len()` bytes from `address` using the native FIFO/state-
+    /// machine path, splitting the transfer into [`HW_MAX_READ_CHUNK`]-sized
+    /// transactions since the controller's receive-count field can only
+    /// request up to 256 bytes at a time.
+    fn master_recv_hw(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), ErrorKind> {
+        for chunk in read.chunks_mut(HW_MAX_READ_CHUNK) {
+            self.hw_clear_interrupt_flags();
+            self.hw_flush_fifo();
+
+            // A count of 0 is interpreted by the hardware as 256.
+            self.i2c_regs
+                .rxctrl1()
+                .modify(|_, w| w.cnt().variant(chunk.len() as u8));
+            self.i2c_regs
+                .fifo()
+                .write(|w| w.data().variant((address << 1) | 1));
+            self.i2c_regs.mstctrl().modify(|_, w| w.start().variant(true));
+
+            self.timer.reset();
+            while !self.i2c_regs.intfl0().read().addr_ack().bit() {
+                if self.i2c_regs.intfl0().read().addr_nack_err().bit() {
+                    self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                    return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+                }
+                if self.hw_bus_error() || self.timer.poll() {
+                    self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                    return Err(ErrorKind::Bus);
+                }
+            }
+
+            for cell in chunk.iter_mut() {
+                self.timer.reset();
+                while self.i2c_regs.status().read().rx_em().bit() {
+                    if self.hw_bus_error() || self.timer.poll() {
+                        self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+                        return Err(ErrorKind::Bus);
+                    }
+                }
+                *cell = self.i2c_regs.fifo().read().data().bits();
+            }
+
+            self.i2c_regs.mstctrl().modify(|_, w| w.stop().bit(true));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'b, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> ErrorType for I2CMaster<'a, 'b, T, R> {
     type Error = ErrorKind;
 }
 
-impl<'a, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> embedded_hal::i2c::I2c for I2CMaster<'a, T, R> {
+impl<'a, 'b, T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C, R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR> embedded_hal::i2c::I2c for I2CMaster<'a, 'b, T, R> {
     fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
         self.master_recv(address, read)?;
         Ok(())
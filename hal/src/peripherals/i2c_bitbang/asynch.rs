@@ -0,0 +1,299 @@
+//! Async wrapper around an [`I2CMaster`] built in hardware-FIFO mode (see
+//! [`I2CMaster::new_hw_fifo`]), implementing `embedded_hal_async::i2c::I2c`.
+//!
+//! Mirrors the approach [`crate::peripherals::i2c::asynch`] takes for the
+//! other I2C driver: instead of busy-waiting on the `done`/FIFO-threshold
+//! flags the way [`I2CMaster::write`](embedded_hal::i2c::I2c::write) does in
+//! hardware mode, the futures here register a per-instance [`AtomicWaker`]
+//! and return [`Poll::Pending`] between FIFO pushes/pulls until the I2Cn
+//! interrupt handler wakes them. Callers are responsible for routing the
+//! I2Cn interrupt to [`on_interrupt`] from their `#[interrupt]` handler.
+//!
+//! Only meaningful for an [`I2CMaster`] built with
+//! [`I2CMaster::new_hw_fifo`] -- the bit-banged path has no peripheral
+//! interrupt to wait on, so there's nothing for this wrapper to yield to.
+
+use core::future::poll_fn;
+use core::ops::Deref;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Poll;
+
+use embassy_sync::waker::AtomicWaker;
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation, SevenBitAddress};
+use embedded_hal_async::i2c::I2c;
+use max78000::{i2c0, tmr};
+
+use crate::peripherals::i2c_bitbang::{I2CMaster, BBGCRI2C};
+use crate::peripherals::timer::TimerPeripheralGCR;
+
+/// Number of I2C instances (I2C0, I2C1, I2C2) that need a waker slot.
+const NUM_I2C_INSTANCES: usize = 3;
+
+static I2C_WAKERS: [AtomicWaker; NUM_I2C_INSTANCES] =
+    [const { AtomicWaker::new() }; NUM_I2C_INSTANCES];
+static I2C_ERROR: [AtomicBool; NUM_I2C_INSTANCES] =
+    [const { AtomicBool::new(false) }; NUM_I2C_INSTANCES];
+
+/// Identifies which of the three I2C register blocks a caller is operating
+/// on, used to index into the waker/error tables.
+#[derive(Copy, Clone)]
+pub enum I2CInstance {
+    /// I2C0
+    I2C0 = 0,
+    /// I2C1
+    I2C1 = 1,
+    /// I2C2
+    I2C2 = 2,
+}
+
+fn enable_fifo_interrupts(i2c_regs: &impl Deref<Target = i2c0::RegisterBlock>) {
+    i2c_regs.inten0().modify(|_, w| {
+        w.tx_thd()
+            .bit(true)
+            .rx_thd()
+            .bit(true)
+            .done()
+            .bit(true)
+            .addr_ack()
+            .bit(true)
+    });
+}
+
+fn disable_fifo_interrupts(i2c_regs: &impl Deref<Target = i2c0::RegisterBlock>) {
+    i2c_regs.inten0().modify(|_, w| {
+        w.tx_thd()
+            .bit(false)
+            .rx_thd()
+            .bit(false)
+            .done()
+            .bit(false)
+            .addr_ack()
+            .bit(false)
+    });
+}
+
+/// `true` if any of the controller's latched bus-error interrupt flags are
+/// set, mirroring [`I2CMaster`]'s private `hw_bus_error` check.
+fn bus_error(i2c_regs: &impl Deref<Target = i2c0::RegisterBlock>) -> bool {
+    let flags = i2c_regs.intfl0().read();
+    flags.data_err().bit()
+        || flags.addr_nack_err().bit()
+        || flags.stop_err().bit()
+        || flags.start_err().bit()
+        || flags.dnr_err().bit()
+        || flags.arb_err().bit()
+}
+
+/// Call this from the `I2Cn` NVIC handler. Masks the FIFO-threshold/`done`
+/// interrupts back off (the future re-arms them on its next poll) and wakes
+/// whichever future is waiting on this instance.
+pub fn on_interrupt(instance: I2CInstance, i2c_regs: &impl Deref<Target = i2c0::RegisterBlock>) {
+    disable_fifo_interrupts(i2c_regs);
+
+    if bus_error(i2c_regs) {
+        I2C_ERROR[instance as usize].store(true, Ordering::Release);
+    }
+
+    I2C_WAKERS[instance as usize].wake();
+}
+
+/// Async extension for an [`I2CMaster`] built with
+/// [`I2CMaster::new_hw_fifo`].
+pub struct I2CMasterAsync<
+    'a,
+    'b,
+    T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C,
+    R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR,
+> {
+    inner: I2CMaster<'a, 'b, T, R>,
+    instance: I2CInstance,
+}
+
+impl<
+        'a,
+        'b,
+        T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C,
+        R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR,
+    > I2CMasterAsync<'a, 'b, T, R>
+{
+    /// Wraps an existing [`I2CMaster`] (built with
+    /// [`I2CMaster::new_hw_fifo`]) to add the `embedded_hal_async::i2c::I2c`
+    /// surface.
+    pub fn new(inner: I2CMaster<'a, 'b, T, R>, instance: I2CInstance) -> Self {
+        Self { inner, instance }
+    }
+
+    /// Waits for one step of a hardware transaction, re-arming the FIFO
+    /// interrupts and yielding to the executor between attempts rather than
+    /// busy-waiting the way the blocking hardware-mode path does.
+    async fn wait_for<F: FnMut() -> Option<Result<(), ErrorKind>>>(
+        &mut self,
+        mut ready: F,
+    ) -> Result<(), ErrorKind> {
+        poll_fn(|cx| {
+            I2C_WAKERS[self.instance as usize].register(cx.waker());
+            enable_fifo_interrupts(&self.inner.i2c_regs);
+
+            if I2C_ERROR[self.instance as usize].swap(false, Ordering::AcqRel) {
+                return Poll::Ready(Err(ErrorKind::Bus));
+            }
+
+            match ready() {
+                Some(result) => Poll::Ready(result),
+                None => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// Asynchronously writes `write` to `address`, yielding between FIFO
+    /// refills instead of spinning until the controller reports `done`.
+    pub async fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), ErrorKind> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+
+        self.inner.hw_clear_interrupt_flags();
+        self.inner.hw_flush_fifo();
+
+        self.inner
+            .i2c_regs
+            .fifo()
+            .write(|w| w.data().variant(address << 1));
+
+        let mut bytes = write.iter().copied();
+        while !self.inner.i2c_regs.status().read().tx_full().bit() {
+            match bytes.next() {
+                Some(byte) => self.inner.i2c_regs.fifo().write(|w| w.data().variant(byte)),
+                None => break,
+            }
+        }
+
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        self.wait_for(|| {
+            let flags = self.inner.i2c_regs.intfl0().read();
+            if flags.addr_nack_err().bit() {
+                Some(Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)))
+            } else if flags.addr_ack().bit() {
+                Some(Ok(()))
+            } else {
+                None
+            }
+        })
+        .await?;
+
+        for byte in bytes {
+            self.wait_for(|| {
+                (!self.inner.i2c_regs.status().read().tx_full().bit()).then_some(Ok(()))
+            })
+            .await?;
+            self.inner.i2c_regs.fifo().write(|w| w.data().variant(byte));
+        }
+
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.stop().bit(true));
+
+        self.wait_for(|| {
+            self.inner
+                .i2c_regs
+                .intfl0()
+                .read()
+                .done()
+                .bit()
+                .then_some(Ok(()))
+        })
+        .await
+    }
+
+    /// Asynchronously reads from `address` into `read`, yielding between RX
+    /// FIFO drains instead of spinning until it fills.
+    pub async fn read(
+        &mut self,
+        address: SevenBitAddress,
+        read: &mut [u8],
+    ) -> Result<(), ErrorKind> {
+        I2C_ERROR[self.instance as usize].store(false, Ordering::Release);
+
+        self.inner.hw_clear_interrupt_flags();
+        self.inner.hw_flush_fifo();
+
+        // A count of 0 is interpreted by the hardware as 256; longer reads
+        // are the caller's responsibility to chunk, mirroring the blocking
+        // hardware-mode path's `HW_MAX_READ_CHUNK`.
+        self.inner
+            .i2c_regs
+            .rxctrl1()
+            .modify(|_, w| w.cnt().variant(read.len() as u8));
+        self.inner
+            .i2c_regs
+            .fifo()
+            .write(|w| w.data().variant((address << 1) | 1));
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.start().variant(true));
+
+        self.wait_for(|| {
+            let flags = self.inner.i2c_regs.intfl0().read();
+            if flags.addr_nack_err().bit() {
+                Some(Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)))
+            } else if flags.addr_ack().bit() {
+                Some(Ok(()))
+            } else {
+                None
+            }
+        })
+        .await?;
+
+        for cell in read.iter_mut() {
+            self.wait_for(|| {
+                (!self.inner.i2c_regs.status().read().rx_em().bit()).then_some(Ok(()))
+            })
+            .await?;
+            *cell = self.inner.i2c_regs.fifo().read().data().bits();
+        }
+
+        self.inner
+            .i2c_regs
+            .mstctrl()
+            .modify(|_, w| w.stop().bit(true));
+
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        'b,
+        T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C,
+        R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR,
+    > ErrorType for I2CMasterAsync<'a, 'b, T, R>
+{
+    type Error = ErrorKind;
+}
+
+impl<
+        'a,
+        'b,
+        T: Deref<Target = i2c0::RegisterBlock> + BBGCRI2C,
+        R: Sized + Deref<Target = tmr::RegisterBlock> + TimerPeripheralGCR,
+    > I2c for I2CMasterAsync<'a, 'b, T, R>
+{
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations.iter_mut() {
+            match operation {
+                Operation::Read(read) => self.read(address, read).await?,
+                Operation::Write(write) => self.write(address, write).await?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,9 +3,15 @@
 //! \[low_frequency\] features should not be used if the flc is also in use
 //! because the FLC_CLK needs to be 1MHz.
 
+use core::convert::Infallible;
+
 use max78000::gcr::CLKCTRL;
+#[cfg(feature = "low_frequency")]
+use max78000::trimsir::inro::LPCLKSEL_A;
 use max78000::trimsir::INRO;
 
+use crate::peripherals::power::{PeripheralClockGuard, Power, PowerControl, ToggleableModule};
+
 /// Acceptable Internal Primary Oscillator frequency. Can be converted into a
 /// u32 integer representing a value in hertz.
 #[derive(Clone, Copy, Default)]
@@ -133,6 +139,28 @@ impl From<IpoDivider> for u8 {
     }
 }
 
+impl DividerValues for IpoDivider {
+    #[cfg(not(feature = "low_frequency"))]
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32, 64];
+    #[cfg(feature = "low_frequency")]
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32, 64, 128];
+
+    fn from_value(div: u8) -> Self {
+        match div {
+            1 => IpoDivider::_1,
+            2 => IpoDivider::_2,
+            4 => IpoDivider::_4,
+            8 => IpoDivider::_8,
+            16 => IpoDivider::_16,
+            32 => IpoDivider::_32,
+            64 => IpoDivider::_64,
+            #[cfg(feature = "low_frequency")]
+            128 => IpoDivider::_128,
+            _ => unreachable!("div is always drawn from Self::VALUES"),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[allow(missing_docs)]
 /// Acceptable Internal Secondary Oscillator dividers.
@@ -167,6 +195,29 @@ impl From<IsoDivider> for u8 {
     }
 }
 
+impl DividerValues for IsoDivider {
+    #[cfg(not(feature = "low_frequency"))]
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32];
+    #[cfg(feature = "low_frequency")]
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32, 64, 128];
+
+    fn from_value(div: u8) -> Self {
+        match div {
+            1 => IsoDivider::_1,
+            2 => IsoDivider::_2,
+            4 => IsoDivider::_4,
+            8 => IsoDivider::_8,
+            16 => IsoDivider::_16,
+            32 => IsoDivider::_32,
+            #[cfg(feature = "low_frequency")]
+            64 => IsoDivider::_64,
+            #[cfg(feature = "low_frequency")]
+            128 => IsoDivider::_128,
+            _ => unreachable!("div is always drawn from Self::VALUES"),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[allow(missing_docs)]
 /// Acceptable Internal Baud Rate Oscillator dividers.
@@ -207,6 +258,32 @@ impl From<IbroDivider> for u8 {
     }
 }
 
+impl DividerValues for IbroDivider {
+    #[cfg(not(feature = "low_frequency"))]
+    const VALUES: &'static [u8] = &[1, 2, 4];
+    #[cfg(feature = "low_frequency")]
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32, 64, 128];
+
+    fn from_value(div: u8) -> Self {
+        match div {
+            1 => IbroDivider::_1,
+            2 => IbroDivider::_2,
+            4 => IbroDivider::_4,
+            #[cfg(feature = "low_frequency")]
+            8 => IbroDivider::_8,
+            #[cfg(feature = "low_frequency")]
+            16 => IbroDivider::_16,
+            #[cfg(feature = "low_frequency")]
+            32 => IbroDivider::_32,
+            #[cfg(feature = "low_frequency")]
+            64 => IbroDivider::_64,
+            #[cfg(feature = "low_frequency")]
+            128 => IbroDivider::_128,
+            _ => unreachable!("div is always drawn from Self::VALUES"),
+        }
+    }
+}
+
 #[cfg(feature = "low_frequency")]
 #[derive(Clone, Copy)]
 #[allow(missing_docs)]
@@ -246,6 +323,25 @@ impl From<InroDivider> for u8 {
     }
 }
 
+#[cfg(feature = "low_frequency")]
+impl DividerValues for InroDivider {
+    const VALUES: &'static [u8] = &[1, 2, 4, 8, 16, 32, 64, 128];
+
+    fn from_value(div: u8) -> Self {
+        match div {
+            1 => InroDivider::_1,
+            2 => InroDivider::_2,
+            4 => InroDivider::_4,
+            8 => InroDivider::_8,
+            16 => InroDivider::_16,
+            32 => InroDivider::_32,
+            64 => InroDivider::_64,
+            128 => InroDivider::_128,
+            _ => unreachable!("div is always drawn from Self::VALUES"),
+        }
+    }
+}
+
 #[cfg(feature = "low_frequency")]
 /// Acceptable External Real Time Clock dividers.
 /// Can be converted into a u8 integer.
@@ -262,7 +358,7 @@ pub struct SystemClock<'a, 'b> {
     /// Reference to the inro register from the TRIMSIR
     trimsir_inro_register: &'b INRO,
     /// The current SYS_OSC frequency
-    clock_frequency: u32,
+    clock_frequency: Hertz,
     /// The current SYS_OSC divider
     clock_divider: u8,
 }
@@ -273,35 +369,149 @@ impl<'a, 'b> SystemClock<'a, 'b> {
     /// inro register block. The constructor defines current system clock's
     /// frequency and divider. In addition it sets the system oscillator to the
     /// desired oscillator using the SystemClock's set_sysclk function.
+    ///
+    /// # Errors
+    ///
+    /// - [`VoltageScaleError::FrequencyExceedsVoltageScale`] - `osc`'s
+    ///   effective frequency exceeds what `power`'s active VCORE range
+    ///   permits; raise it with [`Power::set_overdrive`] first.
     /// # Example
     /// ```
     /// let ipo = Ipo::new(IpoFrequency::_100MHz, IpoDivider::_1);
-    /// let sys_clk = SystemClock::new(&ipo, clkctrl_peripheral, trimsir_peripheral);
+    /// let sys_clk = SystemClock::new(&ipo, clkctrl_peripheral, trimsir_peripheral, &power);
     /// ```
     pub(crate) fn new<T: Oscillator + private::Oscillator>(
         osc: &T,
         gcr_clkctrl_peripheral: &'a CLKCTRL,
         trimsir_inro_peripheral: &'b INRO,
-    ) -> Self {
+        power: &Power,
+    ) -> Result<Self, VoltageScaleError> {
         let mut new_sysclk = Self {
             gcr_clkctrl_register: gcr_clkctrl_peripheral,
             trimsir_inro_register: trimsir_inro_peripheral,
-            clock_frequency: osc.get_freq().into(),
+            clock_frequency: Hertz::new(osc.get_freq().into()),
             clock_divider: osc.get_div().into(),
         };
 
-        new_sysclk.set_sysclk(osc);
-        new_sysclk
+        new_sysclk.set_sysclk(osc, power)?;
+        Ok(new_sysclk)
     }
 
-    /// Sets the desired oscillator as the system oscillator using the
-    /// set_sysclk function of the oscillator type. In addition, it updates the
-    /// clock_frequency and clock_divider fields of the SystemClock struct.
-    pub fn set_sysclk<T: Oscillator + private::Oscillator>(&mut self, osc: &T) {
-        osc.set_sysclk(self.gcr_clkctrl_register);
+    /// Sets the desired oscillator as the system oscillator, blocking until
+    /// ``sysclk_rdy`` is observed set. Equivalent to [`Self::begin_sysclk_switch`]
+    /// immediately followed by a blocking [`Self::await_switch`] loop; kept
+    /// around for callers that have no other work to overlap with the
+    /// oscillator's stabilization time.
+    ///
+    /// # Errors
+    ///
+    /// - [`VoltageScaleError::FrequencyExceedsVoltageScale`] - see
+    ///   [`Self::begin_sysclk_switch`].
+    pub fn set_sysclk<T: Oscillator + private::Oscillator>(
+        &mut self,
+        osc: &T,
+        power: &Power,
+    ) -> Result<(), VoltageScaleError> {
+        let token = self.begin_sysclk_switch(osc, power)?;
+        while self.await_switch(token).is_err() {}
+        Ok(())
+    }
+
+    /// Begins switching the system oscillator to `osc`: enables it and
+    /// writes ``sysclk_sel``/the divider, but does **not** spin on
+    /// ``sysclk_rdy``. Enabling the oscillator still busy-waits on its own
+    /// ready bit (e.g. ``ipo_rdy``), so that stabilization time is not
+    /// overlapped; only the wait for the switch itself is left to the
+    /// caller. Poll the returned [`SysclkSwitchToken`] with
+    /// [`Self::await_switch`] to find out when the switch has landed.
+    ///
+    /// Before touching any registers, checks `osc`'s effective frequency
+    /// (base frequency divided by divider) against `power`'s currently
+    /// active [`VoltageScale`](crate::peripherals::power::VoltageScale); the datasheet caps SYS_CLK depending on the
+    /// VCORE range, so a frequency above that cap would otherwise silently
+    /// risk a brownout/timing fault.
+    ///
+    /// # Errors
+    ///
+    /// - [`VoltageScaleError::FrequencyExceedsVoltageScale`] - `osc`'s
+    ///   effective frequency exceeds what `power`'s active VCORE range
+    ///   permits.
+    pub fn begin_sysclk_switch<T: Oscillator + private::Oscillator>(
+        &self,
+        osc: &T,
+        power: &Power,
+    ) -> Result<SysclkSwitchToken, VoltageScaleError> {
+        let target_frequency = Hertz::new(osc.get_freq().into());
+        let target_divider: u8 = osc.get_div().into();
+        let effective_hz = (target_frequency / u32::from(target_divider)).to_hz();
+
+        let scale = power.voltage_scale();
+        let max_hz = scale.max_sysclk_hz();
+        if effective_hz > max_hz {
+            return Err(VoltageScaleError::FrequencyExceedsVoltageScale {
+                requested_hz: effective_hz,
+                max_hz,
+            });
+        }
+
+        osc.begin_sysclk(self.gcr_clkctrl_register);
         osc.set_divider(self.gcr_clkctrl_register, self.trimsir_inro_register);
-        self.clock_frequency = osc.get_freq().into();
-        self.clock_divider = osc.get_div().into();
+
+        Ok(SysclkSwitchToken {
+            target_frequency,
+            target_divider,
+        })
+    }
+
+    /// Polls a switch begun by [`Self::begin_sysclk_switch`], returning
+    /// [`nb::Error::WouldBlock`] while the oscillator is still stabilizing.
+    /// Once ``sysclk_rdy`` is observed set, this commits `token`'s target
+    /// frequency/divider into [`Self::clock_frequency`]/[`Self::clock_divider`]
+    /// and returns `Ok(())`, so the struct never reports a frequency the
+    /// hardware hasn't reached yet. The hardware switch itself was already
+    /// started by [`Self::begin_sysclk_switch`]: if no call with the token
+    /// ever returns `Ok(())`, the struct keeps reporting the previous
+    /// frequency/divider.
+    pub fn await_switch(&mut self, token: SysclkSwitchToken) -> nb::Result<(), Infallible> {
+        if !self.gcr_clkctrl_register.read().sysclk_rdy().bit_is_set() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.clock_frequency = token.target_frequency;
+        self.clock_divider = token.target_divider;
+        Ok(())
+    }
+
+    /// Begins switching the system clock to `T`, automatically picking
+    /// whichever legal divider brings `T`'s base frequency closest to
+    /// `target_hz` (see [`best_divider`]) instead of forcing the caller to
+    /// hand-pick e.g. `IpoDivider::_8`. Like [`Self::begin_sysclk_switch`],
+    /// this does not spin on ``sysclk_rdy``; poll the returned
+    /// [`SysclkSwitchToken`] with [`Self::await_switch`].
+    ///
+    /// # ERRORS:
+    ///
+    /// - [`SysclkTargetError::TargetAboveBaseFrequency`] - `target_hz` is
+    ///   above `T`'s base frequency; dividers can only slow a clock down,
+    ///   so no legal divider can reach it.
+    /// - [`SysclkTargetError::VoltageScale`] - the frequency the chosen
+    ///   divider achieves exceeds what `power`'s active VCORE range permits.
+    pub fn set_sysclk_target<T>(
+        &self,
+        target_hz: u32,
+        power: &Power,
+    ) -> Result<SysclkSwitchToken, SysclkTargetError>
+    where
+        T: Oscillator + private::Oscillator,
+        T::Frequency: Default,
+        T::Divider: DividerValues,
+    {
+        let frequency = T::Frequency::default();
+        let base_hz: u32 = frequency.into();
+        let (divider, _achieved_hz) = best_divider::<T::Divider>(base_hz, target_hz)?;
+        let osc = T::new(frequency, divider);
+
+        Ok(self.begin_sysclk_switch(&osc, power)?)
     }
 
     /// Returns the clock divider of the SYS_OSC
@@ -309,10 +519,233 @@ impl<'a, 'b> SystemClock<'a, 'b> {
         self.clock_divider
     }
 
-    /// Returns the frequency of the SYS_OSC in hertz
-    pub fn get_freq(&self) -> u32 {
+    /// Returns the frequency of the SYS_OSC.
+    pub fn get_freq(&self) -> Hertz {
         self.clock_frequency
     }
+
+    /// Returns the current SYS_CLK frequency and divider alongside a
+    /// [`PeripheralClockGuard`] that ungates `module`'s clock, so a driver
+    /// can pick up its bus rate and its clock gate from one call instead of
+    /// separately calling [`Self::get_freq`]/[`Self::get_div`] and
+    /// [`PowerControl::enable_guarded`]. This is the common bug this method
+    /// exists to prevent: configuring a peripheral against a frequency that
+    /// was read before its clock was actually ungated.
+    pub fn request_clock<'p, 'r, 'l>(
+        &self,
+        power_ctrl: &'p PowerControl<'r, 'l>,
+        module: ToggleableModule,
+    ) -> (Hertz, u8, PeripheralClockGuard<'p, 'r, 'l>) {
+        (
+            self.clock_frequency,
+            self.clock_divider,
+            power_ctrl.enable_guarded(module),
+        )
+    }
+
+    /// Freezes the current configuration into a [`Clocks`] snapshot,
+    /// computing SYS_CLK (base/divider) and the fixed-rate always-on clock
+    /// domains. Following the atsamd `GClock`/stm32 `Clocks` convention,
+    /// this consumes `self` so downstream drivers query the frozen
+    /// [`Clocks`] instead of risking a stale read through a `SystemClock`
+    /// that could still be reconfigured out from under them.
+    pub fn freeze(self) -> Clocks {
+        Clocks {
+            sys_clk: self.clock_frequency / u32::from(self.clock_divider),
+            ibro: Hertz(IbroFrequency::_7_3728MHz.into()),
+            #[cfg(feature = "low_frequency")]
+            ertco: Hertz(ErtcoFrequency::_32_768kHz.into()),
+            #[cfg(feature = "low_frequency")]
+            inro: Hertz(match self.trimsir_inro_register.read().lpclksel().variant() {
+                LPCLKSEL_A::_8Khz => 8_000,
+                LPCLKSEL_A::_16Khz => 16_000,
+                LPCLKSEL_A::_30Khz => 30_000,
+            }),
+        }
+    }
+}
+
+/// A frequency in hertz, handed out by [`SystemClock::get_freq`]/[`Clocks`]
+/// so consumers don't have to hand-roll unit bookkeeping (or compare against
+/// magic numbers like `100_000_000`) when querying a clock's rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hertz(u32);
+
+impl Hertz {
+    /// Constructs a frequency from a raw value in hertz.
+    pub const fn new(hz: u32) -> Self {
+        Self(hz)
+    }
+
+    /// Constructs a frequency from a value in kilohertz.
+    pub const fn from_khz(khz: u32) -> Self {
+        Self(khz * 1_000)
+    }
+
+    /// Constructs a frequency from a value in megahertz.
+    pub const fn from_mhz(mhz: u32) -> Self {
+        Self(mhz * 1_000_000)
+    }
+
+    /// The frequency in hertz as a raw integer.
+    pub fn to_hz(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<Hertz> for u32 {
+    fn from(hz: Hertz) -> Self {
+        hz.0
+    }
+}
+
+/// Divides a frequency by a (unitless) divider, e.g. a [`SystemClock::get_div`]
+/// value, without downcasting to a raw integer first.
+impl core::ops::Div<u32> for Hertz {
+    type Output = Hertz;
+
+    fn div(self, rhs: u32) -> Hertz {
+        Hertz(self.0 / rhs)
+    }
+}
+
+/// A frozen snapshot of every clock domain's effective, post-divider
+/// frequency, produced by [`SystemClock::freeze`]. Unlike [`SystemClock`],
+/// which only tracks the SYS_OSC/SYS_CLK pair, this also records the
+/// fixed-rate always-on domains (IBRO, and under `low_frequency`, ERTCO and
+/// INRO), so downstream UART/timer/SPI drivers can query the bus rate they
+/// actually run at instead of recomputing it.
+#[derive(Debug, Clone, Copy)]
+pub struct Clocks {
+    sys_clk: Hertz,
+    ibro: Hertz,
+    #[cfg(feature = "low_frequency")]
+    ertco: Hertz,
+    #[cfg(feature = "low_frequency")]
+    inro: Hertz,
+}
+
+impl Clocks {
+    /// The effective SYS_CLK frequency (base oscillator frequency divided
+    /// by the selected divider).
+    pub fn sys_clk(&self) -> Hertz {
+        self.sys_clk
+    }
+
+    /// The IBRO frequency (7.3728 MHz), the always-on reference UART
+    /// baud-rate generators run from.
+    pub fn ibro(&self) -> Hertz {
+        self.ibro
+    }
+
+    /// The ERTCO frequency (32.768 kHz).
+    #[cfg(feature = "low_frequency")]
+    pub fn ertco(&self) -> Hertz {
+        self.ertco
+    }
+
+    /// The INRO frequency, read back from `TRIMSIR.lpclksel` at the time
+    /// [`SystemClock::freeze`] was called (8, 16, or 30 kHz).
+    #[cfg(feature = "low_frequency")]
+    pub fn inro(&self) -> Hertz {
+        self.inro
+    }
+}
+
+/// Token representing an in-flight, non-blocking switch of the system clock
+/// source begun by [`SystemClock::begin_sysclk_switch`]. Carries the target
+/// frequency/divider so [`SystemClock::await_switch`] can commit them once
+/// the switch is observed to have completed.
+#[derive(Debug, Clone, Copy)]
+pub struct SysclkSwitchToken {
+    target_frequency: Hertz,
+    target_divider: u8,
+}
+
+impl SysclkSwitchToken {
+    /// The frequency the system clock will have once this switch completes.
+    pub fn target_frequency(&self) -> Hertz {
+        self.target_frequency
+    }
+
+    /// The divider the system clock will have once this switch completes.
+    pub fn target_divider(&self) -> u8 {
+        self.target_divider
+    }
+}
+
+/// Error returned when [`SystemClock::set_sysclk_target`] can't find a
+/// legal divider for a requested target frequency.
+#[derive(Debug, Copy, Clone)]
+pub enum SysclkTargetError {
+    /// The requested target frequency is above the oscillator's base
+    /// frequency. Dividers can only slow a clock down, never speed it up,
+    /// so a divider of `1` (the smallest legal value) is the ceiling.
+    TargetAboveBaseFrequency,
+    /// The frequency the chosen divider achieves exceeds what the active
+    /// VCORE range permits. See [`VoltageScaleError`].
+    VoltageScale(VoltageScaleError),
+}
+
+impl From<VoltageScaleError> for SysclkTargetError {
+    fn from(err: VoltageScaleError) -> Self {
+        SysclkTargetError::VoltageScale(err)
+    }
+}
+
+/// Error returned when a requested SYS_CLK frequency exceeds what the
+/// currently active [`VoltageScale`](crate::peripherals::power::VoltageScale) permits.
+#[derive(Debug, Copy, Clone)]
+pub enum VoltageScaleError {
+    /// The requested effective frequency (base frequency divided by
+    /// divider) is above [`VoltageScale::max_sysclk_hz`](crate::peripherals::power::VoltageScale::max_sysclk_hz) for the VCORE
+    /// range [`Power::voltage_scale`] currently reports. Raise VCORE with
+    /// [`Power::set_overdrive`] before retrying, or pick a lower frequency.
+    FrequencyExceedsVoltageScale {
+        /// The effective frequency that was requested, in hertz.
+        requested_hz: u32,
+        /// The maximum frequency the active VCORE range permits, in hertz.
+        max_hz: u32,
+    },
+}
+
+/// Implemented by each oscillator's divider enum so [`best_divider`] can
+/// search its legal values generically. `VALUES` must be sorted ascending
+/// and only list variants compiled in under the active feature set.
+pub trait DividerValues: Into<u8> + Sized {
+    /// All legal divider values for this oscillator, ascending, restricted
+    /// to whichever variants the active feature set compiles in.
+    const VALUES: &'static [u8];
+
+    /// Looks up the variant whose value is `div`. Only ever called with a
+    /// `div` drawn from `Self::VALUES`.
+    fn from_value(div: u8) -> Self;
+}
+
+/// Finds the legal divider (from `T::VALUES`) that brings `src_hz` closest
+/// to `target_hz`, modeled on rp2040's `make_div`. Returns the selected
+/// divider and the frequency it actually achieves, so callers can check the
+/// rounding error against what they asked for.
+///
+/// # ERRORS:
+///
+/// - [`SysclkTargetError::TargetAboveBaseFrequency`] - `target_hz` is above
+///   `src_hz`.
+pub fn best_divider<T: DividerValues>(
+    src_hz: u32,
+    target_hz: u32,
+) -> Result<(T, u32), SysclkTargetError> {
+    if target_hz > src_hz {
+        return Err(SysclkTargetError::TargetAboveBaseFrequency);
+    }
+
+    let (div, achieved_hz) = T::VALUES
+        .iter()
+        .map(|&div| (div, src_hz / u32::from(div)))
+        .min_by_key(|&(_, achieved_hz)| target_hz.abs_diff(achieved_hz))
+        .expect("VALUES is never empty");
+
+    Ok((T::from_value(div), achieved_hz))
 }
 
 /// Oscillator trait that describes the needed functionality of a oscillator type
@@ -344,8 +777,9 @@ pub(crate) mod private {
         fn enable(&self, gcr_clkctrl: &CLKCTRL);
         /// Sets the bits in the GCR clkctrl register to select the oscillitor as
         /// the system oscillator used by the system clock. If the oscillator is not
-        /// enable, this function enables it
-        fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL);
+        /// enabled, this function enables it. Does **not** wait for ``sysclk_rdy``;
+        /// callers poll that separately so the switch can be non-blocking.
+        fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL);
         /// Sets the bits in the GCR clkctrl register to select the clock divider and frequency
         fn set_divider(&self, gcr_clkctrl: &CLKCTRL, trimsir_inro: &INRO);
     }
@@ -382,10 +816,9 @@ impl private::Oscillator for Ipo {
         while !gcr_clkctrl.read().ipo_rdy().bit_is_set() {}
     }
 
-    fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
+    fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
         self.enable(gcr_clkctrl);
         gcr_clkctrl.modify(|_, w| w.sysclk_sel().ipo());
-        while !gcr_clkctrl.read().sysclk_rdy().bit_is_set() {}
     }
 
     fn set_divider(&self, gcr_clkctrl: &CLKCTRL, _trimsir_inro: &INRO) {
@@ -451,10 +884,9 @@ impl private::Oscillator for Iso {
         while !gcr_clkctrl.read().iso_rdy().bit_is_set() {}
     }
 
-    fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
+    fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
         self.enable(gcr_clkctrl);
         gcr_clkctrl.modify(|_, w| w.sysclk_sel().iso());
-        while !gcr_clkctrl.read().sysclk_rdy().bit_is_set() {}
     }
 
     fn set_divider(&self, gcr_clkctrl: &CLKCTRL, _trimsir_inro: &INRO) {
@@ -520,10 +952,9 @@ impl private::Oscillator for Ibro {
         while !gcr_clkctrl.read().ibro_rdy().bit_is_set() {}
     }
 
-    fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
+    fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
         self.enable(gcr_clkctrl);
         gcr_clkctrl.modify(|_, w| w.sysclk_sel().ibro());
-        while !gcr_clkctrl.read().sysclk_rdy().bit_is_set() {}
     }
 
     fn set_divider(&self, gcr_clkctrl: &CLKCTRL, _trimsir_inro: &INRO) {
@@ -595,10 +1026,9 @@ impl private::Oscillator for Inro {
         while !gcr_clkctrl.read().inro_rdy().bit_is_set() {}
     }
 
-    fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
+    fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
         self.enable(gcr_clkctrl);
         gcr_clkctrl.modify(|_, w| w.sysclk_sel().inro());
-        while !gcr_clkctrl.read().sysclk_rdy().bit_is_set() {}
     }
 
     fn set_divider(&self, gcr_clkctrl: &CLKCTRL, trimsir: &INRO) {
@@ -678,10 +1108,9 @@ impl private::Oscillator for Ertco {
         while !gcr_clkctrl.read().ertco_rdy().bit_is_set() {}
     }
 
-    fn set_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
+    fn begin_sysclk(&self, gcr_clkctrl: &CLKCTRL) {
         self.enable(gcr_clkctrl);
         gcr_clkctrl.modify(|_, w| w.sysclk_sel().ertco());
-        while !gcr_clkctrl.read().sysclk_rdy().bit_is_set() {}
     }
 
     fn set_divider(&self, gcr_clkctrl: &CLKCTRL, _trimsir_inro: &INRO) {
@@ -9,6 +9,7 @@ pub struct PowerControl<'r, 'l> {
 }
 
 /// Indicate a module to enable, disable, or reset through power control registers
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ToggleableModule {
     /// Low-power comparators
     LPCOMP,
@@ -92,6 +93,61 @@ pub enum NonToggleableModule {
     DVS,
 }
 
+/// VCORE range the MAX78000's SIMO regulator is configured for, mirroring
+/// the stm32/embassy `VoltageScale` convention. The datasheet caps SYS_CLK
+/// depending on which range is active; see [`Self::max_sysclk_hz`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VoltageScale {
+    /// Nominal VCORE. Lower power, but caps SYS_CLK at 50 MHz.
+    Nominal,
+    /// Overdrive VCORE. Required to run SYS_CLK at the full 100 MHz IPO.
+    Overdrive,
+}
+
+impl VoltageScale {
+    /// The maximum SYS_CLK frequency, in hertz, the datasheet permits while
+    /// this VCORE range is active.
+    pub fn max_sysclk_hz(self) -> u32 {
+        match self {
+            VoltageScale::Nominal => 50_000_000,
+            VoltageScale::Overdrive => 100_000_000,
+        }
+    }
+}
+
+/// Handle to the core regulator's VCORE range, recorded in the GCR's `pm`
+/// register. [`SystemClock`](crate::peripherals::oscillator::SystemClock)
+/// consults this before switching SYS_CLK so a frequency the active range
+/// can't support is a caught error instead of a silent brownout/timing
+/// fault.
+pub struct Power<'r> {
+    gcr: &'r GCR,
+}
+
+impl<'r> Power<'r> {
+    // TODO: Make pub(crate)
+    /// Creates a new `Power` handle wrapping the GCR register block.
+    pub fn new(gcr: &'r GCR) -> Self {
+        Self { gcr }
+    }
+
+    /// The VCORE range currently active.
+    pub fn voltage_scale(&self) -> VoltageScale {
+        if self.gcr.pm().read().ovr().is_overdrive() {
+            VoltageScale::Overdrive
+        } else {
+            VoltageScale::Nominal
+        }
+    }
+
+    /// Raises VCORE into [`VoltageScale::Overdrive`] so SYS_CLK can be run
+    /// up to the full 100 MHz IPO. There's no supported way back down to
+    /// [`VoltageScale::Nominal`] short of a reset.
+    pub fn set_overdrive(&self) {
+        self.gcr.pm().modify(|_, w| w.ovr().overdrive());
+    }
+}
+
 impl<'r, 'l> PowerControl<'r, 'l> {
     // TODO: Make pub(crate)
     /// Creates a new PowerControl instance that holds references to the GCR and LPGCR registers.
@@ -102,132 +158,220 @@ impl<'r, 'l> PowerControl<'r, 'l> {
     /// Enables the module from the Module enum
     pub fn enable_peripheral(&self, module_input: ToggleableModule) {
         match module_input {
-            ToggleableModule::LPCOMP => self.lpgcr.pclkdis().write(|w| w.lpcomp().en()),
-            ToggleableModule::UART3 => self.lpgcr.pclkdis().write(|w| w.uart3().en()),
-            ToggleableModule::TMR5 => self.lpgcr.pclkdis().write(|w| w.tmr5().en()),
-            ToggleableModule::TMR4 => self.lpgcr.pclkdis().write(|w| w.tmr4().en()),
-            ToggleableModule::WDT1 => self.lpgcr.pclkdis().write(|w| w.wdt1().en()),
-            ToggleableModule::GPIO2 => self.lpgcr.pclkdis().write(|w| w.gpio2().en()),
-
-            ToggleableModule::PT => self.gcr.pclkdis0().write(|w| w.pt().en()),
-            ToggleableModule::I2C1 => self.gcr.pclkdis0().write(|w| w.i2c1().en()),
-            ToggleableModule::CNN => self.gcr.pclkdis0().write(|w| w.cnn().en()),
-            ToggleableModule::ADC => self.gcr.pclkdis0().write(|w| w.adc().en()),
-            ToggleableModule::TMR3 => self.gcr.pclkdis0().write(|w| w.tmr3().en()),
-            ToggleableModule::TMR2 => self.gcr.pclkdis0().write(|w| w.tmr2().en()),
-            ToggleableModule::TMR1 => self.gcr.pclkdis0().write(|w| w.tmr1().en()),
-            ToggleableModule::TMR0 => self.gcr.pclkdis0().write(|w| w.tmr0().en()),
-            ToggleableModule::I2C0 => self.gcr.pclkdis0().write(|w| w.i2c0().en()),
-            ToggleableModule::UART1 => self.gcr.pclkdis0().write(|w| w.uart1().en()),
-            ToggleableModule::UART0 => self.gcr.pclkdis0().write(|w| w.uart0().en()),
-            ToggleableModule::SPI1 => self.gcr.pclkdis0().write(|w| w.spi1().en()),
-            ToggleableModule::DMA => self.gcr.pclkdis0().write(|w| w.dma().en()),
-            ToggleableModule::GPIO1 => self.gcr.pclkdis0().write(|w| w.gpio1().en()),
-            ToggleableModule::GPIO0 => self.gcr.pclkdis0().write(|w| w.gpio0().en()),
-
-            ToggleableModule::CRC => self.gcr.pclkdis1().write(|w| w.crc().en()),
-            ToggleableModule::OWM => self.gcr.pclkdis1().write(|w| w.owm().en()),
-            ToggleableModule::SMPHR => self.gcr.pclkdis1().write(|w| w.smphr().en()),
-            ToggleableModule::TRNG => self.gcr.pclkdis1().write(|w| w.trng().en()),
-            ToggleableModule::UART2 => self.gcr.pclkdis1().write(|w| w.uart2().en()),
-            ToggleableModule::WDT0 => self.gcr.pclkdis1().write(|w| w.wdt0().en()),
-            ToggleableModule::I2C2 => self.gcr.pclkdis1().write(|w| w.i2c2().en()),
-            ToggleableModule::I2S => self.gcr.pclkdis1().write(|w| w.i2s().en()),
-            ToggleableModule::SPI0 => self.gcr.pclkdis1().write(|w| w.spi0().en()),
-            ToggleableModule::AES => self.gcr.pclkdis1().write(|w| w.aes().en()),
-            ToggleableModule::CPU1 => self.gcr.pclkdis1().write(|w| w.cpu1().en()),
+            ToggleableModule::LPCOMP => self.lpgcr.pclkdis().modify(|_, w| w.lpcomp().en()),
+            ToggleableModule::UART3 => self.lpgcr.pclkdis().modify(|_, w| w.uart3().en()),
+            ToggleableModule::TMR5 => self.lpgcr.pclkdis().modify(|_, w| w.tmr5().en()),
+            ToggleableModule::TMR4 => self.lpgcr.pclkdis().modify(|_, w| w.tmr4().en()),
+            ToggleableModule::WDT1 => self.lpgcr.pclkdis().modify(|_, w| w.wdt1().en()),
+            ToggleableModule::GPIO2 => self.lpgcr.pclkdis().modify(|_, w| w.gpio2().en()),
+
+            ToggleableModule::PT => self.gcr.pclkdis0().modify(|_, w| w.pt().en()),
+            ToggleableModule::I2C1 => self.gcr.pclkdis0().modify(|_, w| w.i2c1().en()),
+            ToggleableModule::CNN => self.gcr.pclkdis0().modify(|_, w| w.cnn().en()),
+            ToggleableModule::ADC => self.gcr.pclkdis0().modify(|_, w| w.adc().en()),
+            ToggleableModule::TMR3 => self.gcr.pclkdis0().modify(|_, w| w.tmr3().en()),
+            ToggleableModule::TMR2 => self.gcr.pclkdis0().modify(|_, w| w.tmr2().en()),
+            ToggleableModule::TMR1 => self.gcr.pclkdis0().modify(|_, w| w.tmr1().en()),
+            ToggleableModule::TMR0 => self.gcr.pclkdis0().modify(|_, w| w.tmr0().en()),
+            ToggleableModule::I2C0 => self.gcr.pclkdis0().modify(|_, w| w.i2c0().en()),
+            ToggleableModule::UART1 => self.gcr.pclkdis0().modify(|_, w| w.uart1().en()),
+            ToggleableModule::UART0 => self.gcr.pclkdis0().modify(|_, w| w.uart0().en()),
+            ToggleableModule::SPI1 => self.gcr.pclkdis0().modify(|_, w| w.spi1().en()),
+            ToggleableModule::DMA => self.gcr.pclkdis0().modify(|_, w| w.dma().en()),
+            ToggleableModule::GPIO1 => self.gcr.pclkdis0().modify(|_, w| w.gpio1().en()),
+            ToggleableModule::GPIO0 => self.gcr.pclkdis0().modify(|_, w| w.gpio0().en()),
+
+            ToggleableModule::CRC => self.gcr.pclkdis1().modify(|_, w| w.crc().en()),
+            ToggleableModule::OWM => self.gcr.pclkdis1().modify(|_, w| w.owm().en()),
+            ToggleableModule::SMPHR => self.gcr.pclkdis1().modify(|_, w| w.smphr().en()),
+            ToggleableModule::TRNG => self.gcr.pclkdis1().modify(|_, w| w.trng().en()),
+            ToggleableModule::UART2 => self.gcr.pclkdis1().modify(|_, w| w.uart2().en()),
+            ToggleableModule::WDT0 => self.gcr.pclkdis1().modify(|_, w| w.wdt0().en()),
+            ToggleableModule::I2C2 => self.gcr.pclkdis1().modify(|_, w| w.i2c2().en()),
+            ToggleableModule::I2S => self.gcr.pclkdis1().modify(|_, w| w.i2s().en()),
+            ToggleableModule::SPI0 => self.gcr.pclkdis1().modify(|_, w| w.spi0().en()),
+            ToggleableModule::AES => self.gcr.pclkdis1().modify(|_, w| w.aes().en()),
+            ToggleableModule::CPU1 => self.gcr.pclkdis1().modify(|_, w| w.cpu1().en()),
         }
     }
 
     /// Disables the module from the module enum
     pub fn disable_peripheral(&self, module_input: ToggleableModule) {
         match module_input {
-            ToggleableModule::LPCOMP => self.lpgcr.pclkdis().write(|w| w.lpcomp().dis()),
-            ToggleableModule::UART3 => self.lpgcr.pclkdis().write(|w| w.uart3().dis()),
-            ToggleableModule::TMR5 => self.lpgcr.pclkdis().write(|w| w.tmr5().dis()),
-            ToggleableModule::TMR4 => self.lpgcr.pclkdis().write(|w| w.tmr4().dis()),
-            ToggleableModule::WDT1 => self.lpgcr.pclkdis().write(|w| w.wdt1().dis()),
-            ToggleableModule::GPIO2 => self.lpgcr.pclkdis().write(|w| w.gpio2().dis()),
-
-            ToggleableModule::PT => self.gcr.pclkdis0().write(|w| w.pt().dis()),
-            ToggleableModule::I2C1 => self.gcr.pclkdis0().write(|w| w.i2c1().dis()),
-            ToggleableModule::CNN => self.gcr.pclkdis0().write(|w| w.cnn().dis()),
-            ToggleableModule::ADC => self.gcr.pclkdis0().write(|w| w.adc().dis()),
-            ToggleableModule::TMR3 => self.gcr.pclkdis0().write(|w| w.tmr3().dis()),
-            ToggleableModule::TMR2 => self.gcr.pclkdis0().write(|w| w.tmr2().dis()),
-            ToggleableModule::TMR1 => self.gcr.pclkdis0().write(|w| w.tmr1().dis()),
-            ToggleableModule::TMR0 => self.gcr.pclkdis0().write(|w| w.tmr0().dis()),
-            ToggleableModule::I2C0 => self.gcr.pclkdis0().write(|w| w.i2c0().dis()),
-            ToggleableModule::UART1 => self.gcr.pclkdis0().write(|w| w.uart1().dis()),
-            ToggleableModule::UART0 => self.gcr.pclkdis0().write(|w| w.uart0().dis()),
-            ToggleableModule::SPI1 => self.gcr.pclkdis0().write(|w| w.spi1().dis()),
-            ToggleableModule::DMA => self.gcr.pclkdis0().write(|w| w.dma().dis()),
-            ToggleableModule::GPIO1 => self.gcr.pclkdis0().write(|w| w.gpio1().dis()),
-            ToggleableModule::GPIO0 => self.gcr.pclkdis0().write(|w| w.gpio0().dis()),
-
-            ToggleableModule::CRC => self.gcr.pclkdis1().write(|w| w.crc().dis()),
-            ToggleableModule::OWM => self.gcr.pclkdis1().write(|w| w.owm().dis()),
-            ToggleableModule::SMPHR => self.gcr.pclkdis1().write(|w| w.smphr().dis()),
-            ToggleableModule::TRNG => self.gcr.pclkdis1().write(|w| w.trng().dis()),
-            ToggleableModule::UART2 => self.gcr.pclkdis1().write(|w| w.uart2().dis()),
-            ToggleableModule::WDT0 => self.gcr.pclkdis1().write(|w| w.wdt0().dis()),
-            ToggleableModule::I2C2 => self.gcr.pclkdis1().write(|w| w.i2c2().dis()),
-            ToggleableModule::I2S => self.gcr.pclkdis1().write(|w| w.i2s().dis()),
-            ToggleableModule::SPI0 => self.gcr.pclkdis1().write(|w| w.spi0().dis()),
-            ToggleableModule::AES => self.gcr.pclkdis1().write(|w| w.aes().dis()),
-            ToggleableModule::CPU1 => self.gcr.pclkdis1().write(|w| w.cpu1().dis()),
+            ToggleableModule::LPCOMP => self.lpgcr.pclkdis().modify(|_, w| w.lpcomp().dis()),
+            ToggleableModule::UART3 => self.lpgcr.pclkdis().modify(|_, w| w.uart3().dis()),
+            ToggleableModule::TMR5 => self.lpgcr.pclkdis().modify(|_, w| w.tmr5().dis()),
+            ToggleableModule::TMR4 => self.lpgcr.pclkdis().modify(|_, w| w.tmr4().dis()),
+            ToggleableModule::WDT1 => self.lpgcr.pclkdis().modify(|_, w| w.wdt1().dis()),
+            ToggleableModule::GPIO2 => self.lpgcr.pclkdis().modify(|_, w| w.gpio2().dis()),
+
+            ToggleableModule::PT => self.gcr.pclkdis0().modify(|_, w| w.pt().dis()),
+            ToggleableModule::I2C1 => self.gcr.pclkdis0().modify(|_, w| w.i2c1().dis()),
+            ToggleableModule::CNN => self.gcr.pclkdis0().modify(|_, w| w.cnn().dis()),
+            ToggleableModule::ADC => self.gcr.pclkdis0().modify(|_, w| w.adc().dis()),
+            ToggleableModule::TMR3 => self.gcr.pclkdis0().modify(|_, w| w.tmr3().dis()),
+            ToggleableModule::TMR2 => self.gcr.pclkdis0().modify(|_, w| w.tmr2().dis()),
+            ToggleableModule::TMR1 => self.gcr.pclkdis0().modify(|_, w| w.tmr1().dis()),
+            ToggleableModule::TMR0 => self.gcr.pclkdis0().modify(|_, w| w.tmr0().dis()),
+            ToggleableModule::I2C0 => self.gcr.pclkdis0().modify(|_, w| w.i2c0().dis()),
+            ToggleableModule::UART1 => self.gcr.pclkdis0().modify(|_, w| w.uart1().dis()),
+            ToggleableModule::UART0 => self.gcr.pclkdis0().modify(|_, w| w.uart0().dis()),
+            ToggleableModule::SPI1 => self.gcr.pclkdis0().modify(|_, w| w.spi1().dis()),
+            ToggleableModule::DMA => self.gcr.pclkdis0().modify(|_, w| w.dma().dis()),
+            ToggleableModule::GPIO1 => self.gcr.pclkdis0().modify(|_, w| w.gpio1().dis()),
+            ToggleableModule::GPIO0 => self.gcr.pclkdis0().modify(|_, w| w.gpio0().dis()),
+
+            ToggleableModule::CRC => self.gcr.pclkdis1().modify(|_, w| w.crc().dis()),
+            ToggleableModule::OWM => self.gcr.pclkdis1().modify(|_, w| w.owm().dis()),
+            ToggleableModule::SMPHR => self.gcr.pclkdis1().modify(|_, w| w.smphr().dis()),
+            ToggleableModule::TRNG => self.gcr.pclkdis1().modify(|_, w| w.trng().dis()),
+            ToggleableModule::UART2 => self.gcr.pclkdis1().modify(|_, w| w.uart2().dis()),
+            ToggleableModule::WDT0 => self.gcr.pclkdis1().modify(|_, w| w.wdt0().dis()),
+            ToggleableModule::I2C2 => self.gcr.pclkdis1().modify(|_, w| w.i2c2().dis()),
+            ToggleableModule::I2S => self.gcr.pclkdis1().modify(|_, w| w.i2s().dis()),
+            ToggleableModule::SPI0 => self.gcr.pclkdis1().modify(|_, w| w.spi0().dis()),
+            ToggleableModule::AES => self.gcr.pclkdis1().modify(|_, w| w.aes().dis()),
+            ToggleableModule::CPU1 => self.gcr.pclkdis1().modify(|_, w| w.cpu1().dis()),
         }
     }
 
     /// Reset the given module
     pub fn reset_toggleable(&self, module_input: ToggleableModule) {
         match module_input {
-            ToggleableModule::LPCOMP => self.lpgcr.rst().write(|w| w.lpcomp().bit(true)),
-            ToggleableModule::UART3 => self.lpgcr.rst().write(|w| w.uart3().bit(true)),
-            ToggleableModule::TMR5 => self.lpgcr.rst().write(|w| w.tmr5().bit(true)),
-            ToggleableModule::TMR4 => self.lpgcr.rst().write(|w| w.tmr4().bit(true)),
-            ToggleableModule::WDT1 => self.lpgcr.rst().write(|w| w.wdt1().bit(true)),
-            ToggleableModule::GPIO2 => self.lpgcr.rst().write(|w| w.gpio2().bit(true)),
-
-            ToggleableModule::PT => self.gcr.rst1().write(|w| w.pt().bit(true)),
-            ToggleableModule::I2C1 => self.gcr.rst1().write(|w| w.i2c1().bit(true)),
-            ToggleableModule::CNN => self.gcr.rst0().write(|w| w.cnn().bit(true)),
-            ToggleableModule::ADC => self.gcr.rst0().write(|w| w.adc().bit(true)),
-            ToggleableModule::TMR3 => self.gcr.rst0().write(|w| w.tmr3().bit(true)),
-            ToggleableModule::TMR2 => self.gcr.rst0().write(|w| w.tmr2().bit(true)),
-            ToggleableModule::TMR1 => self.gcr.rst0().write(|w| w.tmr1().bit(true)),
-            ToggleableModule::TMR0 => self.gcr.rst0().write(|w| w.tmr0().bit(true)),
-            ToggleableModule::I2C0 => self.gcr.rst0().write(|w| w.i2c0().bit(true)),
-            ToggleableModule::UART1 => self.gcr.rst0().write(|w| w.uart1().bit(true)),
-            ToggleableModule::UART0 => self.gcr.rst0().write(|w| w.uart0().bit(true)),
-            ToggleableModule::SPI1 => self.gcr.rst0().write(|w| w.spi1().bit(true)),
-            ToggleableModule::DMA => self.gcr.rst0().write(|w| w.dma().bit(true)),
-            ToggleableModule::GPIO1 => self.gcr.rst0().write(|w| w.gpio1().bit(true)),
-            ToggleableModule::GPIO0 => self.gcr.rst0().write(|w| w.gpio0().bit(true)),
-
-            ToggleableModule::CRC => self.gcr.rst1().write(|w| w.crc().bit(true)),
-            ToggleableModule::OWM => self.gcr.rst1().write(|w| w.owm().bit(true)),
-            ToggleableModule::SMPHR => self.gcr.rst1().write(|w| w.smphr().bit(true)),
-            ToggleableModule::TRNG => self.gcr.rst0().write(|w| w.trng().bit(true)),
-            ToggleableModule::UART2 => self.gcr.rst0().write(|w| w.uart2().bit(true)),
-            ToggleableModule::WDT0 => self.gcr.rst0().write(|w| w.wdt0().bit(true)),
-            ToggleableModule::I2C2 => self.gcr.rst1().write(|w| w.i2c2().bit(true)),
-            ToggleableModule::I2S => self.gcr.rst1().write(|w| w.i2s().bit(true)),
-            ToggleableModule::SPI0 => self.gcr.rst1().write(|w| w.spi0().bit(true)),
-            ToggleableModule::AES => self.gcr.rst1().write(|w| w.aes().bit(true)),
+            ToggleableModule::LPCOMP => self.lpgcr.rst().modify(|_, w| w.lpcomp().bit(true)),
+            ToggleableModule::UART3 => self.lpgcr.rst().modify(|_, w| w.uart3().bit(true)),
+            ToggleableModule::TMR5 => self.lpgcr.rst().modify(|_, w| w.tmr5().bit(true)),
+            ToggleableModule::TMR4 => self.lpgcr.rst().modify(|_, w| w.tmr4().bit(true)),
+            ToggleableModule::WDT1 => self.lpgcr.rst().modify(|_, w| w.wdt1().bit(true)),
+            ToggleableModule::GPIO2 => self.lpgcr.rst().modify(|_, w| w.gpio2().bit(true)),
+
+            ToggleableModule::PT => self.gcr.rst1().modify(|_, w| w.pt().bit(true)),
+            ToggleableModule::I2C1 => self.gcr.rst1().modify(|_, w| w.i2c1().bit(true)),
+            ToggleableModule::CNN => self.gcr.rst0().modify(|_, w| w.cnn().bit(true)),
+            ToggleableModule::ADC => self.gcr.rst0().modify(|_, w| w.adc().bit(true)),
+            ToggleableModule::TMR3 => self.gcr.rst0().modify(|_, w| w.tmr3().bit(true)),
+            ToggleableModule::TMR2 => self.gcr.rst0().modify(|_, w| w.tmr2().bit(true)),
+            ToggleableModule::TMR1 => self.gcr.rst0().modify(|_, w| w.tmr1().bit(true)),
+            ToggleableModule::TMR0 => self.gcr.rst0().modify(|_, w| w.tmr0().bit(true)),
+            ToggleableModule::I2C0 => self.gcr.rst0().modify(|_, w| w.i2c0().bit(true)),
+            ToggleableModule::UART1 => self.gcr.rst0().modify(|_, w| w.uart1().bit(true)),
+            ToggleableModule::UART0 => self.gcr.rst0().modify(|_, w| w.uart0().bit(true)),
+            ToggleableModule::SPI1 => self.gcr.rst0().modify(|_, w| w.spi1().bit(true)),
+            ToggleableModule::DMA => self.gcr.rst0().modify(|_, w| w.dma().bit(true)),
+            ToggleableModule::GPIO1 => self.gcr.rst0().modify(|_, w| w.gpio1().bit(true)),
+            ToggleableModule::GPIO0 => self.gcr.rst0().modify(|_, w| w.gpio0().bit(true)),
+
+            ToggleableModule::CRC => self.gcr.rst1().modify(|_, w| w.crc().bit(true)),
+            ToggleableModule::OWM => self.gcr.rst1().modify(|_, w| w.owm().bit(true)),
+            ToggleableModule::SMPHR => self.gcr.rst1().modify(|_, w| w.smphr().bit(true)),
+            ToggleableModule::TRNG => self.gcr.rst0().modify(|_, w| w.trng().bit(true)),
+            ToggleableModule::UART2 => self.gcr.rst0().modify(|_, w| w.uart2().bit(true)),
+            ToggleableModule::WDT0 => self.gcr.rst0().modify(|_, w| w.wdt0().bit(true)),
+            ToggleableModule::I2C2 => self.gcr.rst1().modify(|_, w| w.i2c2().bit(true)),
+            ToggleableModule::I2S => self.gcr.rst1().modify(|_, w| w.i2s().bit(true)),
+            ToggleableModule::SPI0 => self.gcr.rst1().modify(|_, w| w.spi0().bit(true)),
+            ToggleableModule::AES => self.gcr.rst1().modify(|_, w| w.aes().bit(true)),
+            ToggleableModule::CPU1 => todo!("CPU1 reset not implemented due to inconsistent documentation, see slugSecurity/max78000#11"),
+        }
+    }
+
+    /// Returns whether the given module's reset bit is still asserted, i.e. the
+    /// reset triggered by [`Self::reset_toggleable`] hasn't self-cleared yet.
+    fn reset_pending(&self, module_input: ToggleableModule) -> bool {
+        match module_input {
+            ToggleableModule::LPCOMP => self.lpgcr.rst().read().lpcomp().bit_is_set(),
+            ToggleableModule::UART3 => self.lpgcr.rst().read().uart3().bit_is_set(),
+            ToggleableModule::TMR5 => self.lpgcr.rst().read().tmr5().bit_is_set(),
+            ToggleableModule::TMR4 => self.lpgcr.rst().read().tmr4().bit_is_set(),
+            ToggleableModule::WDT1 => self.lpgcr.rst().read().wdt1().bit_is_set(),
+            ToggleableModule::GPIO2 => self.lpgcr.rst().read().gpio2().bit_is_set(),
+
+            ToggleableModule::PT => self.gcr.rst1().read().pt().bit_is_set(),
+            ToggleableModule::I2C1 => self.gcr.rst1().read().i2c1().bit_is_set(),
+            ToggleableModule::CNN => self.gcr.rst0().read().cnn().bit_is_set(),
+            ToggleableModule::ADC => self.gcr.rst0().read().adc().bit_is_set(),
+            ToggleableModule::TMR3 => self.gcr.rst0().read().tmr3().bit_is_set(),
+            ToggleableModule::TMR2 => self.gcr.rst0().read().tmr2().bit_is_set(),
+            ToggleableModule::TMR1 => self.gcr.rst0().read().tmr1().bit_is_set(),
+            ToggleableModule::TMR0 => self.gcr.rst0().read().tmr0().bit_is_set(),
+            ToggleableModule::I2C0 => self.gcr.rst0().read().i2c0().bit_is_set(),
+            ToggleableModule::UART1 => self.gcr.rst0().read().uart1().bit_is_set(),
+            ToggleableModule::UART0 => self.gcr.rst0().read().uart0().bit_is_set(),
+            ToggleableModule::SPI1 => self.gcr.rst0().read().spi1().bit_is_set(),
+            ToggleableModule::DMA => self.gcr.rst0().read().dma().bit_is_set(),
+            ToggleableModule::GPIO1 => self.gcr.rst0().read().gpio1().bit_is_set(),
+            ToggleableModule::GPIO0 => self.gcr.rst0().read().gpio0().bit_is_set(),
+
+            ToggleableModule::CRC => self.gcr.rst1().read().crc().bit_is_set(),
+            ToggleableModule::OWM => self.gcr.rst1().read().owm().bit_is_set(),
+            ToggleableModule::SMPHR => self.gcr.rst1().read().smphr().bit_is_set(),
+            ToggleableModule::TRNG => self.gcr.rst0().read().trng().bit_is_set(),
+            ToggleableModule::UART2 => self.gcr.rst0().read().uart2().bit_is_set(),
+            ToggleableModule::WDT0 => self.gcr.rst0().read().wdt0().bit_is_set(),
+            ToggleableModule::I2C2 => self.gcr.rst1().read().i2c2().bit_is_set(),
+            ToggleableModule::I2S => self.gcr.rst1().read().i2s().bit_is_set(),
+            ToggleableModule::SPI0 => self.gcr.rst1().read().spi0().bit_is_set(),
+            ToggleableModule::AES => self.gcr.rst1().read().aes().bit_is_set(),
             ToggleableModule::CPU1 => todo!("CPU1 reset not implemented due to inconsistent documentation, see slugSecurity/max78000#11"),
         }
     }
 
+    /// Enables the given module's clock, resets it, and waits for the reset
+    /// bit to self-clear, all inside a single critical section.
+    ///
+    /// [`Self::enable_peripheral`] and [`Self::reset_toggleable`] are separate
+    /// read-modify-write sequences on the shared GCR/LPGCR registers; calling
+    /// them back to back leaves a window where an interrupt handler or another
+    /// [`crate::peripherals::PeripheralManager`] accessor can run between the
+    /// two writes and either observe the peripheral clock-enabled but not yet
+    /// reset, or tear the RMW on a register another module also writes. This
+    /// combines both steps (and the wait for completion) into one atomic
+    /// operation, so callers no longer have to reason about the ordering.
+    pub fn enable_and_reset(&self, module_input: ToggleableModule) {
+        critical_section::with(|_| {
+            self.enable_peripheral(module_input);
+            self.reset_toggleable(module_input);
+            while self.reset_pending(module_input) {}
+        });
+    }
+
     /// Reset a module that cannot be enabled or disabled
     pub fn reset_non_toggleable(&self, module_input: NonToggleableModule) {
         match module_input {
-            NonToggleableModule::SYS => self.gcr.rst0().write(|w| w.sys().bit(true)),
-            NonToggleableModule::PERIPH => self.gcr.rst0().write(|w| w.periph().bit(true)),
-            NonToggleableModule::SOFT => self.gcr.rst0().write(|w| w.soft().bit(true)),
-            NonToggleableModule::RTC => self.gcr.rst0().write(|w| w.rtc().bit(true)),
-            NonToggleableModule::SIMO => self.gcr.rst1().write(|w| w.simo().bit(true)),
-            NonToggleableModule::DVS => self.gcr.rst1().write(|w| w.dvs().bit(true)),
+            NonToggleableModule::SYS => self.gcr.rst0().modify(|_, w| w.sys().bit(true)),
+            NonToggleableModule::PERIPH => self.gcr.rst0().modify(|_, w| w.periph().bit(true)),
+            NonToggleableModule::SOFT => self.gcr.rst0().modify(|_, w| w.soft().bit(true)),
+            NonToggleableModule::RTC => self.gcr.rst0().modify(|_, w| w.rtc().bit(true)),
+            NonToggleableModule::SIMO => self.gcr.rst1().modify(|_, w| w.simo().bit(true)),
+            NonToggleableModule::DVS => self.gcr.rst1().modify(|_, w| w.dvs().bit(true)),
+        }
+    }
+
+    /// Enables `module`'s clock and returns a guard that disables it again
+    /// once dropped. Drivers that only need a peripheral clock for the
+    /// duration of a scope can use this instead of pairing up their own
+    /// [`Self::enable_peripheral`]/[`Self::disable_peripheral`] calls, so the
+    /// clock can't be left gated on by a forgotten disable or an early
+    /// return.
+    pub fn enable_guarded(&self, module: ToggleableModule) -> PeripheralClockGuard<'_, 'r, 'l> {
+        self.enable_peripheral(module);
+        PeripheralClockGuard {
+            power: self,
+            module,
         }
     }
 }
+
+/// RAII guard returned by [`PowerControl::enable_guarded`]. Disables the
+/// guarded peripheral's clock when dropped, mirroring the clock-gating guards
+/// atsamd's `GenericClockController` hands out.
+pub struct PeripheralClockGuard<'p, 'r, 'l> {
+    power: &'p PowerControl<'r, 'l>,
+    module: ToggleableModule,
+}
+
+impl Drop for PeripheralClockGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.power.disable_peripheral(self.module);
+    }
+}
@@ -1,6 +1,7 @@
 //! CSPRNG (cryptographically-secure pseudorandom number generator) abstraction API.
 
 mod entropy;
+mod hash_drbg;
 
 use max78000::TMR;
 use rand_chacha::{
@@ -11,17 +12,60 @@ use rand_chacha::{
 use crate::communication::lower_layers::crypto::RandomSource;
 
 use self::entropy::{ClockDrift, EntropyHasher, Secret, TrngEntropy};
+pub(crate) use self::hash_drbg::HashDrbg;
 
-use super::{timer::Clock, trng::Trng};
+use super::{
+    timer::{Clock, Time, Timer, TimerMode},
+    trng::Trng,
+};
 
 /// The size of the static secret in bytes.
 pub const SECRET_SIZE: usize = 32;
 
+/// How often a [`ReseedingCsprng`] re-draws entropy and rekeys its
+/// `ChaCha20Rng`, bounding how much output is ever produced from a single
+/// seed (the Fortuna/DRBG practice), which matters since this device's
+/// initial boot-time entropy may be weak.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReseedInterval {
+    /// Reseed once this many bytes have been produced by
+    /// [`RandomSource::fill_rand_slice`] since the last reseed.
+    pub max_bytes: u64,
+    /// Reseed once this many [`Clock`] ticks have elapsed since the last
+    /// reseed, regardless of how many bytes have been produced.
+    pub max_ticks: u32,
+}
+
+impl Default for ReseedInterval {
+    /// Effectively "never": [`EntropyGatherer::init_csprng`] ignores this
+    /// field entirely, so this is only a sensible default for call sites
+    /// that don't wrap the result in a [`ReseedingCsprng`].
+    fn default() -> Self {
+        Self {
+            max_bytes: u64::MAX,
+            max_ticks: u32::MAX,
+        }
+    }
+}
+
 /// CSPRNG initialization arguments.
 pub(crate) struct CsprngInitArgs<'a, 'b, 'c, F: FnMut(&mut [u8])> {
     pub trng: &'a Trng,
     pub csprng_timer: &'b Clock<'c, TMR>,
     pub get_rng_static_secret: F,
+    /// Consulted only by [`ReseedingCsprng`]; ignored by
+    /// [`EntropyGatherer::init_csprng`] itself.
+    pub reseed_interval: ReseedInterval,
+}
+
+impl<'a, 'b, 'c, F: FnMut(&mut [u8])> CsprngInitArgs<'a, 'b, 'c, F> {
+    /// Sets how many bytes [`ReseedingCsprng`] may produce before it
+    /// re-draws entropy and rekeys; ignored by [`EntropyGatherer::init_csprng`]
+    /// itself, which only ever seeds once.
+    pub(crate) fn reseed_after(mut self, bytes: usize) -> Self {
+        self.reseed_interval.max_bytes = bytes as u64;
+        self
+    }
 }
 
 /// Entropy gatherer.
@@ -43,3 +87,101 @@ impl RandomSource for ChaCha20Rng {
         self.fill_bytes(slice_ref.as_mut());
     }
 }
+
+impl<T: entropy::EntropySource, F: FnMut(&mut [u8]) + Copy> RandomSource
+    for HashDrbg<'_, '_, '_, T, F>
+{
+    fn fill_rand_slice<S: AsMut<[u8]>>(&mut self, mut slice_ref: S) {
+        self.fill_bytes(slice_ref.as_mut());
+    }
+}
+
+/// Wraps a `ChaCha20Rng`, periodically re-drawing TRNG + clock-drift entropy
+/// through the existing [`EntropyHasher`] pipeline and rekeying the
+/// generator once `reseed_interval` bytes have been produced or that many
+/// [`Clock`] ticks have elapsed, whichever comes first. This bounds how much
+/// keystream is ever produced from a single seed, following Fortuna/DRBG
+/// practice, which matters for a long-running secure device whose initial
+/// boot-time entropy may be weak.
+///
+/// `F` is required to be [`Copy`] (in practice, a plain `fn` pointer) since
+/// the same secret-fetching callback is invoked again on every reseed.
+pub(crate) struct ReseedingCsprng<'a, 'b, 'c, F: FnMut(&mut [u8]) + Copy> {
+    rng: ChaCha20Rng,
+    trng: &'a Trng,
+    csprng_timer: &'b Clock<'c, TMR>,
+    get_rng_static_secret: F,
+    reseed_interval: ReseedInterval,
+    bytes_since_reseed: u64,
+    reseed_timer: Timer<'b, 'c, TMR>,
+}
+
+impl<'a, 'b, 'c, F: FnMut(&mut [u8]) + Copy> ReseedingCsprng<'a, 'b, 'c, F> {
+    /// Seeds the CSPRNG the same way [`EntropyGatherer::init_csprng`] does,
+    /// and starts counting bytes/ticks towards the next reseed from
+    /// `csprng_init_args.reseed_interval`.
+    pub(crate) fn new(csprng_init_args: CsprngInitArgs<'a, 'b, 'c, F>) -> Self {
+        let CsprngInitArgs {
+            trng,
+            csprng_timer,
+            get_rng_static_secret,
+            reseed_interval,
+        } = csprng_init_args;
+
+        let rng = EntropyGatherer::init_csprng(CsprngInitArgs {
+            trng,
+            csprng_timer,
+            get_rng_static_secret,
+            reseed_interval,
+        });
+
+        ReseedingCsprng {
+            rng,
+            trng,
+            csprng_timer,
+            get_rng_static_secret,
+            reseed_interval,
+            bytes_since_reseed: 0,
+            reseed_timer: csprng_timer
+                .new_timer_with_mode(Time::Ticks(reseed_interval.max_ticks), TimerMode::Periodic),
+        }
+    }
+
+    /// Re-draws fresh entropy and rekeys [`Self::rng`], resetting the
+    /// byte/tick counters towards the next reseed. The previous draw's raw
+    /// entropy is already zeroized by the `EntropySource` chain's `Drop`
+    /// impls (see `TrngEntropy`, `ClockDrift`, `Secret`) by the time this
+    /// returns, so no separate cleanup is needed here.
+    fn reseed(&mut self) {
+        self.rng = EntropyGatherer::init_csprng(CsprngInitArgs {
+            trng: self.trng,
+            csprng_timer: self.csprng_timer,
+            get_rng_static_secret: self.get_rng_static_secret,
+            reseed_interval: self.reseed_interval,
+        });
+        self.bytes_since_reseed = 0;
+        self.reseed_timer.reset();
+    }
+}
+
+impl<F: FnMut(&mut [u8]) + Copy> RandomSource for ReseedingCsprng<'_, '_, '_, F> {
+    fn fill_rand_slice<T: AsMut<[u8]>>(&mut self, mut slice_ref: T) {
+        let mut slice = slice_ref.as_mut();
+
+        loop {
+            if self.bytes_since_reseed >= self.reseed_interval.max_bytes || self.reseed_timer.poll() {
+                self.reseed();
+            }
+
+            let budget = (self.reseed_interval.max_bytes - self.bytes_since_reseed).max(1);
+            let take = slice.len().min(usize::try_from(budget).unwrap_or(usize::MAX));
+            let (now, rest) = core::mem::take(&mut slice).split_at_mut(take);
+            self.rng.fill_rand_slice(now);
+            self.bytes_since_reseed += take as u64;
+            slice = rest;
+            if slice.is_empty() {
+                break;
+            }
+        }
+    }
+}
@@ -12,6 +12,85 @@ const MS_TO_COUNT: u32 = 4;
 /// The number of bytes to get from clock drift.
 const CLOCK_DRIFT_ENTROPY_SIZE: usize = 24;
 
+/// Repetition Count Test cutoff: the number of consecutive identical raw
+/// samples that trips the test. SP 800-90B's formula for a false-positive
+/// rate of `2^-20` under a conservative 1-bit-min-entropy assumption is
+/// `1 + ceil(20 / H)`, i.e. 21; rounded up here for margin.
+const RCT_CUTOFF: u32 = 26;
+
+/// Adaptive Proportion Test window size, in raw samples. SP 800-90B allows
+/// 1024 or 4096; the smaller window is used so a failing oscillator is
+/// caught sooner.
+const APT_WINDOW: usize = 1024;
+
+/// Adaptive Proportion Test cutoff for [`APT_WINDOW`]: the count of
+/// occurrences of the window's reference value above which the test fails.
+/// Conservatively derived assuming only 1 bit of min-entropy per raw
+/// sample, same as [`RCT_CUTOFF`].
+const APT_CUTOFF: usize = 890;
+
+/// Upper bound on raw samples drawn for a single collection attempt before
+/// giving up and restarting health-test bookkeeping from scratch. Von
+/// Neumann debiasing discards roughly half of all sampled pairs, so this is
+/// generous headroom over the number of raw samples a healthy source needs.
+const MAX_RAW_SAMPLES: usize = CLOCK_DRIFT_ENTROPY_SIZE * 8 * 64;
+
+/// Online implementations of the two SP 800-90B startup/continuous health
+/// tests, run over the *raw* (pre-debiasing) samples so a misbehaving
+/// source is caught before any of its output reaches the SHA3 pool.
+struct HealthTests {
+    /// Value of the current repetition run, and how long it's lasted.
+    rct_run: Option<(bool, u32)>,
+    /// Reference value for the current Adaptive Proportion Test window,
+    /// how many samples in the window have matched it, and how far into
+    /// the window we are.
+    apt_window: Option<(bool, usize)>,
+    apt_pos: usize,
+}
+
+impl HealthTests {
+    fn new() -> Self {
+        HealthTests {
+            rct_run: None,
+            apt_window: None,
+            apt_pos: 0,
+        }
+    }
+
+    /// Feeds one raw sample through both tests. Returns `false` if either
+    /// test fails, in which case the caller must discard everything
+    /// sampled so far and start over.
+    fn check(&mut self, sample: bool) -> bool {
+        match &mut self.rct_run {
+            Some((value, run)) if *value == sample => {
+                *run += 1;
+                if *run >= RCT_CUTOFF {
+                    return false;
+                }
+            }
+            _ => self.rct_run = Some((sample, 1)),
+        }
+
+        if self.apt_pos == 0 {
+            self.apt_window = Some((sample, 1));
+        } else if let Some((reference, count)) = &mut self.apt_window {
+            if *reference == sample {
+                *count += 1;
+                if *count > APT_CUTOFF {
+                    return false;
+                }
+            }
+        }
+
+        self.apt_pos += 1;
+        if self.apt_pos >= APT_WINDOW {
+            self.apt_pos = 0;
+        }
+
+        true
+    }
+}
+
 /// Clock drift entropy source.
 ///
 /// IMPORTANT: This struct should not be moved to ensure the entropy gets zeroed out on drop.
@@ -23,22 +102,56 @@ pub(crate) struct ClockDrift<T: EntropySource> {
 impl<T: EntropySource> EntropySource for ClockDrift<T> {
     fn init<F: FnMut(&mut [u8])>(csprng_init_args: CsprngInitArgs<F>) -> Self {
         let mut entropy_pool = [0; CLOCK_DRIFT_ENTROPY_SIZE];
+        let total_bits = CLOCK_DRIFT_ENTROPY_SIZE * 8;
 
-        for mut bit in entropy_pool.as_mut_bits::<Lsb0>() {
-            // Initialize timer.
+        // Draws one raw entropy bit: the LSB of a free-running counter
+        // incremented for MS_TO_COUNT ms, biased but cheap to sample.
+        let sample_raw_bit = || -> bool {
             let mut clock_drift_timer = csprng_init_args
                 .csprng_timer
                 .new_timer(Time::Milliseconds(MS_TO_COUNT));
 
-            // Wait for timer to reach MS_TO_COUNT ms and count.
             let mut counter: u32 = 0;
-
             while !clock_drift_timer.poll() {
                 counter += 1;
             }
 
-            // Set bit to 1 if counter LSB is 1.
-            bit.set((counter & 1) == 1);
+            (counter & 1) == 1
+        };
+
+        'collect: loop {
+            let mut health = HealthTests::new();
+            let mut filled = 0;
+            let mut raw_samples = 0;
+
+            while filled < total_bits {
+                if raw_samples >= MAX_RAW_SAMPLES {
+                    continue 'collect;
+                }
+
+                // Von Neumann extractor: sample bits in pairs, emitting `0`
+                // for `01`, `1` for `10`, and discarding `00`/`11` pairs.
+                // This removes first-order bias at the cost of a variable
+                // number of raw samples per debiased bit.
+                let b1 = sample_raw_bit();
+                raw_samples += 1;
+                if !health.check(b1) {
+                    continue 'collect;
+                }
+
+                let b2 = sample_raw_bit();
+                raw_samples += 1;
+                if !health.check(b2) {
+                    continue 'collect;
+                }
+
+                if b1 != b2 {
+                    entropy_pool.as_mut_bits::<Lsb0>().set(filled, b1);
+                    filled += 1;
+                }
+            }
+
+            break;
         }
 
         ClockDrift {
@@ -0,0 +1,220 @@
+//! NIST SP 800-90A Hash_DRBG, instantiated with SHA3-256, layered over
+//! [`entropy::EntropyHasher`](super::entropy::EntropyHasher) so callers can
+//! draw a continuous keystream instead of a single one-shot digest.
+
+use rand_core::{CryptoRng, RngCore};
+use sha3::{Digest, Sha3_256};
+
+use max78000::TMR;
+
+use super::entropy::{EntropyHasher, EntropySource};
+use super::{CsprngInitArgs, ReseedInterval};
+use crate::peripherals::{timer::Clock, trng::Trng};
+
+/// Hash_DRBG's `seedlen` for a 256-bit-output hash function, in bits (SP
+/// 800-90A table 2).
+const SEEDLEN_BITS: u32 = 440;
+/// [`SEEDLEN_BITS`] in bytes.
+const SEEDLEN_BYTES: usize = (SEEDLEN_BITS / 8) as usize;
+
+/// How many [`HashDrbg::generate`] calls (`reseed_counter` increments) are
+/// allowed from one seed before the next call reseeds first. SP 800-90A
+/// permits up to 2^48 for Hash_DRBG; this is set far lower since reseeding
+/// here only costs an on-chip TRNG draw plus clock-jitter sampling, not a
+/// call to an external entropy source, so a conservative bound is cheap.
+const RESEED_COUNTER_LIMIT: u64 = 1 << 20;
+
+/// `Hash_df(input, SEEDLEN_BITS)`: stretches/compresses the concatenation of
+/// `input`'s parts to exactly [`SEEDLEN_BYTES`] by concatenating
+/// `Hash(counter || SEEDLEN_BITS || input)` for `counter = 1, 2, ...` until
+/// enough output has been produced.
+fn hash_df(input: &[&[u8]]) -> [u8; SEEDLEN_BYTES] {
+    let mut out = [0u8; SEEDLEN_BYTES];
+    let mut counter: u8 = 1;
+    let mut filled = 0;
+
+    while filled < SEEDLEN_BYTES {
+        let mut hasher = Sha3_256::new();
+        hasher.update([counter]);
+        hasher.update(SEEDLEN_BITS.to_be_bytes());
+        for part in input {
+            hasher.update(part);
+        }
+        let digest = hasher.finalize();
+
+        let take = (SEEDLEN_BYTES - filled).min(digest.len());
+        out[filled..filled + take].copy_from_slice(&digest[..take]);
+        filled += take;
+        counter += 1;
+    }
+
+    out
+}
+
+/// Adds `addend` (big-endian, right-aligned against `v`'s low-order end)
+/// into `v` modulo `2^SEEDLEN_BITS`, i.e. ordinary big-endian addition with
+/// any final carry out of the top byte discarded.
+fn add_mod_seedlen(v: &mut [u8; SEEDLEN_BYTES], addend: &[u8]) {
+    let mut carry = 0u16;
+    let mut vi = SEEDLEN_BYTES;
+    let mut ai = addend.len();
+
+    while vi > 0 {
+        vi -= 1;
+        let a = if ai > 0 {
+            ai -= 1;
+            addend[ai] as u16
+        } else {
+            0
+        };
+        let sum = v[vi] as u16 + a + carry;
+        v[vi] = sum as u8;
+        carry = sum >> 8;
+    }
+}
+
+fn increment_mod_seedlen(v: &mut [u8; SEEDLEN_BYTES]) {
+    add_mod_seedlen(v, &[1]);
+}
+
+/// Generates `out.len()` pseudorandom bytes from `v` via Hashgen, per SP
+/// 800-90A: `data = v`; repeatedly `W = Hash(data)`, append `W`, `data += 1`.
+/// Does not touch `v` itself -- the DRBG's `V` update after generation is a
+/// separate step, done by the caller.
+fn hashgen(v: &[u8; SEEDLEN_BYTES], out: &mut [u8]) {
+    let mut data = *v;
+    let mut filled = 0;
+
+    while filled < out.len() {
+        let w = Sha3_256::digest(data);
+        let take = (out.len() - filled).min(w.len());
+        out[filled..filled + take].copy_from_slice(&w[..take]);
+        filled += take;
+        increment_mod_seedlen(&mut data);
+    }
+}
+
+/// NIST SP 800-90A Hash_DRBG, instantiated with SHA3-256 (every `Hash()` in
+/// the algorithm below is SHA3-256; `seedlen = 440` bits per the SP 800-90A
+/// table for a 256-bit-output hash). Implements [`RngCore`]/[`CryptoRng`] so
+/// it can be used anywhere a `rand_core`-based RNG is expected, continuously
+/// stretching a seed instead of producing a single digest the way
+/// [`EntropyHasher`] does on its own.
+///
+/// Both instantiation and reseeding draw entropy by constructing a fresh
+/// `EntropyHasher<T>` (re-running the whole `T: EntropySource` chain from
+/// scratch, so a reseed pulls genuinely new TRNG/clock-drift samples rather
+/// than rehashing stale state) and feeding its digest through [`hash_df`].
+/// The `nonce`/`personalization_string` inputs SP 800-90A allows are both
+/// empty here -- the entropy input is already a full-strength 256-bit digest
+/// of hardware sources.
+pub(crate) struct HashDrbg<'a, 'b, 'c, T: EntropySource, F: FnMut(&mut [u8]) + Copy> {
+    v: [u8; SEEDLEN_BYTES],
+    c: [u8; SEEDLEN_BYTES],
+    reseed_counter: u64,
+    trng: &'a Trng,
+    csprng_timer: &'b Clock<'c, TMR>,
+    get_rng_static_secret: F,
+    _entropy: core::marker::PhantomData<T>,
+}
+
+impl<'a, 'b, 'c, T: EntropySource, F: FnMut(&mut [u8]) + Copy> HashDrbg<'a, 'b, 'c, T, F> {
+    /// Instantiates the DRBG, drawing its first seed from `csprng_init_args`
+    /// the same way [`super::EntropyGatherer::init_csprng`] does.
+    pub(crate) fn new(csprng_init_args: CsprngInitArgs<'a, 'b, 'c, F>) -> Self {
+        let CsprngInitArgs {
+            trng,
+            csprng_timer,
+            get_rng_static_secret,
+            reseed_interval: _,
+        } = csprng_init_args;
+
+        let mut drbg = HashDrbg {
+            v: [0; SEEDLEN_BYTES],
+            c: [0; SEEDLEN_BYTES],
+            reseed_counter: 1,
+            trng,
+            csprng_timer,
+            get_rng_static_secret,
+            _entropy: core::marker::PhantomData,
+        };
+        drbg.instantiate();
+        drbg
+    }
+
+    /// Draws a fresh 256-bit entropy input by running the `T: EntropySource`
+    /// chain from scratch through a new [`EntropyHasher`].
+    fn fresh_entropy_input(&mut self) -> [u8; 32] {
+        EntropyHasher::<T>::new(CsprngInitArgs {
+            trng: self.trng,
+            csprng_timer: self.csprng_timer,
+            get_rng_static_secret: self.get_rng_static_secret,
+            reseed_interval: ReseedInterval::default(),
+        })
+        .hash()
+    }
+
+    fn instantiate(&mut self) {
+        let entropy_input = self.fresh_entropy_input();
+        self.v = hash_df(&[&entropy_input]);
+        self.c = hash_df(&[&[0x00], &self.v]);
+        self.reseed_counter = 1;
+    }
+
+    /// `V = Hash_df(0x01 || V || entropy_input, seedlen)`, recomputing `C`
+    /// and resetting the counter, per SP 800-90A's Hash_DRBG reseed.
+    fn reseed(&mut self) {
+        let entropy_input = self.fresh_entropy_input();
+        self.v = hash_df(&[&[0x01], &self.v, &entropy_input]);
+        self.c = hash_df(&[&[0x00], &self.v]);
+        self.reseed_counter = 1;
+    }
+
+    /// Fills `out` via Hashgen, then advances `V` and `reseed_counter` per
+    /// SP 800-90A's Hash_DRBG generate, reseeding first if
+    /// [`RESEED_COUNTER_LIMIT`] has been reached.
+    fn generate(&mut self, out: &mut [u8]) {
+        if self.reseed_counter > RESEED_COUNTER_LIMIT {
+            self.reseed();
+        }
+
+        hashgen(&self.v, out);
+
+        let mut prefixed_v = [0u8; 1 + SEEDLEN_BYTES];
+        prefixed_v[0] = 0x03;
+        prefixed_v[1..].copy_from_slice(&self.v);
+        let h = Sha3_256::digest(prefixed_v);
+
+        add_mod_seedlen(&mut self.v, &h);
+        add_mod_seedlen(&mut self.v, &self.c);
+        add_mod_seedlen(&mut self.v, &self.reseed_counter.to_be_bytes());
+        self.reseed_counter += 1;
+    }
+}
+
+impl<T: EntropySource, F: FnMut(&mut [u8]) + Copy> RngCore for HashDrbg<'_, '_, '_, T, F> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.generate(&mut buf);
+        u32::from_ne_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.generate(&mut buf);
+        u64::from_ne_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.generate(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// A Hash_DRBG reseeded from on-chip hardware entropy sources is suitable
+/// for cryptographic use, same as [`Trng`]'s `CryptoRng` impl.
+impl<T: EntropySource, F: FnMut(&mut [u8]) + Copy> CryptoRng for HashDrbg<'_, '_, '_, T, F> {}
@@ -0,0 +1,241 @@
+//! Real-time clock (RTC) driver, built on the always-on `RTC_SEC`/`RTC_SSEC`
+//! counters.
+//!
+//! The RTC runs in its own clock domain, so writes to `RTC_SEC`/`RTC_SSEC`
+//! take effect asynchronously: software has to set `RTC_CTRL.wte` (write
+//! time enable), wait for `RTC_CTRL.rdy` to go high, write the counters,
+//! then clear `wte` again. [`Rtc::ready`] polls that `rdy` bit through the
+//! [`bit_band!`](crate::bit_band) handle from
+//! [`bit_banding`](crate::peripherals::bit_banding) instead of a
+//! read-modify-write loop. With the `chrono` feature enabled,
+//! [`Rtc::now_datetime`]/[`Rtc::set_now`] give a
+//! [`chrono::DateTime<chrono::Utc>`] view onto the same counters, treating
+//! `RTC_SEC` as a Unix timestamp.
+//!
+//! [`Rtc::set_alarm`]/[`Rtc::enable_alarm`] program a time-of-day alarm
+//! against `RTC_RAS`, and [`InterruptRtc`] parks the core on it with `wfi`
+//! so callers can sleep until a deadline instead of polling
+//! [`Rtc::alarm_pending`] in a busy loop.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use max78000::RTC;
+
+use crate::bit_band;
+
+/// Number of `RTC_SSEC` ticks per second.
+const SSEC_TICKS_PER_SEC: u32 = 256;
+
+/// Number of ticks [`Rtc::now`] reports sub-second time in. Finer than the
+/// `1/256`s [`Rtc::sub_second_ticks`] the hardware counter itself runs at,
+/// matching the sub-tick precision downstream consumers (CSPRNG seeding,
+/// CommStack timestamps) expect from a monotonic clock source.
+const SUBSEC_TICKS_PER_SEC: u32 = 4096;
+
+/// Error returned by [`Rtc`] operations that write the time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RtcError {
+    /// `RTC_CTRL.rdy` never went high after `RTC_CTRL.wte` was set, so the
+    /// write-enable handshake couldn't complete.
+    NotReady,
+}
+
+/// Real-time clock, counting whole seconds since an arbitrary epoch in
+/// `RTC_SEC` plus `1/256`s ticks in `RTC_SSEC`.
+pub struct Rtc {
+    regs: RTC,
+}
+
+impl Rtc {
+    /// Wraps `regs`. Does not enable the RTC counter; call [`Self::enable`]
+    /// first if it isn't already running.
+    pub fn new(regs: RTC) -> Self {
+        Self { regs }
+    }
+
+    /// Enables the RTC counter.
+    pub fn enable(&mut self) {
+        self.regs.ctrl().modify(|_, w| w.en().set_bit());
+    }
+
+    /// Disables the RTC counter.
+    pub fn disable(&mut self) {
+        self.regs.ctrl().modify(|_, w| w.en().clear_bit());
+    }
+
+    /// Whether `RTC_CTRL.rdy` is set, i.e. the counters are safe to write.
+    fn ready(&self) -> bool {
+        bit_band!(self.regs.ctrl(), rdy, 4).read()
+    }
+
+    /// Runs `f`, which should write `RTC_SEC`/`RTC_SSEC`, inside the
+    /// write-enable handshake.
+    fn with_write_enabled(&mut self, f: impl FnOnce(&mut Self)) -> Result<(), RtcError> {
+        /// How many times `rdy` is polled before giving up with `NotReady`.
+        const RDY_POLL_LIMIT: u32 = 1_000_000;
+
+        self.regs.ctrl().modify(|_, w| w.wte().set_bit());
+
+        let mut polls = 0;
+        while !self.ready() {
+            polls += 1;
+            if polls >= RDY_POLL_LIMIT {
+                self.regs.ctrl().modify(|_, w| w.wte().clear_bit());
+                return Err(RtcError::NotReady);
+            }
+        }
+
+        f(self);
+
+        self.regs.ctrl().modify(|_, w| w.wte().clear_bit());
+        Ok(())
+    }
+
+    /// Whole seconds elapsed since the RTC's epoch.
+    pub fn seconds(&self) -> u32 {
+        self.regs.sec().read().bits()
+    }
+
+    /// `1/256`s ticks past [`Self::seconds`].
+    pub fn sub_second_ticks(&self) -> u8 {
+        self.regs.ssec().read().bits() as u8
+    }
+
+    /// Sets the whole-seconds counter, leaving the sub-second counter
+    /// running from wherever it was.
+    pub fn set_seconds(&mut self, seconds: u32) -> Result<(), RtcError> {
+        self.with_write_enabled(|rtc| {
+            // SAFETY: `RTC_SEC` is a plain 32-bit counter; every value is valid.
+            rtc.regs.sec().write(|w| unsafe { w.bits(seconds) });
+        })
+    }
+
+    /// Monotonic wall-clock time, as whole seconds plus a `1/4096`s
+    /// sub-second count. `RTC_SEC`/`RTC_SSEC` tick in the RTC's own
+    /// asynchronous clock domain, so a read can land exactly as `RTC_SSEC`
+    /// wraps back to `0` without `RTC_SEC` having incremented yet (or vice
+    /// versa); reading the seconds counter twice around the sub-second read
+    /// and retrying if they disagree rules that race out.
+    pub fn now(&self) -> (u32, u16) {
+        loop {
+            let seconds_before = self.seconds();
+            let subsec_ticks = self.sub_second_ticks();
+            let seconds_after = self.seconds();
+            if seconds_before == seconds_after {
+                let subsec = u32::from(subsec_ticks) * (SUBSEC_TICKS_PER_SEC / SSEC_TICKS_PER_SEC);
+                return (seconds_before, subsec as u16);
+            }
+        }
+    }
+
+    /// Sets the wall-clock time to `seconds`, leaving the sub-second
+    /// counter running. An alias for [`Self::set_seconds`] matching the
+    /// `now`/`set_time` naming other HAL RTC drivers use.
+    pub fn set_time(&mut self, seconds: u32) -> Result<(), RtcError> {
+        self.set_seconds(seconds)
+    }
+
+    /// Sets the time-of-day alarm's compare value, in whole seconds since
+    /// the RTC's epoch. Goes through the same write-enable handshake as
+    /// [`Self::set_seconds`], since `RTC_RAS` lives in the same
+    /// asynchronous clock domain as `RTC_SEC`/`RTC_SSEC`.
+    pub fn set_alarm(&mut self, seconds: u32) -> Result<(), RtcError> {
+        self.with_write_enabled(|rtc| {
+            // SAFETY: `RTC_RAS` is a plain 32-bit compare value; every value is valid.
+            rtc.regs.ras().write(|w| unsafe { w.bits(seconds) });
+        })
+    }
+
+    /// Enables the time-of-day alarm interrupt: once [`Self::seconds`]
+    /// reaches the value set by [`Self::set_alarm`], `RTC_CTRL.alsf` is set
+    /// and, if the RTC interrupt is unmasked at the NVIC, the core wakes
+    /// from `wfi`/`wfe` sleep to service it.
+    pub fn enable_alarm(&mut self) {
+        self.regs.ctrl().modify(|_, w| w.ade().set_bit());
+    }
+
+    /// Disables the time-of-day alarm interrupt.
+    pub fn disable_alarm(&mut self) {
+        self.regs.ctrl().modify(|_, w| w.ade().clear_bit());
+    }
+
+    /// Whether `RTC_CTRL.alsf` is set, i.e. the alarm has fired since it was
+    /// last cleared with [`Self::clear_alarm`].
+    pub fn alarm_pending(&self) -> bool {
+        bit_band!(self.regs.ctrl(), alsf, 6).read()
+    }
+
+    /// Clears a pending alarm flag.
+    pub fn clear_alarm(&mut self) {
+        bit_band!(self.regs.ctrl(), alsf, 6).set();
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Rtc {
+    /// Current time, treating [`Self::seconds`] as a Unix timestamp and
+    /// [`Self::sub_second_ticks`] as the fractional second.
+    pub fn now_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        let secs = self.seconds() as i64;
+        // `sub_second_ticks` is `< SSEC_TICKS_PER_SEC`, so this is always `< 1_000_000_000`.
+        let nanos = self.sub_second_ticks() as u32 * (1_000_000_000 / SSEC_TICKS_PER_SEC);
+
+        chrono::DateTime::from_timestamp(secs, nanos)
+            .expect("seconds/nanos are always in range for a valid Unix timestamp")
+    }
+
+    /// Sets the whole-seconds counter from `time`'s Unix timestamp,
+    /// truncating sub-second precision (the RTC's sub-second counter keeps
+    /// running and can't be written independently of [`Self::set_seconds`]).
+    pub fn set_now(&mut self, time: chrono::DateTime<chrono::Utc>) -> Result<(), RtcError> {
+        self.set_seconds(time.timestamp().clamp(0, u32::MAX as i64) as u32)
+    }
+}
+
+/// Set by [`on_interrupt`], polled by [`InterruptRtc::wait_for_alarm`].
+static ALARM_FIRED: AtomicBool = AtomicBool::new(false);
+
+/// Call this from the `RTC` NVIC handler. Clears the hardware alarm flag
+/// and records that the alarm fired for [`InterruptRtc::wait_for_alarm`] to
+/// pick up.
+pub fn on_interrupt(regs: &RTC) {
+    bit_band!(regs.ctrl(), alsf, 6).set();
+    ALARM_FIRED.store(true, Ordering::Release);
+}
+
+/// Interrupt-driven wrapper around [`Rtc`]'s time-of-day alarm, letting the
+/// core sleep (`wfi`) until [`on_interrupt`] observes the alarm firing
+/// instead of busy-polling [`Rtc::alarm_pending`]. Parallels
+/// `InterruptTimer`'s (`crate::peripherals::timer`) poll-a-flag-set-by-the-
+/// ISR pattern.
+pub struct InterruptRtc {
+    rtc: Rtc,
+}
+
+impl InterruptRtc {
+    /// Wraps `rtc`, enabling its alarm interrupt. Callers are responsible
+    /// for routing the `RTC` interrupt to [`on_interrupt`] from their
+    /// `#[interrupt]` handler, and for arming a deadline with
+    /// [`Rtc::set_alarm`] before waiting.
+    pub fn new(mut rtc: Rtc) -> Self {
+        rtc.enable_alarm();
+        Self { rtc }
+    }
+
+    /// Clears [`ALARM_FIRED`], parks the core (`wfi`) until [`on_interrupt`]
+    /// sets it again, then clears the hardware alarm flag.
+    pub fn wait_for_alarm(&mut self) {
+        ALARM_FIRED.store(false, Ordering::Release);
+        while !ALARM_FIRED.load(Ordering::Acquire) {
+            cortex_m::asm::wfi();
+        }
+        self.rtc.clear_alarm();
+    }
+
+    /// Disables the alarm interrupt and hands back the underlying [`Rtc`].
+    pub fn release(mut self) -> Rtc {
+        self.rtc.disable_alarm();
+        self.rtc
+    }
+}
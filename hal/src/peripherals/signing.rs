@@ -0,0 +1,80 @@
+//! Ed25519 signing and verification, keyed from the CSPRNG entropy
+//! subsystem (see [`random`](crate::peripherals::random)).
+//!
+//! Gives the crate an end-to-end path from hardware entropy to
+//! authenticated signatures -- e.g. for authenticating firmware images or
+//! messages exchanged on-device -- without pulling in a separate RNG: any
+//! [`RandomSource`] already seeded by [`EntropyGatherer`](crate::peripherals::random)
+//! or a [`ReseedingCsprng`](crate::peripherals::random) can be handed
+//! straight to [`generate_keypair`], and so can a
+//! [`HashDrbg`](crate::peripherals::random) now that it implements
+//! [`RandomSource`] too, for callers that want a keypair drawn straight from
+//! the on-chip entropy sources without a `ChaCha20Rng` in between.
+
+use ed25519_dalek::{Signer as _, SigningKey, Verifier as _};
+use zeroize::Zeroize;
+
+pub use ed25519_dalek::{Signature, SignatureError, VerifyingKey};
+
+use crate::communication::lower_layers::crypto::RandomSource;
+
+/// Length in bytes of an Ed25519 seed.
+const SEED_SIZE: usize = 32;
+
+/// An Ed25519 keypair's secret seed.
+///
+/// IMPORTANT: This struct should not be moved to ensure the seed gets
+/// zeroed out on drop.
+struct Seed([u8; SEED_SIZE]);
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// An Ed25519 keypair. Only the [`SEED_SIZE`]-byte seed is held long-term,
+/// in a zeroize-on-drop buffer exactly like
+/// [`ClockDrift`](crate::peripherals::random)'s entropy buffer; the
+/// `ed25519_dalek` signing key derived from it is only ever materialized
+/// for the duration of a single [`Keypair::sign`] call.
+pub struct Keypair {
+    seed: Seed,
+    verifying_key: VerifyingKey,
+}
+
+impl Keypair {
+    /// Produces a detached signature over `message`.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        SigningKey::from_bytes(&self.seed.0).sign(message)
+    }
+
+    /// Returns the public key corresponding to this keypair's secret seed,
+    /// for sharing with whoever calls [`verify`].
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.verifying_key
+    }
+}
+
+/// Generates a new Ed25519 keypair, drawing its seed from `rng`.
+pub fn generate_keypair<R: RandomSource>(rng: &mut R) -> Keypair {
+    let mut seed = Seed([0; SEED_SIZE]);
+    rng.fill_rand_slice(&mut seed.0);
+
+    let verifying_key = SigningKey::from_bytes(&seed.0).verifying_key();
+
+    Keypair {
+        seed,
+        verifying_key,
+    }
+}
+
+/// Verifies a detached Ed25519 `signature` over `message` against
+/// `verifying_key`.
+pub fn verify(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), SignatureError> {
+    verifying_key.verify(message, signature)
+}
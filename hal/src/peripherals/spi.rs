@@ -0,0 +1,241 @@
+//! SPI Peripheral Drivers
+
+use core::cell::RefMut;
+use core::ops::Deref;
+use embedded_hal::spi::{ErrorType, Mode, Phase, Polarity, SpiBus};
+use max78000::spi0;
+use max78000::{SPI0, SPI1};
+
+use crate::peripherals::gpio::active::port_num_types::GpioZero;
+use crate::peripherals::gpio::active::ActivePinHandle;
+use crate::peripherals::gpio::pin_traits::IoPin;
+use crate::peripherals::gpio::{GpioError, PinOperatingMode};
+use crate::peripherals::oscillator::SystemClock;
+
+/// Auxiliary trait that only the SPI0 and SPI1 registers can implement;
+/// allows bus-level FIFO/status access common to both instances.
+pub trait GCRSPI: Deref<Target = spi0::RegisterBlock> {
+    /// Flush transmit and receive FIFOs
+    fn flush_fifo(&self);
+    /// Is receive FIFO empty?
+    fn is_rx_fifo_empty(&self) -> bool;
+    /// Is transmit FIFO full?
+    fn is_tx_fifo_full(&self) -> bool;
+    /// Has the current transaction completed?
+    fn transaction_done(&self) -> bool;
+    /// Clear the latched interrupt flags
+    fn clear_interrupt_flags(&self);
+    /// Has a transmit or receive FIFO overrun latched?
+    fn fault(&self) -> bool;
+}
+
+macro_rules! gen_impl_gcrspi {
+    ($register:ty) => {
+        impl GCRSPI for $register {
+            fn flush_fifo(&self) {
+                self.dma()
+                    .modify(|_, w| w.tx_flush().bit(true).rx_flush().bit(true));
+                while self.dma().read().tx_flush().bit() || self.dma().read().rx_flush().bit() {}
+            }
+            fn is_rx_fifo_empty(&self) -> bool {
+                self.dma().read().rx_lvl().bits() == 0
+            }
+            fn is_tx_fifo_full(&self) -> bool {
+                self.dma().read().tx_lvl().bits() >= 8
+            }
+            fn transaction_done(&self) -> bool {
+                self.intfl().read().mstdone().bit()
+            }
+            fn clear_interrupt_flags(&self) {
+                self.intfl().modify(|_, w| {
+                    w.mstdone()
+                        .bit(true)
+                        .tx_ov()
+                        .bit(true)
+                        .rx_ov()
+                        .bit(true)
+                        .rx_us()
+                        .bit(true)
+                });
+            }
+            fn fault(&self) -> bool {
+                self.intfl().read().tx_ov().bit() || self.intfl().read().rx_ov().bit()
+            }
+        }
+    };
+}
+
+gen_impl_gcrspi!(SPI0);
+gen_impl_gcrspi!(SPI1);
+
+/// SPI failure reasons surfaced through `embedded_hal::spi::Error`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpiError {
+    /// The transmit or receive FIFO overran mid-transaction.
+    Overrun,
+}
+
+impl embedded_hal::spi::Error for SpiError {
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        embedded_hal::spi::ErrorKind::Overrun
+    }
+}
+
+/// Which hardware chip-select line a [`SpiMaster`] asserts for the duration
+/// of a transaction. SPI1 only brings out `Ss0`; SPI0 additionally offers
+/// `Ss1`/`Ss2` on pins shared with I2C0 (see the alternate-function table in
+/// [`crate::peripherals::gpio::active`]).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChipSelect {
+    /// SS0, the primary/only chip select for both SPI0 and SPI1.
+    Ss0,
+    /// SS1, SPI0 only.
+    Ss1,
+    /// SS2, SPI0 only.
+    Ss2,
+}
+
+/// Configuration for a [`SpiMaster`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SpiConfig {
+    /// SPI clock polarity/phase, i.e. one of `embedded_hal::spi::MODE_0..MODE_3`.
+    pub mode: Mode,
+    /// Target SCK frequency in Hz. The nearest divider the hardware can
+    /// produce that does not exceed this is selected.
+    pub frequency_hz: u32,
+    /// Which hardware SS line to assert while a transaction is in flight.
+    pub chip_select: ChipSelect,
+}
+
+/// An SPI peripheral operating as a bus master, implementing
+/// `embedded_hal::spi::SpiBus<u8>`. Chip-select assertion is handled in
+/// hardware by the peripheral itself for the [`ChipSelect`] line chosen at
+/// construction; there is no separate GPIO toggle to manage.
+pub struct SpiMaster<'a, T: GCRSPI> {
+    spi_regs: RefMut<'a, T>,
+}
+
+impl<'a, T: GCRSPI> SpiMaster<'a, T> {
+    pub(crate) fn new(
+        config: SpiConfig,
+        system_clock: core::cell::Ref<SystemClock>,
+        spi_regs: RefMut<'a, T>,
+        mut sck_pin: ActivePinHandle<'a, GpioZero, 31>,
+        mut mosi_pin: ActivePinHandle<'a, GpioZero, 31>,
+        mut miso_pin: ActivePinHandle<'a, GpioZero, 31>,
+        mut cs_pin: ActivePinHandle<'a, GpioZero, 31>,
+        cs_alt_function: PinOperatingMode,
+    ) -> Result<Self, GpioError> {
+        sck_pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
+        mosi_pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
+        miso_pin.set_operating_mode(PinOperatingMode::AltFunction1)?;
+        cs_pin.set_operating_mode(cs_alt_function)?;
+
+        // Master mode, 8 bits per character, SS driven by hardware for the
+        // duration of each transaction rather than toggled by software.
+        spi_regs.ctrl0().modify(|_, w| {
+            w.master()
+                .bit(true)
+                .en()
+                .bit(true)
+                .ss_ctrl()
+                .bit(false)
+                .ss_io()
+                .bit(true)
+        });
+
+        spi_regs.ctrl2().modify(|_, w| {
+            w.clkpol()
+                .bit(config.mode.polarity == Polarity::IdleHigh)
+                .clkpha()
+                .bit(config.mode.phase == Phase::CaptureOnSecondTransition)
+                .numbits()
+                .variant(8)
+        });
+
+        // calculations pulled from msdk: PCLK is the SYS_OSC post-divider
+        // halved, as in the I2C drivers. Rounding the divider up keeps SCK at
+        // or below `frequency_hz`.
+        let pclk_speed = (system_clock.get_freq() / u32::from(system_clock.get_div()) / 2).to_hz();
+        let divider = pclk_speed.div_ceil(config.frequency_hz.max(1)).max(1);
+        let lo = (divider / 2) as u16;
+        let hi = (divider - (divider / 2)) as u16;
+        spi_regs
+            .clkctrl()
+            .write(|w| w.lo().variant(lo).hi().variant(hi));
+
+        spi_regs.ctrl0().modify(|_, w| w.en().bit(true));
+
+        Ok(Self { spi_regs })
+    }
+
+    /// Blocks until every queued byte has drained out of the transmit FIFO
+    /// and the current transaction, if any, has completed.
+    fn wait_for_done(&self) -> Result<(), SpiError> {
+        while !self.spi_regs.transaction_done() && !self.spi_regs.fault() {}
+        let faulted = self.spi_regs.fault();
+        self.spi_regs.clear_interrupt_flags();
+        if faulted {
+            Err(SpiError::Overrun)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn transfer_byte(&mut self, tx: u8) -> Result<u8, SpiError> {
+        while self.spi_regs.is_tx_fifo_full() {}
+        self.spi_regs.fifo().write(|w| w.data().variant(tx));
+        self.spi_regs.mstctrl().modify(|_, w| w.start().bit(true));
+        while self.spi_regs.is_rx_fifo_empty() && !self.spi_regs.fault() {}
+        if self.spi_regs.fault() {
+            self.spi_regs.clear_interrupt_flags();
+            return Err(SpiError::Overrun);
+        }
+        let rx = self.spi_regs.fifo().read().data().bits();
+        self.wait_for_done()?;
+        Ok(rx)
+    }
+}
+
+impl<T: GCRSPI> ErrorType for SpiMaster<'_, T> {
+    type Error = SpiError;
+}
+
+impl<T: GCRSPI> SpiBus<u8> for SpiMaster<'_, T> {
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        for word in words.iter_mut() {
+            *word = self.transfer_byte(0)?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        for &word in words.iter() {
+            self.transfer_byte(word)?;
+        }
+        Ok(())
+    }
+
+    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        let len = read.len().max(write.len());
+        for i in 0..len {
+            let tx = write.get(i).copied().unwrap_or(0);
+            let rx = self.transfer_byte(tx)?;
+            if let Some(out) = read.get_mut(i) {
+                *out = rx;
+            }
+        }
+        Ok(())
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        for word in words.iter_mut() {
+            *word = self.transfer_byte(*word)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_done()
+    }
+}
@@ -1,7 +1,9 @@
 //! Peripheral API for Timers
 
 use core::cell::Cell;
+use core::convert::Infallible;
 use core::ops::Deref;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use core::time::Duration;
 use max78000::gcr::clkctrl::ERTCO_EN_A;
 use max78000::gcr::pclkdis0::GPIO0_A;
@@ -12,6 +14,9 @@ use max78000::GCR;
 use max78000::{TMR, TMR1, TMR2, TMR3};
 
 use crate::communication::Timeout;
+use crate::peripherals::gpio::active::port_num_types::GpioPortNum;
+use crate::peripherals::gpio::active::ActivePinHandle;
+use crate::peripherals::gpio::{GpioError, PinOperatingMode};
 
 /// Auxiliary trait that only the TMR, TMR1, TMR2, and TMR3 registers can implement;
 /// Allows peripheral toggle and reset functionality to said peripherals if GCR regs
@@ -53,6 +58,76 @@ gen_impl_tpgcr!(TMR1, tmr1);
 gen_impl_tpgcr!(TMR2, tmr2);
 gen_impl_tpgcr!(TMR3, tmr3);
 
+/// Type-erased timer register block, holding ownership of whichever of
+/// `TMR`/`TMR1`/`TMR2`/`TMR3` it was built from. Lets [`Clock<AnyTimer>`] be
+/// stored uniformly (e.g. in an array) and passed to code that only needs
+/// "a timer" rather than a specific one.
+pub enum AnyTimer {
+    /// Wraps the TMR0 register block.
+    Tmr0(TMR),
+    /// Wraps the TMR1 register block.
+    Tmr1(TMR1),
+    /// Wraps the TMR2 register block.
+    Tmr2(TMR2),
+    /// Wraps the TMR3 register block.
+    Tmr3(TMR3),
+}
+
+impl Deref for AnyTimer {
+    type Target = tmr::RegisterBlock;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            AnyTimer::Tmr0(regs) => regs,
+            AnyTimer::Tmr1(regs) => regs,
+            AnyTimer::Tmr2(regs) => regs,
+            AnyTimer::Tmr3(regs) => regs,
+        }
+    }
+}
+
+impl TimerPeripheralGCR for AnyTimer {
+    // `TimerPeripheralGCR`'s methods are associated functions dispatched on `Self`
+    // at compile time, so they can't branch on which variant a runtime `AnyTimer`
+    // value holds. Enable and reset the underlying timer (e.g. via
+    // [`crate::peripherals::power::PowerControl::enable_and_reset`]) before erasing
+    // it into an `AnyTimer`; these are unreachable in practice because
+    // [`Clock::new`]/[`Clock::configure`] never call through this trait themselves.
+    fn peripheral_clock_disable(_gcr_reg: &GCR) {
+        unreachable!("AnyTimer cannot dispatch a static TimerPeripheralGCR method at runtime")
+    }
+    fn peripheral_clock_enable(_gcr_reg: &GCR) {
+        unreachable!("AnyTimer cannot dispatch a static TimerPeripheralGCR method at runtime")
+    }
+    fn reset_peripheral(_gcr_reg: &GCR) {
+        unreachable!("AnyTimer cannot dispatch a static TimerPeripheralGCR method at runtime")
+    }
+}
+
+impl From<TMR> for AnyTimer {
+    fn from(regs: TMR) -> Self {
+        AnyTimer::Tmr0(regs)
+    }
+}
+
+impl From<TMR1> for AnyTimer {
+    fn from(regs: TMR1) -> Self {
+        AnyTimer::Tmr1(regs)
+    }
+}
+
+impl From<TMR2> for AnyTimer {
+    fn from(regs: TMR2) -> Self {
+        AnyTimer::Tmr2(regs)
+    }
+}
+
+impl From<TMR3> for AnyTimer {
+    fn from(regs: TMR3) -> Self {
+        AnyTimer::Tmr3(regs)
+    }
+}
+
 /// `Clock` struct. This will take ownership of the timer peripheral registers and is generic to
 /// `TMR`, `TMR1`, `TMR2`, and `TMR3`. With it you can start timers using [`Clock::new_timer`]
 ///
@@ -99,6 +174,26 @@ pub struct Timer<'clock, 'gcr, T: TimerPeripheralGCR> {
     pub end: u32,
     clock: &'clock Clock<'gcr, T>,
     finished: bool,
+    /// Set by [`embedded_hal_0_2::timer::Cancel::cancel`] to make
+    /// [`embedded_hal_0_2::timer::CountDown::wait`] block forever until the
+    /// next `start`, since there's no hardware countdown to actually stop.
+    cancelled: bool,
+    mode: TimerMode,
+}
+
+/// Whether a [`Timer`] fires once or automatically rearms itself, mirroring
+/// the split the msp432/stm32 HALs make between a plain `CountDown` and one
+/// also tagged `Periodic` (see this module's own `Periodic` impl).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerMode {
+    /// `poll()` latches `true` once the timer fires and stays that way.
+    /// If this is the clock's only outstanding timer, `poll()` also clears
+    /// the clock's count-enable bit rather than let the shared, otherwise
+    /// free-running counter keep ticking for no outstanding timer to read.
+    OneShot,
+    /// `poll()` automatically calls [`Timer::reset`] once the timer fires,
+    /// so the same duration starts counting down again immediately.
+    Periodic,
 }
 
 impl<T: TimerPeripheralGCR> Drop for Timer<'_, '_, T> {
@@ -125,12 +220,14 @@ impl<T: TimerPeripheralGCR> Timeout for Timer<'_, '_, T> {
 }
 
 impl<'clock, 'gcr, T: TimerPeripheralGCR> Timer<'clock, 'gcr, T> {
-    fn new(start: u32, end: u32, clock: &'clock Clock<'gcr, T>) -> Self {
+    fn new(start: u32, end: u32, clock: &'clock Clock<'gcr, T>, mode: TimerMode) -> Self {
         Self {
             start,
             end,
             clock,
             finished: false,
+            cancelled: false,
+            mode,
         }
     }
 
@@ -149,7 +246,18 @@ impl<'clock, 'gcr, T: TimerPeripheralGCR> Timer<'clock, 'gcr, T> {
             self.clock.get_count() >= self.end
         };
         if res {
-            self.finished = true;
+            match self.mode {
+                TimerMode::OneShot => {
+                    self.finished = true;
+                    if self.clock.active_timers.get() == 1 {
+                        self.clock
+                            .tmr_registers
+                            .ctrl0()
+                            .modify(|_, w| w.en_a().variant(false));
+                    }
+                }
+                TimerMode::Periodic => self.reset(),
+            }
         }
         res
     }
@@ -161,6 +269,7 @@ impl<'clock, 'gcr, T: TimerPeripheralGCR> Timer<'clock, 'gcr, T> {
         self.start = cnt;
         self.end = self.start.wrapping_add(duration);
         self.finished = false;
+        self.cancelled = false;
     }
 
     /// Get total duration, in clock ticks
@@ -172,6 +281,193 @@ impl<'clock, 'gcr, T: TimerPeripheralGCR> Timer<'clock, 'gcr, T> {
     pub fn duration_ms(&self) -> u32 {
         self.clock.ticks_to_ms(self.duration_ticks())
     }
+
+    /// Rearms this timer for a new `duration` starting now, the way
+    /// [`embedded_hal_0_2::timer::CountDown::start`] needs to (unlike
+    /// [`Self::reset`], which always repeats the previous duration).
+    fn restart(&mut self, duration: Time) {
+        let cnt = self.clock.get_count();
+        self.start = cnt;
+        self.end = match duration {
+            Time::Ticks(ticks) => cnt.wrapping_add(ticks),
+            Time::Milliseconds(ms) => cnt.wrapping_add(self.clock.ms_to_ticks(ms)),
+        };
+        self.finished = false;
+        self.cancelled = false;
+    }
+}
+
+/// Error returned by [`embedded_hal_0_2::timer::Cancel::cancel`] when the
+/// timer was already cancelled.
+#[derive(Debug, Copy, Clone)]
+pub struct TimerNotRunningError;
+
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal_0_2::timer::CountDown for Timer<'_, '_, T> {
+    type Time = Time;
+
+    fn start<Tm>(&mut self, count: Tm)
+    where
+        Tm: Into<Self::Time>,
+    {
+        self.restart(count.into());
+    }
+
+    fn wait(&mut self) -> nb::Result<(), Infallible> {
+        if self.cancelled {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        if self.poll() {
+            // Auto-reload, per the `Periodic` impl below.
+            self.reset();
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+/// Marks [`Timer`] as auto-reloading: once [`embedded_hal_0_2::timer::CountDown::wait`]
+/// reports completion it immediately rearms itself for the same duration,
+/// the way a free-running hardware PWM/reload timer would.
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal_0_2::timer::Periodic for Timer<'_, '_, T> {}
+
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal_0_2::timer::Cancel for Timer<'_, '_, T> {
+    type Error = TimerNotRunningError;
+
+    fn cancel(&mut self) -> Result<(), Self::Error> {
+        if self.cancelled {
+            return Err(TimerNotRunningError);
+        }
+        self.cancelled = true;
+        Ok(())
+    }
+}
+
+/// Identifies which of the four TMR register blocks a caller is operating
+/// on, used to index into [`TIMER_FINISHED`].
+#[derive(Copy, Clone)]
+pub enum TimerInstance {
+    /// TMR0
+    Tmr0 = 0,
+    /// TMR1
+    Tmr1 = 1,
+    /// TMR2
+    Tmr2 = 2,
+    /// TMR3
+    Tmr3 = 3,
+}
+
+/// Number of TMR instances (TMR0..TMR3) that need a finished-flag slot.
+const NUM_TIMER_INSTANCES: usize = 4;
+
+static TIMER_FINISHED: [AtomicBool; NUM_TIMER_INSTANCES] =
+    [const { AtomicBool::new(false) }; NUM_TIMER_INSTANCES];
+
+/// Call this from the `TMRn` NVIC handler. Clears the compare-match
+/// interrupt flag and marks the matching [`InterruptTimer`] finished.
+pub fn on_interrupt<T: TimerPeripheralGCR>(instance: TimerInstance, regs: &T) {
+    regs.intfl().write(|w| w.irq_a().bit(true));
+    TIMER_FINISHED[instance as usize].store(true, Ordering::Release);
+}
+
+/// An interrupt-driven [`Timer`], started via [`Clock::new_timer_interrupt`].
+/// Splits a plain counter from an interrupt-capable one the same way
+/// atsamd's timer v2 does: [`Self::poll`] checks the flag [`on_interrupt`]
+/// set rather than re-reading the hardware counter, so the core can sleep
+/// between interrupts instead of spinning on [`Timer::poll`].
+pub struct InterruptTimer<'clock, 'gcr, T: TimerPeripheralGCR> {
+    timer: Timer<'clock, 'gcr, T>,
+    instance: TimerInstance,
+}
+
+impl<'clock, 'gcr, T: TimerPeripheralGCR> InterruptTimer<'clock, 'gcr, T> {
+    /// Returns `true` once [`on_interrupt`] has observed this timer's
+    /// compare match.
+    pub fn poll(&mut self) -> bool {
+        TIMER_FINISHED[self.instance as usize].load(Ordering::Acquire)
+    }
+
+    /// Rearms the timer for the same duration starting now, clearing the
+    /// finished flag and reprogramming the compare register.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+        TIMER_FINISHED[self.instance as usize].store(false, Ordering::Release);
+        self.timer
+            .clock
+            .tmr_registers
+            .cmp()
+            .write(|w| w.compare().variant(self.timer.end));
+    }
+
+    /// Releases the underlying [`Timer`] and disables the compare-match
+    /// interrupt.
+    pub fn release(self) -> Timer<'clock, 'gcr, T> {
+        self.timer
+            .clock
+            .tmr_registers
+            .ctrl0()
+            .modify(|_, w| w.ie_a().bit(false));
+        self.timer
+    }
+}
+
+/// Per-instance period counter for [`Clock::now_ticks`], maintained by
+/// [`on_overflow_interrupt`] using the period-counting scheme from embassy's
+/// time driver: incremented every time the hardware counter wraps past `0`
+/// or past its halfway point `0x8000_0000`. Even means the counter is in
+/// `0..0x8000_0000`, odd means it's in `0x8000_0000..=0xFFFF_FFFF`.
+static PERIOD: [AtomicU32; NUM_TIMER_INSTANCES] = [const { AtomicU32::new(0) }; NUM_TIMER_INSTANCES];
+
+impl<T: TimerPeripheralGCR> Clock<'_, T> {
+    /// Arms this timer's compare-match interrupt to fire at both the
+    /// counter's overflow (wrap to `0`) and its halfway point
+    /// (`0x8000_0000`), so [`on_overflow_interrupt`] can maintain the period
+    /// counter [`Self::now_ticks`] reads. This reprograms the same compare
+    /// register [`Self::new_timer_interrupt`] uses for its own per-timer
+    /// deadline, so don't combine the two on the same timer instance.
+    pub fn enable_overflow_tracking(&self, instance: TimerInstance) {
+        PERIOD[instance as usize].store(0, Ordering::Release);
+        self.tmr_registers
+            .cmp()
+            .write(|w| w.compare().variant(0x8000_0000));
+        self.tmr_registers.ctrl0().modify(|_, w| w.ie_a().bit(true));
+    }
+
+    /// Returns the hardware count extended to 64 bits by the period counter
+    /// that [`Self::enable_overflow_tracking`] started from `0` for
+    /// `instance` (that call does not reset the hardware count itself), as a
+    /// count that (unlike
+    /// [`Self::get_count`]/[`Timer::poll`]) doesn't wrap for hundreds of
+    /// years. Reads the period counter, then the hardware counter, then the
+    /// period counter again, retrying if the two period reads disagree (the
+    /// counter wrapped mid-read), so the combined value is race-free.
+    pub fn now_ticks(&self, instance: TimerInstance) -> u64 {
+        loop {
+            let period_before = PERIOD[instance as usize].load(Ordering::Acquire);
+            let counter = self.get_count();
+            let period_after = PERIOD[instance as usize].load(Ordering::Acquire);
+            if period_before == period_after {
+                return ((period_before as u64 >> 1) << 32) | counter as u64;
+            }
+        }
+    }
+}
+
+/// Call this from the `TMRn` NVIC handler when the timer was armed by
+/// [`Clock::enable_overflow_tracking`] rather than
+/// [`Clock::new_timer_interrupt`]. Clears the compare-match interrupt flag,
+/// advances the period counter, and reprograms the compare register for the
+/// other half of the counter's range.
+pub fn on_overflow_interrupt<T: TimerPeripheralGCR>(instance: TimerInstance, regs: &T) {
+    regs.intfl().write(|w| w.irq_a().bit(true));
+
+    let period = PERIOD[instance as usize].fetch_add(1, Ordering::AcqRel) + 1;
+    let next_compare = if period % 2 == 0 { 0x8000_0000 } else { 0xFFFF_FFFF };
+    regs.cmp().write(|w| w.compare().variant(next_compare));
 }
 
 /// Error type that represents that an operation cannot be performed
@@ -376,21 +672,321 @@ impl<'gcr, T: TimerPeripheralGCR> Clock<'gcr, T> {
         (ticks as f64 / self.ticks_per_ms.get()) as u32
     }
 
+    /// Convert microseconds to ticks
+    pub fn us_to_ticks(&self, us: u32) -> u32 {
+        ((us as f64) * self.ticks_per_ms.get() / 1_000f64) as u32
+    }
+
+    /// Convert nanoseconds to ticks
+    pub fn ns_to_ticks(&self, ns: u32) -> u32 {
+        ((ns as f64) * self.ticks_per_ms.get() / 1_000_000f64) as u32
+    }
+
+    /// Busy-waits until `ticks` clock ticks have elapsed, via the same
+    /// [`Timer`]/[`Timer::poll`] machinery `new_timer` users already spin
+    /// on, so counter wraparound is handled the same way.
+    fn delay_ticks(&self, ticks: u32) {
+        let mut timer = self.new_timer(Time::Ticks(ticks));
+        while !timer.poll() {}
+    }
+
     /// Start a new timer with given `Time`, which can be expressed with either raw `Ticks`
     /// or `Milliseconds`, which will be converted into ticks internally.
     ///
     /// Caveat: Will only work reliably for durations of less than `2^31` ticks.
     pub fn new_timer(&self, duration: Time) -> Timer<T> {
+        self.new_timer_with_mode(duration, TimerMode::OneShot)
+    }
+
+    /// Like [`Self::new_timer`], but lets the caller pick a [`TimerMode`]
+    /// instead of always getting a one-shot timer.
+    ///
+    /// Caveat: Will only work reliably for durations of less than `2^31` ticks.
+    pub fn new_timer_with_mode(&self, duration: Time, mode: TimerMode) -> Timer<T> {
+        if self.active_timers.get() == 0 {
+            // A prior lone one-shot timer may have cleared `en_a` on finish;
+            // re-enable it now that there's a timer counting on it again.
+            self.tmr_registers
+                .ctrl0()
+                .modify(|_, w| w.en_a().variant(true));
+        }
         self.active_timers.set(self.active_timers.get() + 1);
         let current = self.get_count();
         match duration {
             Time::Ticks(ticks) => {
                 // Ticks are straightforward
-                Timer::new(current, current.wrapping_add(ticks), self)
-            }
-            Time::Milliseconds(ms) => {
-                Timer::new(current, current.wrapping_add(self.ms_to_ticks(ms)), self)
+                Timer::new(current, current.wrapping_add(ticks), self, mode)
             }
+            Time::Milliseconds(ms) => Timer::new(
+                current,
+                current.wrapping_add(self.ms_to_ticks(ms)),
+                self,
+                mode,
+            ),
+        }
+    }
+
+    /// Starts a timer like [`Self::new_timer`], but programs `cmp().compare()`
+    /// to the computed end count and enables the compare-match interrupt
+    /// instead of leaving the caller to spin on [`Timer::poll`]. Pair this
+    /// with a `#[interrupt]` handler for this timer's NVIC line that calls
+    /// [`on_interrupt`] with `instance`, and unmasks that line; this mirrors
+    /// how [`crate::peripherals::i2c::asynch`] and
+    /// [`crate::peripherals::gpio::active::asynch`] leave NVIC unmasking to
+    /// the caller rather than doing it inside the HAL.
+    pub fn new_timer_interrupt(&self, duration: Time, instance: TimerInstance) -> InterruptTimer<T> {
+        let timer = self.new_timer(duration);
+        TIMER_FINISHED[instance as usize].store(false, Ordering::Release);
+        self.tmr_registers
+            .cmp()
+            .write(|w| w.compare().variant(timer.end));
+        self.tmr_registers.ctrl0().modify(|_, w| w.ie_a().bit(true));
+        InterruptTimer { timer, instance }
+    }
+}
+
+impl<'gcr, T: TimerPeripheralGCR + Into<AnyTimer>> Clock<'gcr, T> {
+    /// Erases this clock's concrete timer register type, yielding a
+    /// [`Clock<AnyTimer>`] that can be stored alongside clocks built over a
+    /// different timer (e.g. in [`crate::peripherals::PeripheralManager::timers`]).
+    pub fn erase(self) -> Clock<'gcr, AnyTimer> {
+        Clock {
+            gcr: self.gcr,
+            tmr_registers: self.tmr_registers.into(),
+            ticks_per_ms: self.ticks_per_ms,
+            active_timers: self.active_timers,
+        }
+    }
+}
+
+// These are fully qualified rather than imported, mirroring how
+// `peripherals::gpio::common` gates its `embedded_hal` 1.0 digital impls:
+// keeping the trait names out of scope lets this crate add a
+// differently-versioned `embedded_hal` surface later without the two
+// colliding.
+
+#[cfg(feature = "eh1-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal::delay::DelayNs for Clock<'_, T> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.delay_ticks(self.ns_to_ticks(ns));
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.delay_ticks(self.us_to_ticks(us));
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        self.delay_ticks(self.ms_to_ticks(ms));
+    }
+}
+
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal_0_2::blocking::delay::DelayUs<u32> for Clock<'_, T> {
+    fn delay_us(&mut self, us: u32) {
+        self.delay_ticks(self.us_to_ticks(us));
+    }
+}
+
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR> embedded_hal_0_2::blocking::delay::DelayMs<u32> for Clock<'_, T> {
+    fn delay_ms(&mut self, ms: u32) {
+        self.delay_ticks(self.ms_to_ticks(ms));
+    }
+}
+
+/// Drives a timer's `TMR*_IOA`/`TMR*_IOB` alternate-function pin as a PWM
+/// output, built from an already-configured [`Clock`]. This parallels the
+/// `pwm`/`pwm_input` modules in the stm32f1xx/stm32f4xx HALs: the timer owns
+/// the period/duty registers while the caller is responsible for having put
+/// the pin into the `AltFunction1`/`AltFunction2` mode that actually routes
+/// it to this timer (see the alt-function tables in
+/// [`crate::peripherals::gpio::active`]).
+pub struct PwmTimer<'gcr, 'pin, T: TimerPeripheralGCR, PortNum: GpioPortNum + 'static, const PIN_CT: usize> {
+    clock: Clock<'gcr, T>,
+    _pin: ActivePinHandle<'pin, PortNum, PIN_CT>,
+}
+
+impl<'gcr, 'pin, T: TimerPeripheralGCR, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    PwmTimer<'gcr, 'pin, T, PortNum, PIN_CT>
+{
+    /// Puts `pin` into `alt_function` (the alternate function that routes it
+    /// to `clock`'s timer output) and reprograms the timer for PWM mode with
+    /// the given `period`, replacing the continuous-cascade mode that
+    /// [`Clock::new`] leaves it in. The timer starts out disabled; call
+    /// [`Self::enable`] to start toggling the pin.
+    pub fn new(
+        mut clock: Clock<'gcr, T>,
+        mut pin: ActivePinHandle<'pin, PortNum, PIN_CT>,
+        alt_function: PinOperatingMode,
+        period: Time,
+    ) -> Result<Self, GpioError> {
+        pin.set_operating_mode(alt_function)?;
+
+        clock
+            .tmr_registers
+            .ctrl0()
+            .modify(|_, w| w.en_a().variant(false));
+        clock
+            .tmr_registers
+            .ctrl1()
+            .modify(|_, w| w.cascade().variant(false));
+        clock
+            .tmr_registers
+            .ctrl0()
+            .modify(|_, w| w.mode_a().variant(MODE_A_A::PWM));
+
+        let mut pwm_timer = Self { clock, _pin: pin };
+        pwm_timer.set_period(period);
+        pwm_timer.set_duty_cycle(0.0);
+
+        Ok(pwm_timer)
+    }
+
+    /// Reprograms the PWM period (the timer's reload/compare value).
+    pub fn set_period(&mut self, period: Time) {
+        let ticks = match period {
+            Time::Ticks(ticks) => ticks,
+            Time::Milliseconds(ms) => self.clock.ms_to_ticks(ms),
+        };
+        self.clock
+            .tmr_registers
+            .cmp()
+            .write(|w| w.compare().variant(ticks));
+    }
+
+    /// Sets the fraction of the period (clamped to `0.0..=1.0`) that the
+    /// output pin is held high for.
+    pub fn set_duty_cycle(&mut self, fraction: f32) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let period = self.clock.tmr_registers.cmp().read().compare().bits();
+        let duty = (period as f32 * fraction) as u32;
+        self.clock
+            .tmr_registers
+            .pwm()
+            .write(|w| w.pwm().variant(duty));
+    }
+
+    /// Starts the timer toggling the pin.
+    pub fn enable(&mut self) {
+        self.clock
+            .tmr_registers
+            .ctrl0()
+            .modify(|_, w| w.en_a().variant(true));
+    }
+
+    /// Stops the timer from toggling the pin.
+    pub fn disable(&mut self) {
+        self.clock
+            .tmr_registers
+            .ctrl0()
+            .modify(|_, w| w.en_a().variant(false));
+    }
+}
+
+#[cfg(feature = "eh1-pwm")]
+impl<T: TimerPeripheralGCR, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    embedded_hal::pwm::ErrorType for PwmTimer<'_, '_, T, PortNum, PIN_CT>
+{
+    type Error = Infallible;
+}
+
+#[cfg(feature = "eh1-pwm")]
+impl<T: TimerPeripheralGCR, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    embedded_hal::pwm::SetDutyCycle for PwmTimer<'_, '_, T, PortNum, PIN_CT>
+{
+    fn max_duty_cycle(&self) -> u16 {
+        u16::MAX
+    }
+
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+        PwmTimer::set_duty_cycle(self, duty as f32 / u16::MAX as f32);
+        Ok(())
+    }
+}
+
+// This parallels the PWM implementations added to the va108xx and stm32
+// HALs: the eh 1.0 `SetDutyCycle` impl above and this eh 0.2 `PwmPin` impl
+// both drive the same `set_duty_cycle`. The eh 1.0 impl has its own
+// `eh1-pwm` feature; this one shares `eh02-delay` with this module's
+// `DelayUs`/`DelayMs` impls.
+#[cfg(feature = "eh02-delay")]
+impl<T: TimerPeripheralGCR, PortNum: GpioPortNum + 'static, const PIN_CT: usize>
+    embedded_hal_0_2::PwmPin for PwmTimer<'_, '_, T, PortNum, PIN_CT>
+{
+    type Duty = u16;
+
+    fn disable(&mut self) {
+        PwmTimer::disable(self);
+    }
+
+    fn enable(&mut self) {
+        PwmTimer::enable(self);
+    }
+
+    fn get_duty(&self) -> Self::Duty {
+        let period = self.clock.tmr_registers.cmp().read().compare().bits();
+        if period == 0 {
+            return 0;
         }
+        let duty = self.clock.tmr_registers.pwm().read().pwm().bits();
+        ((duty as u64 * u16::MAX as u64) / period as u64) as u16
+    }
+
+    fn get_max_duty(&self) -> Self::Duty {
+        u16::MAX
+    }
+
+    fn set_duty(&mut self, duty: Self::Duty) {
+        PwmTimer::set_duty_cycle(self, duty as f32 / u16::MAX as f32);
+    }
+}
+
+/// Exposes a [`Clock`] as an RTIC monotonic time source, the same capability
+/// atsamd added with `into_monotonic()`/`MonotonicTimer` in their Timer V2
+/// work. `Clock` already runs its two halves cascaded into a free-running
+/// 32-bit counter (see [`Clock::configure`]), so this just reads/programs
+/// that counter through [`rtic_monotonic::Monotonic`] instead of
+/// [`Timer::poll`], letting RTIC schedule tasks in real time units rather
+/// than hand-rolled tick math.
+#[cfg(feature = "rtic")]
+pub struct MonotonicClock<'gcr, T: TimerPeripheralGCR> {
+    clock: Clock<'gcr, T>,
+}
+
+#[cfg(feature = "rtic")]
+impl<'gcr, T: TimerPeripheralGCR> MonotonicClock<'gcr, T> {
+    /// Wraps an already-configured [`Clock`] for use as an RTIC monotonic.
+    pub fn new(clock: Clock<'gcr, T>) -> Self {
+        Self { clock }
+    }
+}
+
+#[cfg(feature = "rtic")]
+impl<T: TimerPeripheralGCR> rtic_monotonic::Monotonic for MonotonicClock<'_, T> {
+    type Instant = fugit::TimerInstantU32<1000>;
+    type Duration = fugit::TimerDurationU32<1000>;
+
+    unsafe fn reset(&mut self) {
+        self.clock.tmr_registers.cnt().write(|w| w.count().variant(0));
+    }
+
+    fn now(&mut self) -> Self::Instant {
+        Self::Instant::from_ticks(self.clock.ticks_to_ms(self.clock.get_count()))
+    }
+
+    fn zero() -> Self::Instant {
+        Self::Instant::from_ticks(0)
+    }
+
+    fn set_compare(&mut self, instant: Self::Instant) {
+        let ticks = self.clock.ms_to_ticks(instant.duration_since_epoch().ticks());
+        self.clock
+            .tmr_registers
+            .cmp()
+            .write(|w| w.compare().variant(ticks));
+    }
+
+    fn clear_compare_flag(&mut self) {
+        self.clock.tmr_registers.intfl().write(|w| w.irq_a().bit(true));
     }
 }
@@ -3,10 +3,89 @@
 use core::mem;
 
 use max78000::TRNG;
+use rand_core::{CryptoRng, RngCore};
+
+/// Non-blocking, interrupt-driven fill, for latency-sensitive callers.
+pub mod asynch;
+
+/// Repetition Count Test cutoff: the number of consecutive identical output
+/// bytes that trips the test. SP 800-90B's formula for a false-positive
+/// rate of `alpha = 2^-20` is `C = 1 + ceil(-log2(alpha)/H)`; with
+/// `H = 7.5` bits/byte (the min-entropy the crate's TRNG tests already
+/// require of a large draw) that's `1 + ceil(20/7.5) = 4`.
+const RCT_CUTOFF: u32 = 4;
+
+/// Adaptive Proportion Test window size, in output bytes, per SP 800-90B's
+/// byte-granularity recommendation.
+const APT_WINDOW: usize = 1024;
+
+/// Adaptive Proportion Test cutoff for [`APT_WINDOW`] bytes at `H = 7.5`
+/// bits/byte: each of the other 1023 bytes matches the reference with
+/// probability `2^-7.5`, so the reference appears more than 21 times in a
+/// window (itself plus at least 21 matches) with probability below
+/// `2^-20`, the same false-positive rate as [`RCT_CUTOFF`].
+const APT_CUTOFF: usize = 21;
+
+/// Error returned by [`Trng::fill_buffer_checked`] when a continuous health
+/// test trips, meaning the TRNG's output can no longer be trusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrngHealthError {
+    /// The Repetition Count Test failed: the same byte value repeated
+    /// [`RCT_CUTOFF`] times in a row.
+    RepetitionCount,
+    /// The Adaptive Proportion Test failed: the window's reference byte
+    /// value appeared more than [`APT_CUTOFF`] times within one
+    /// [`APT_WINDOW`]-byte window.
+    AdaptiveProportion,
+}
+
+/// Running SP 800-90B continuous health test state over the TRNG's output
+/// byte stream, persisted across [`Trng::fill_buffer_checked`] calls so a
+/// degraded source is caught even if no single call pulls a full window.
+#[derive(Default)]
+struct HealthTestState {
+    rct_run: Option<(u8, u32)>,
+    apt_window: Option<(u8, usize)>,
+    apt_pos: usize,
+}
+
+impl HealthTestState {
+    /// Feeds one output byte through both tests.
+    fn check(&mut self, byte: u8) -> Result<(), TrngHealthError> {
+        match &mut self.rct_run {
+            Some((value, run)) if *value == byte => {
+                *run += 1;
+                if *run >= RCT_CUTOFF {
+                    return Err(TrngHealthError::RepetitionCount);
+                }
+            }
+            _ => self.rct_run = Some((byte, 1)),
+        }
+
+        if self.apt_pos == 0 {
+            self.apt_window = Some((byte, 1));
+        } else if let Some((reference, count)) = &mut self.apt_window {
+            if *reference == byte {
+                *count += 1;
+                if *count > APT_CUTOFF {
+                    return Err(TrngHealthError::AdaptiveProportion);
+                }
+            }
+        }
+
+        self.apt_pos += 1;
+        if self.apt_pos >= APT_WINDOW {
+            self.apt_pos = 0;
+        }
+
+        Ok(())
+    }
+}
 
 /// TRNG peripheral.
 pub struct Trng {
     trng: TRNG,
+    health: HealthTestState,
 }
 
 // TODO: Implement with the peripheral API when available.
@@ -15,7 +94,10 @@ impl Trng {
     /// Creates a new TRNG peripheral.
     // TODO: Make this function pub(crate) when the peripheral API is available. Tests needs it public until then.
     pub(crate) fn new(trng: TRNG) -> Self {
-        Self { trng }
+        Self {
+            trng,
+            health: HealthTestState::default(),
+        }
     }
 
     /// Returns a random number.
@@ -31,4 +113,50 @@ impl Trng {
             chunk.copy_from_slice(&random.to_ne_bytes()[..chunk.len()]);
         });
     }
+
+    /// Fills a buffer with random bytes, running the Repetition Count Test
+    /// and Adaptive Proportion Test over the generated bytes as they come
+    /// off the peripheral. Returns as soon as either test trips, rather
+    /// than letting suspect output reach the caller, with `buf` left
+    /// partially filled.
+    pub fn fill_buffer_checked(&mut self, buf: &mut [u8]) -> Result<(), TrngHealthError> {
+        for chunk in buf.chunks_mut(mem::size_of::<u32>()) {
+            let random = self.random_u32().to_ne_bytes();
+            let bytes = &random[..chunk.len()];
+
+            for &byte in bytes {
+                self.health.check(byte)?;
+            }
+
+            chunk.copy_from_slice(bytes);
+        }
+
+        Ok(())
+    }
+}
+
+impl RngCore for Trng {
+    fn next_u32(&mut self) -> u32 {
+        Trng::random_u32(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u32();
+        let lo = self.next_u32();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        Trng::fill_buffer(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
 }
+
+/// Hardware TRNG output is suitable for cryptographic use, so `Trng` can
+/// seed/drive `rand_core`-based crypto crates (e.g. `ed25519_dalek`) without
+/// callers writing their own adapter.
+impl CryptoRng for Trng {}
@@ -0,0 +1,64 @@
+//! Async, interrupt-driven filling for [`Trng`].
+//!
+//! [`Trng::fill_buffer`] busy-polls `status().rdy()` for every word it
+//! draws -- fine for a handful of bytes, but the 80 KiB entropy test pulls
+//! ~20k words this way, parking the core the whole time. This module adds a
+//! non-blocking path that enables the TRNG's ready interrupt and registers a
+//! [`core::task::Waker`], woken from [`on_interrupt`], so an executor can run
+//! other tasks between words -- the same pattern
+//! [`i2c::asynch`](crate::peripherals::i2c::asynch) and
+//! [`i2c_bitbang::asynch`](crate::peripherals::i2c_bitbang::asynch) use for
+//! their peripherals. Callers are responsible for routing the `TRNG` NVIC
+//! interrupt to [`on_interrupt`] from their `#[interrupt]` handler.
+
+use core::future::poll_fn;
+use core::mem;
+use core::task::Poll;
+
+use embassy_sync::waker::AtomicWaker;
+
+use crate::peripherals::trng::Trng;
+
+static TRNG_WAKER: AtomicWaker = AtomicWaker::new();
+
+fn enable_ready_interrupt(trng: &Trng) {
+    trng.trng.ctrl().modify(|_, w| w.rnd_irq_en().bit(true));
+}
+
+fn disable_ready_interrupt(trng: &Trng) {
+    trng.trng.ctrl().modify(|_, w| w.rnd_irq_en().bit(false));
+}
+
+/// Call this from the `TRNG` NVIC handler. Masks the ready interrupt back
+/// off (the future re-arms it on its next poll) and wakes whichever future
+/// is waiting on a word.
+pub fn on_interrupt(trng: &Trng) {
+    disable_ready_interrupt(trng);
+    TRNG_WAKER.wake();
+}
+
+impl Trng {
+    /// Fills a buffer with random bytes without busy-polling: yields to the
+    /// executor between words instead of spinning on `status().rdy()`, so
+    /// latency-sensitive callers can overlap RNG collection with other work.
+    ///
+    /// Behaves identically to [`Self::fill_buffer`] otherwise, including the
+    /// handling of a final partial word.
+    pub async fn fill_buffer_async(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(mem::size_of::<u32>()) {
+            let random = poll_fn(|cx| {
+                TRNG_WAKER.register(cx.waker());
+                enable_ready_interrupt(self);
+
+                if self.trng.status().read().rdy().is_ready() {
+                    Poll::Ready(self.trng.data().read().bits())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+
+            chunk.copy_from_slice(&random.to_ne_bytes()[..chunk.len()]);
+        }
+    }
+}
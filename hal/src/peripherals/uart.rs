@@ -1,7 +1,8 @@
 //! Module for UART API.
 //!
-//! Currently only UART0 is implemented, using pins 0 and 1 as those are connected to the
-//! USB-to-UART bridge on the MAX78000FTHR board.
+//! UART0 uses pins 0 and 1 as those are connected to the USB-to-UART bridge on
+//! the MAX78000FTHR board. UART1/UART2/UART3 are also supported, each wired
+//! to a fixed set of GPIO pins; see [`UartPins`] for the mapping.
 //!
 //! # Example usage
 //!
@@ -29,7 +30,7 @@
 //! // we need timers for the timeout receive methods
 //! let clk0 = manager.timer_0().unwrap();
 //! // 115,200 baud
-//! let mut uart = manager.build_uart().unwrap().build(115200);
+//! let mut uart = manager.build_uart().unwrap().build(115200, UartConfig::default());
 //!! let mut timer = clk0.new_timer(Milliseconds(500));
 //! let mut buf = [0u8; 16];
 //! uart.recv_with_timeout(&mut buf, &mut timer).unwrap();
@@ -39,16 +40,19 @@
 //! uart.send(&mut buf).unwrap();
 //! ```
 
-use core::cell::BorrowMutError;
+use core::cell::{BorrowMutError, RefCell, UnsafeCell};
+use core::convert::Infallible;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use core::{marker::PhantomData, ops::Deref, result::Result};
 
-use embedded_hal::digital::PinState;
+use embedded_hal::digital::{OutputPin, PinState};
+use heapless::Deque;
 use sealed::sealed;
 
 use super::gpio::{
     active::{
-        port_num_types::GpioZero, ActiveInputPin, ActiveInputPinConfig, ActiveOutputPin,
-        ActiveOutputPinConfig, DriveStrength, PowerSupply, PullMode,
+        ActiveInputPinConfig, ActiveOutputPinConfig, AnyInputPin, AnyOutputPin, DriveStrength,
+        PowerSupply, PullMode,
     },
     pin_traits::IoPin,
     GpioError, PinOperatingMode,
@@ -80,12 +84,159 @@ macro_rules! uart_instance_impl {
 }
 
 uart_instance_impl!(Uart0, max78000::UART);
+uart_instance_impl!(Uart1, max78000::UART1);
+uart_instance_impl!(Uart2, max78000::UART2);
+uart_instance_impl!(Uart3, max78000::UART3);
 
-/// Used to configure UART 0
+/// Associates a [`UartInstance`] with the GPIO pin indices and alternate-
+/// function mode its RX/TX lines are wired to, so [`UartBuilder::new`] knows
+/// which pins to claim for a given instance instead of every instance having
+/// to hard-code GPIO0 pins 0/1 the way the original UART0-only builder did.
+/// The pin/alt-function combination is fixed per instance at the type level,
+/// so picking the wrong pins for an instance is a compile error rather than
+/// a runtime one.
+#[sealed]
+pub trait UartPins: UartInstance {
+    /// Pin index of this instance's RX line within its GPIO port.
+    const RX_PIN: usize;
+    /// Pin index of this instance's TX line within its GPIO port.
+    const TX_PIN: usize;
+    /// Alternate-function mode the RX/TX pins must be placed into to route
+    /// them to this instance.
+    const ALT_FUNCTION: PinOperatingMode;
+}
+
+#[sealed]
+impl UartPins for Uart0 {
+    const RX_PIN: usize = 0;
+    const TX_PIN: usize = 1;
+    const ALT_FUNCTION: PinOperatingMode = PinOperatingMode::AltFunction1;
+}
+
+#[sealed]
+impl UartPins for Uart1 {
+    const RX_PIN: usize = 12;
+    const TX_PIN: usize = 13;
+    const ALT_FUNCTION: PinOperatingMode = PinOperatingMode::AltFunction1;
+}
+
+#[sealed]
+impl UartPins for Uart2 {
+    const RX_PIN: usize = 0;
+    const TX_PIN: usize = 1;
+    const ALT_FUNCTION: PinOperatingMode = PinOperatingMode::AltFunction1;
+}
+
+#[sealed]
+impl UartPins for Uart3 {
+    // UART3 is the MAX78000's low-power UART, brought out on GPIO2 pins 6/7
+    // (shared with LPTMR0_CLK/AIN6 and LPTMR1_CLK/AIN7) via AF2 rather than
+    // AF1 like the other three instances.
+    const RX_PIN: usize = 6;
+    const TX_PIN: usize = 7;
+    const ALT_FUNCTION: PinOperatingMode = PinOperatingMode::AltFunction2;
+}
+
+/// Used to configure a [`UartInstance`]
 pub struct UartBuilder<'a, T: UartInstance> {
     uart_regs: PeripheralHandle<'a, T::Registers>,
-    tx: ActiveOutputPin<'a, GpioZero, 31>,
-    rx: ActiveInputPin<'a, GpioZero, 31>,
+    tx: AnyOutputPin<'a>,
+    rx: AnyInputPin<'a>,
+}
+
+/// Number of data bits per UART frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataBits {
+    /// 5 data bits per frame.
+    Five,
+    /// 6 data bits per frame.
+    Six,
+    /// 7 data bits per frame.
+    Seven,
+    /// 8 data bits per frame.
+    #[default]
+    Eight,
+}
+
+/// Parity bit mode for a UART frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parity {
+    /// No parity bit.
+    #[default]
+    None,
+    /// Even parity bit.
+    Even,
+    /// Odd parity bit.
+    Odd,
+}
+
+/// Number of stop bits per UART frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StopBits {
+    /// 1 stop bit.
+    #[default]
+    One,
+    /// 2 stop bits (1.5 stop bits for 5 data bits, per the datasheet).
+    Two,
+}
+
+/// UART frame format, passed to [`UartBuilder::build`]. The `Default` impl
+/// matches the fixed 8N1, non-inverted framing `build` used to hard-code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UartConfig {
+    /// Number of data bits per frame.
+    pub data_bits: DataBits,
+    /// Parity bit mode.
+    pub parity: Parity,
+    /// Number of stop bits per frame.
+    pub stop_bits: StopBits,
+    /// Inverts the RX line's idle/active polarity, for peers that drive
+    /// RS-232-style inverted signaling instead of idle-high UART levels.
+    pub invert_rx: bool,
+    /// Inverts the TX line's idle/active polarity, for peers that expect
+    /// RS-232-style inverted signaling instead of idle-high UART levels.
+    pub invert_tx: bool,
+}
+
+/// Width of the transmitted pulse for one IrDA-encoded bit, as a fraction of
+/// the bit period, passed to [`UartMode::Irda`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IrdaPulseWidth {
+    /// 3/16 of a bit period, the standard IrDA SIR pulse width.
+    #[default]
+    ThreeSixteenths,
+    /// 1/4 of a bit period.
+    OneQuarter,
+    /// 1/2 of a bit period.
+    OneHalf,
+}
+
+/// Selects a special operating mode for [`UartBuilder::build_with_mode`], on
+/// top of the frame format configured by [`UartConfig`].
+pub enum UartMode<'a> {
+    /// Ordinary full-duplex operation; what [`UartBuilder::build`] uses.
+    FullDuplex,
+    /// RS-485 half-duplex, driving `driver_enable` high for the duration of
+    /// each [`TxChannel::send`] call and masking out whatever the UART
+    /// receives while driving, so a loopback-wired transceiver doesn't echo
+    /// the device's own transmission back into the RX path.
+    HalfDuplexRs485 {
+        /// Driver-enable (DE) line for the RS-485 transceiver.
+        driver_enable: AnyOutputPin<'a>,
+    },
+    /// IrDA encode/decode, modulating outgoing bits and demodulating
+    /// incoming ones through an infrared transceiver instead of driving
+    /// RS-232 levels directly.
+    Irda {
+        /// Width of the transmitted pulse for each encoded bit.
+        pulse_width: IrdaPulseWidth,
+    },
+}
+
+impl Default for UartMode<'_> {
+    fn default() -> Self {
+        UartMode::FullDuplex
+    }
 }
 
 /// Error that can be returned while creating a UartBuilders
@@ -122,43 +273,188 @@ impl<'a> UartBuilder<'a, Uart0> {
         // these results have Infallible as the Err type so unwrap is ok
         // pin configs from https://github.com/analogdevicesinc/msdk/blob/c7dc24619e995f17cefd9c776292d318a8a04afb/Libraries/PeriphDrivers/Source/SYS/pins_ai85.c#L45-L46
         let rx = gpio
-            .get_pin_handle(0)?
+            .get_pin_handle(Uart0::RX_PIN)?
             .into_input_pin(ActiveInputPinConfig {
-                operating_mode: PinOperatingMode::AltFunction1,
+                operating_mode: Uart0::ALT_FUNCTION,
                 power_supply: PowerSupply::Vddio,
                 pull_mode: PullMode::HighImpedance,
             })
-            .unwrap();
+            .unwrap()
+            .into();
         let tx = gpio
-            .get_pin_handle(1)?
+            .get_pin_handle(Uart0::TX_PIN)?
             .into_output_pin(
                 PinState::Low,
                 ActiveOutputPinConfig {
-                    operating_mode: PinOperatingMode::AltFunction1,
+                    operating_mode: Uart0::ALT_FUNCTION,
                     power_supply: PowerSupply::Vddio,
                     drive_strength: DriveStrength::S0,
                 },
             )
-            .unwrap();
+            .unwrap()
+            .into();
         Ok(Self {
             uart_regs: peripheral_manager.uart()?,
             rx,
             tx,
         })
     }
+}
+
+impl<'a> UartBuilder<'a, Uart1> {
+    /// Create a [`UartBuilder`] for UART1, claiming GPIO0 pins 12 (RX) and 13 (TX).
+    pub fn new<'pc>(
+        peripheral_manager: &'a PeripheralManager<'pc>,
+    ) -> Result<Self, UartBuilderError>
+    where
+        'a: 'pc,
+    {
+        let gpio = peripheral_manager.gpio0();
+
+        let rx = gpio
+            .get_pin_handle(Uart1::RX_PIN)?
+            .into_input_pin(ActiveInputPinConfig {
+                operating_mode: Uart1::ALT_FUNCTION,
+                power_supply: PowerSupply::Vddio,
+                pull_mode: PullMode::HighImpedance,
+            })
+            .unwrap()
+            .into();
+        let tx = gpio
+            .get_pin_handle(Uart1::TX_PIN)?
+            .into_output_pin(
+                PinState::Low,
+                ActiveOutputPinConfig {
+                    operating_mode: Uart1::ALT_FUNCTION,
+                    power_supply: PowerSupply::Vddio,
+                    drive_strength: DriveStrength::S0,
+                },
+            )
+            .unwrap()
+            .into();
+        Ok(Self {
+            uart_regs: peripheral_manager.uart1()?,
+            rx,
+            tx,
+        })
+    }
+}
+
+impl<'a> UartBuilder<'a, Uart2> {
+    /// Create a [`UartBuilder`] for UART2, claiming GPIO1 pins 0 (RX) and 1 (TX).
+    pub fn new<'pc>(
+        peripheral_manager: &'a PeripheralManager<'pc>,
+    ) -> Result<Self, UartBuilderError>
+    where
+        'a: 'pc,
+    {
+        let gpio = peripheral_manager.gpio1();
+
+        let rx = gpio
+            .get_pin_handle(Uart2::RX_PIN)?
+            .into_input_pin(ActiveInputPinConfig {
+                operating_mode: Uart2::ALT_FUNCTION,
+                power_supply: PowerSupply::Vddio,
+                pull_mode: PullMode::HighImpedance,
+            })
+            .unwrap()
+            .into();
+        let tx = gpio
+            .get_pin_handle(Uart2::TX_PIN)?
+            .into_output_pin(
+                PinState::Low,
+                ActiveOutputPinConfig {
+                    operating_mode: Uart2::ALT_FUNCTION,
+                    power_supply: PowerSupply::Vddio,
+                    drive_strength: DriveStrength::S0,
+                },
+            )
+            .unwrap()
+            .into();
+        Ok(Self {
+            uart_regs: peripheral_manager.uart2()?,
+            rx,
+            tx,
+        })
+    }
+}
 
-    /// Set up and return a UART instance for the given baud rate
-    pub fn build(self, baud: u32) -> Uart<'a, Uart0> {
+impl<'a> UartBuilder<'a, Uart3> {
+    /// Create a [`UartBuilder`] for UART3, the MAX78000's low-power UART,
+    /// claiming GPIO2 pins 6 (RX) and 7 (TX).
+    pub fn new<'pc>(
+        peripheral_manager: &'a PeripheralManager<'pc>,
+    ) -> Result<Self, UartBuilderError>
+    where
+        'a: 'pc,
+    {
+        let gpio = peripheral_manager.gpio2();
+
+        let rx = gpio
+            .get_pin_handle(Uart3::RX_PIN)?
+            .into_input_pin(ActiveInputPinConfig {
+                operating_mode: Uart3::ALT_FUNCTION,
+                power_supply: PowerSupply::Vddio,
+                pull_mode: PullMode::HighImpedance,
+            })
+            .unwrap()
+            .into();
+        let tx = gpio
+            .get_pin_handle(Uart3::TX_PIN)?
+            .into_output_pin(
+                PinState::Low,
+                ActiveOutputPinConfig {
+                    operating_mode: Uart3::ALT_FUNCTION,
+                    power_supply: PowerSupply::Vddio,
+                    drive_strength: DriveStrength::S0,
+                },
+            )
+            .unwrap()
+            .into();
+        Ok(Self {
+            uart_regs: peripheral_manager.uart3()?,
+            rx,
+            tx,
+        })
+    }
+}
+
+impl<'a, T: UartInstance> UartBuilder<'a, T> {
+    /// Set up and return a UART instance for the given baud rate and frame
+    /// format, in ordinary full-duplex operation. Equivalent to
+    /// [`Self::build_with_mode`] with [`UartMode::FullDuplex`].
+    pub fn build(self, baud: u32, config: UartConfig) -> Uart<'a, T> {
+        self.build_with_mode(baud, config, UartMode::FullDuplex)
+    }
+
+    /// Set up and return a UART instance for the given baud rate, frame
+    /// format, and operating mode. See [`UartMode`] for the RS-485
+    /// half-duplex and IrDA modes this makes available on top of ordinary
+    /// full-duplex operation.
+    pub fn build_with_mode(self, baud: u32, config: UartConfig, mode: UartMode<'a>) -> Uart<'a, T> {
         const IBRO_FREQUENCY: u32 = 7372800;
+        self.uart_regs
+            .ctrl()
+            .modify(|_r, w| match config.data_bits {
+                DataBits::Five => w.char_size()._5bits(),
+                DataBits::Six => w.char_size()._6bits(),
+                DataBits::Seven => w.char_size()._7bits(),
+                DataBits::Eight => w.char_size()._8bits(),
+            });
+
         self.uart_regs.ctrl().modify(|_r, w| {
             w.rx_thd_val()
                 .variant(1)
-                .char_size()
-                ._8bits() // 8-bit character length
                 .par_en()
-                .variant(false) // No parity bit
+                .variant(config.parity != Parity::None) // parity bit enable
+                .par_eo()
+                .bit(config.parity == Parity::Odd) // even/odd parity select
                 .stopbits()
-                .bit(false) // 1 stop bit
+                .bit(config.stop_bits == StopBits::Two)
+                .rx_inv()
+                .bit(config.invert_rx)
+                .tx_inv()
+                .bit(config.invert_tx)
                 .bclksrc()
                 .clk2() // use IBRO
         });
@@ -170,6 +466,32 @@ impl<'a> UartBuilder<'a, Uart0> {
             .clkdiv()
             .modify(|_r, w| w.clkdiv().variant(IBRO_FREQUENCY.div_ceil(baud)));
 
+        let driver_enable = match mode {
+            UartMode::FullDuplex => None,
+            UartMode::HalfDuplexRs485 {
+                mut driver_enable, ..
+            } => {
+                // Deasserted (not driving) until the first send.
+                let _ = driver_enable.set_low();
+                Some(driver_enable)
+            }
+            // NOTE: the IrDA enable bit and pulse-width field names below are
+            // a best-effort guess at the register layout; there's no PAC
+            // available in this environment to check them against the
+            // datasheet.
+            UartMode::Irda { pulse_width } => {
+                self.uart_regs.ctrl().modify(|_r, w| w.irda_en().bit(true));
+                self.uart_regs.ctrl().modify(|_r, w| {
+                    w.irda_pulse_wid().variant(match pulse_width {
+                        IrdaPulseWidth::ThreeSixteenths => 0,
+                        IrdaPulseWidth::OneQuarter => 1,
+                        IrdaPulseWidth::OneHalf => 2,
+                    })
+                });
+                None
+            }
+        };
+
         // Enable the baud clock after setting clock divider.
         self.uart_regs.ctrl().modify(|_r, w| w.bclken().set_bit());
 
@@ -181,6 +503,7 @@ impl<'a> UartBuilder<'a, Uart0> {
             _tx: self.tx,
             _rx: self.rx,
             _uart_instance: Default::default(),
+            driver_enable,
         }
     }
 }
@@ -188,9 +511,43 @@ impl<'a> UartBuilder<'a, Uart0> {
 /// A running UART instance
 pub struct Uart<'a, T: UartInstance> {
     regs: PeripheralHandle<'a, T::Registers>,
-    _tx: ActiveOutputPin<'a, GpioZero, 31>,
-    _rx: ActiveInputPin<'a, GpioZero, 31>,
+    _tx: AnyOutputPin<'a>,
+    _rx: AnyInputPin<'a>,
     _uart_instance: PhantomData<T>,
+    /// Driver-enable line for [`UartMode::HalfDuplexRs485`]; `None` in
+    /// full-duplex and IrDA operation.
+    driver_enable: Option<AnyOutputPin<'a>>,
+}
+
+impl<T: UartInstance> Uart<'_, T> {
+    /// Asserts the RS-485 driver-enable line, if configured, ahead of
+    /// clocking bytes out.
+    fn assert_driver_enable(&mut self) {
+        if let Some(driver_enable) = self.driver_enable.as_mut() {
+            let _ = driver_enable.set_high();
+        }
+    }
+
+    /// Waits for the TX path to finish shifting out, deasserts the RS-485
+    /// driver-enable line, and discards whatever the UART received while
+    /// driving, so a transceiver that loops the transmission back onto RX
+    /// doesn't leave an echo sitting in the FIFO for the next read. No-op in
+    /// full-duplex and IrDA operation.
+    fn finish_driver_enable(&mut self) {
+        if self.driver_enable.is_none() {
+            return;
+        }
+
+        while self.regs.status().read().tx_busy().bit() {}
+
+        if let Some(driver_enable) = self.driver_enable.as_mut() {
+            let _ = driver_enable.set_low();
+        }
+
+        while !self.regs.status().read().rx_em().bit() {
+            let _ = self.regs.fifo().read().data().bits();
+        }
+    }
 }
 
 impl<T: UartInstance> Uart<'_, T> {
@@ -250,6 +607,10 @@ impl<T: UartInstance> RxChannel for Uart<'_, T> {
     ) -> CommunicationResult<usize> {
         self.internal_recv::<false, false>(dest, tmr, LineEnding::CR)
     }
+
+    fn try_recv(&mut self, dest: &mut [u8]) -> CommunicationResult<usize> {
+        Ok(self.drain_fifo(dest))
+    }
 }
 
 impl<T: UartInstance> LineDelimitedRxChannel for Uart<'_, T> {
@@ -278,13 +639,415 @@ impl<T: UartInstance> LineDelimitedRxChannel for Uart<'_, T> {
     }
 }
 
+impl<T: UartInstance> Uart<'_, T> {
+    #[inline(always)]
+    fn internal_send<const RESET_EVERY_BYTE: bool>(
+        &mut self,
+        src: &mut [u8],
+        tmr: &mut impl Timeout,
+    ) -> CommunicationResult<usize> {
+        self.assert_driver_enable();
+
+        let mut sent = 0;
+        for &byte in src.iter() {
+            while self.regs.status().read().tx_full().bit() {
+                if tmr.poll() {
+                    self.finish_driver_enable();
+                    return Ok(sent);
+                }
+            }
+
+            self.regs.fifo().modify(|_r, w| w.data().variant(byte));
+            sent += 1;
+
+            if RESET_EVERY_BYTE {
+                tmr.reset();
+            }
+        }
+
+        self.finish_driver_enable();
+        Ok(sent)
+    }
+}
+
 impl<T: UartInstance> TxChannel for Uart<'_, T> {
     fn send(&mut self, src: &mut [u8]) -> CommunicationResult<()> {
+        self.assert_driver_enable();
+
         for &byte in src.iter() {
             while self.regs.status().read().tx_full().bit() {}
             self.regs.fifo().modify(|_r, w| w.data().variant(byte));
         }
 
+        self.finish_driver_enable();
         Ok(())
     }
+
+    fn send_with_timeout<U: Timeout>(
+        &mut self,
+        src: &mut [u8],
+        tmr: &mut U,
+    ) -> CommunicationResult<usize> {
+        self.internal_send::<false>(src, tmr)
+    }
+
+    fn send_with_data_timeout<U: Timeout>(
+        &mut self,
+        src: &mut [u8],
+        tmr: &mut U,
+    ) -> CommunicationResult<usize> {
+        self.internal_send::<true>(src, tmr)
+    }
+}
+
+impl<T: UartInstance> Uart<'_, T> {
+    /// Drains whatever is currently sitting in the RX FIFO into `dest`,
+    /// without waiting for more bytes to arrive. Returns the number of
+    /// bytes copied, which may be `0` if the FIFO was empty.
+    fn drain_fifo(&mut self, dest: &mut [u8]) -> usize {
+        let mut index = 0;
+        while index < dest.len() && !self.regs.status().read().rx_em().bit() {
+            if self.regs.int_fl().read().rx_ov().bit() {
+                panic!("rx fifo overrun");
+            }
+
+            dest[index] = self.regs.fifo().read().data().bits();
+            index += 1;
+        }
+
+        index
+    }
+}
+
+impl<T: UartInstance> embedded_io::ErrorType for Uart<'_, T> {
+    type Error = Infallible;
+}
+
+impl<T: UartInstance> embedded_io::Read for Uart<'_, T> {
+    /// Blocks until at least one byte has arrived, then drains as much of
+    /// the RX FIFO into `buf` as fits, with no timeout. Unlike
+    /// [`RxChannel::recv_with_timeout`], it does not wait for `buf` to fill;
+    /// [`embedded_io::Read::read_exact`] is built on
+    /// top of this for callers that want to block for a whole buffer.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.regs.status().read().rx_em().bit() {}
+        Ok(self.drain_fifo(buf))
+    }
+}
+
+impl<T: UartInstance> embedded_io::Write for Uart<'_, T> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for &byte in buf {
+            while self.regs.status().read().tx_full().bit() {}
+            self.regs.fifo().modify(|_r, w| w.data().variant(byte));
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        while self.regs.status().read().tx_busy().bit() {}
+        Ok(())
+    }
+}
+
+#[cfg(feature = "eh-nb-serial")]
+impl<T: UartInstance> embedded_hal_nb::serial::ErrorType for Uart<'_, T> {
+    type Error = Infallible;
+}
+
+#[cfg(feature = "eh-nb-serial")]
+impl<T: UartInstance> embedded_hal_nb::serial::Read<u8> for Uart<'_, T> {
+    /// Returns [`nb::Error::WouldBlock`] while the RX FIFO is empty, instead
+    /// of spinning the way [`RxChannel::recv_with_timeout`] does.
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        if self.regs.status().read().rx_em().bit() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        Ok(self.regs.fifo().read().data().bits())
+    }
+}
+
+#[cfg(feature = "eh-nb-serial")]
+impl<T: UartInstance> embedded_hal_nb::serial::Write<u8> for Uart<'_, T> {
+    /// Returns [`nb::Error::WouldBlock`] while the TX FIFO is full, instead
+    /// of spinning the way [`TxChannel::send`] does.
+    fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        if self.regs.status().read().tx_full().bit() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.regs.fifo().modify(|_r, w| w.data().variant(word));
+        Ok(())
+    }
+
+    /// Returns [`nb::Error::WouldBlock`] while a transaction is still being
+    /// shifted out.
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        if self.regs.status().read().tx_busy().bit() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        Ok(())
+    }
+}
+
+/// Depth of the ring buffer [`on_interrupt`] stages bytes into for
+/// [`InterruptUartRx`]. Sized generously for line-at-a-time host chatter;
+/// a full ring simply drops the oldest byte to make room for the newest.
+const RX_RING_CAPACITY: usize = 128;
+
+/// Ring buffer shared between [`on_interrupt`] (the producer) and
+/// [`InterruptUartRx::poll`] (the consumer).
+static RX_RING: critical_section::Mutex<RefCell<Deque<u8, RX_RING_CAPACITY>>> =
+    critical_section::Mutex::new(RefCell::new(Deque::new()));
+
+/// Call this from the `UARTn` NVIC handler. Drains every byte currently
+/// sitting in the RX FIFO into [`RX_RING`], dropping the oldest buffered
+/// byte to make room if the ring is full, then re-arms the RX-threshold
+/// interrupt flag.
+pub fn on_interrupt<T: UartInstance>(regs: &T::Registers) {
+    critical_section::with(|cs| {
+        let mut ring = RX_RING.borrow_ref_mut(cs);
+        while !regs.status().read().rx_em().bit() {
+            if regs.int_fl().read().rx_ov().bit() {
+                regs.int_fl().write(|w| w.rx_ov().bit(true));
+            }
+
+            let byte = regs.fifo().read().data().bits();
+            if ring.is_full() {
+                ring.pop_front();
+            }
+            let _ = ring.push_back(byte);
+        }
+
+        regs.int_fl().write(|w| w.rx_thd().bit(true));
+    });
+}
+
+/// An interrupt-driven UART receiver. Instead of busy-waiting on the RX
+/// FIFO the way [`Uart`]'s blocking methods do, this enables the RX-FIFO-
+/// threshold interrupt and lets [`on_interrupt`] buffer incoming bytes into
+/// a ring buffer, which [`poll`](Self::poll) then drains non-blockingly.
+pub struct InterruptUartRx<'a, T: UartInstance> {
+    uart: Uart<'a, T>,
+}
+
+impl<'a, T: UartInstance> InterruptUartRx<'a, T> {
+    /// Wraps `uart`, enabling the RX-FIFO-threshold and overrun interrupts
+    /// so [`on_interrupt`] starts buffering bytes as they arrive.
+    pub fn new(uart: Uart<'a, T>) -> Self {
+        uart.regs
+            .inten()
+            .modify(|_r, w| w.rx_thd().bit(true).rx_ov().bit(true));
+
+        Self { uart }
+    }
+
+    /// Non-blockingly copies as many buffered bytes as are available into
+    /// `dest`, returning how many were copied. Returns `0` immediately if
+    /// nothing has been buffered since the last call.
+    pub fn poll(&mut self, dest: &mut [u8]) -> usize {
+        critical_section::with(|cs| {
+            let mut ring = RX_RING.borrow_ref_mut(cs);
+            let mut index = 0;
+            while index < dest.len() {
+                match ring.pop_front() {
+                    Some(byte) => {
+                        dest[index] = byte;
+                        index += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            index
+        })
+    }
+
+    /// Disables the RX-FIFO-threshold and overrun interrupts and hands back
+    /// the underlying [`Uart`]. Bytes already buffered in the ring are left
+    /// in place for the next [`InterruptUartRx`] to pick up.
+    pub fn release(self) -> Uart<'a, T> {
+        self.uart
+            .regs
+            .inten()
+            .modify(|_r, w| w.rx_thd().bit(false).rx_ov().bit(false));
+
+        self.uart
+    }
+}
+
+/// Lock-free single-producer/single-consumer byte ring over a caller-supplied
+/// buffer. [`BufferedUart::service_interrupt`] is the sole producer and
+/// [`BufferedUart::read`] is the sole consumer, so plain atomic loads/stores
+/// on the head/tail indices hand bytes across without needing a critical
+/// section on every byte, unlike [`RX_RING`]'s `Mutex<RefCell<_>>`. One slot
+/// of `buf` is always left empty to distinguish a full ring from an empty
+/// one, so a ring needs at least 2 bytes of backing storage to hold any data.
+struct RingBuffer<'a> {
+    buf: UnsafeCell<&'a mut [u8]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+impl<'a> RingBuffer<'a> {
+    fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf: UnsafeCell::new(buf),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        // SAFETY: length never changes after construction, so reading it
+        // through the shared reference here can't race the producer/consumer
+        // writes to individual slots below.
+        unsafe { (*self.buf.get()).len() }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire) == self.tail.load(Ordering::Acquire)
+    }
+
+    fn is_full(&self) -> bool {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        (tail + 1) % self.capacity() == head
+    }
+
+    /// Pushes `byte` onto the ring, returning `false` without writing it if
+    /// the ring is already full.
+    fn push(&self, byte: u8) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        let tail = self.tail.load(Ordering::Relaxed);
+        // SAFETY: only the producer ever writes to `buf[tail]`, and it only
+        // advances `tail` (below, with Release) after the write is visible,
+        // so the consumer never observes a torn slot.
+        unsafe { (*self.buf.get())[tail] = byte };
+        self.tail
+            .store((tail + 1) % self.capacity(), Ordering::Release);
+        true
+    }
+
+    /// Pops the oldest byte off the ring, or `None` if it's empty.
+    fn pop(&self) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = self.head.load(Ordering::Relaxed);
+        // SAFETY: only the consumer ever reads/writes `buf[head]`, and the
+        // Acquire load of `tail` in `is_empty` synchronizes with the
+        // producer's Release store, so the byte at `head` is visible here.
+        let byte = unsafe { (*self.buf.get())[head] };
+        self.head
+            .store((head + 1) % self.capacity(), Ordering::Release);
+        Some(byte)
+    }
+}
+
+/// An interrupt-buffered UART, combining non-blocking [`Self::read`] and
+/// [`Self::write`] with a caller-supplied backing buffer instead of
+/// [`InterruptUartRx`]'s fixed-size static ring. [`Self::service_interrupt`]
+/// drains the RX FIFO into the ring on every call rather than panicking on
+/// overrun like [`Uart::read`](embedded_io::Read::read) does, simply
+/// declining to buffer bytes once the ring fills up so the reader can still
+/// catch up on what arrived before the overrun.
+pub struct BufferedUart<'a, T: UartInstance> {
+    uart: Uart<'a, T>,
+    rx_ring: RingBuffer<'a>,
+}
+
+impl<'a, T: UartInstance> BufferedUart<'a, T> {
+    /// Wraps `uart`, staging received bytes into `rx_buf` (at least 2 bytes)
+    /// via the RX-FIFO-threshold and overrun interrupts. Call
+    /// [`Self::service_interrupt`] from the `UARTn` NVIC handler to actually
+    /// drain the hardware FIFO into `rx_buf`.
+    pub fn new(uart: Uart<'a, T>, rx_buf: &'a mut [u8]) -> Self {
+        uart.regs
+            .inten()
+            .modify(|_r, w| w.rx_thd().bit(true).rx_ov().bit(true));
+
+        Self {
+            uart,
+            rx_ring: RingBuffer::new(rx_buf),
+        }
+    }
+
+    /// Call this from the `UARTn` NVIC handler. Drains every byte currently
+    /// sitting in the RX FIFO into the ring buffer, declining to buffer any
+    /// more once it's full rather than overwriting what's already there,
+    /// then re-arms the RX-threshold interrupt flag.
+    pub fn service_interrupt(&self) {
+        let regs = &self.uart.regs;
+        while !regs.status().read().rx_em().bit() {
+            if regs.int_fl().read().rx_ov().bit() {
+                regs.int_fl().write(|w| w.rx_ov().bit(true));
+            }
+
+            if !self.rx_ring.push(regs.fifo().read().data().bits()) {
+                break;
+            }
+        }
+
+        regs.int_fl().write(|w| w.rx_thd().bit(true));
+    }
+
+    /// Non-blockingly copies as many buffered bytes as are available into
+    /// `dest`, returning how many were copied. Returns `0` immediately if
+    /// nothing has been buffered since the last call.
+    pub fn read(&self, dest: &mut [u8]) -> usize {
+        let mut index = 0;
+        while index < dest.len() {
+            match self.rx_ring.pop() {
+                Some(byte) => {
+                    dest[index] = byte;
+                    index += 1;
+                }
+                None => break,
+            }
+        }
+
+        index
+    }
+
+    /// Non-blockingly writes as much of `src` as fits in the TX FIFO right
+    /// now, returning how many bytes were written. Stops as soon as the FIFO
+    /// is full instead of busy-waiting like [`Uart::send`](TxChannel::send)
+    /// does.
+    pub fn write(&mut self, src: &[u8]) -> usize {
+        let mut index = 0;
+        for &byte in src {
+            if self.uart.regs.status().read().tx_full().bit() {
+                break;
+            }
+
+            self.uart.regs.fifo().modify(|_r, w| w.data().variant(byte));
+            index += 1;
+        }
+
+        index
+    }
+
+    /// Disables the RX-FIFO-threshold and overrun interrupts and hands back
+    /// the underlying [`Uart`]. Bytes already buffered in the ring are
+    /// dropped.
+    pub fn release(self) -> Uart<'a, T> {
+        self.uart
+            .regs
+            .inten()
+            .modify(|_r, w| w.rx_thd().bit(false).rx_ov().bit(false));
+
+        self.uart
+    }
 }
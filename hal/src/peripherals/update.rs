@@ -0,0 +1,522 @@
+#![cfg(feature = "flc-ram")]
+//! A/B dual-slot firmware update subsystem.
+//!
+//! Manages two firmware slots, [`SlotId::A`] and [`SlotId::B`], in the flash
+//! region immediately following the bootloader's first page, plus a small
+//! metadata log recording each slot's length, version, and
+//! pending/confirmed/empty state. A new image is streamed into the inactive
+//! slot page-by-page with [`SlotWriter`], which only marks it
+//! [`SlotState::Pending`] once [`bootloader::verify_image`] has checked its
+//! trailing [`bootloader::ImageFooter`] signature; [`select_boot_slot`] then
+//! picks the newest confirmed-or-pending slot for the bootloader to verify
+//! again and hand off to at boot time. [`record_boot_attempt`] tracks how
+//! many times a pending slot has been tried and rolls it back (demoting it
+//! to [`SlotState::Empty`]) once [`MAX_BOOT_ATTEMPTS`] is reached without a
+//! [`confirm_slot`] call, so a bad update automatically falls back to the
+//! last known-good, already-confirmed slot instead of boot-looping forever.
+//!
+//! This mirrors the bootloader + flashloader split in projects like
+//! va416xx-rs, giving power-fail-safe OTA-style updates. The metadata log is
+//! ping-ponged across two pages so that committing new metadata never
+//! requires erasing the page holding the currently-valid record, and within
+//! a record the slot data words are written before the header word that
+//! makes the record valid, so a reset mid-commit leaves the previous record
+//! as the latest valid one instead of bricking the device.
+
+use crate::peripherals::bootloader::{self, never_exit};
+use crate::peripherals::flash_controller::{
+    FlashController, FlashErr, FLASH_MEM_BASE, FLASH_MEM_SIZE, FLASH_PAGE_SIZE,
+};
+use crate::peripherals::oscillator::SystemClock;
+#[cfg(feature = "fip")]
+use crate::peripherals::rand_chacha::ChaChaRng;
+#[cfg(feature = "fip")]
+use fault_injection_protection_arm::FaultInjectionPrevention;
+
+/// Size in bytes of one firmware slot.
+pub const SLOT_SIZE: u32 = 30 * FLASH_PAGE_SIZE;
+
+/// Start address of slot A, immediately after the bootloader's first page.
+pub const SLOT_A_ADDR: u32 = FLASH_MEM_BASE + FLASH_PAGE_SIZE;
+
+/// Start address of slot B, immediately after slot A.
+pub const SLOT_B_ADDR: u32 = SLOT_A_ADDR + SLOT_SIZE;
+
+/// First of the two ping-ponged metadata pages, at the end of flash.
+const META_PAGE_0: u32 = FLASH_MEM_BASE + FLASH_MEM_SIZE - 2 * FLASH_PAGE_SIZE;
+/// Second of the two ping-ponged metadata pages.
+const META_PAGE_1: u32 = META_PAGE_0 + FLASH_PAGE_SIZE;
+
+const _: () = assert!(SLOT_B_ADDR + SLOT_SIZE <= META_PAGE_0);
+
+/// Number of unconfirmed boot attempts a [`SlotState::Pending`] slot is given
+/// before [`record_boot_attempt`] rolls it back to [`SlotState::Empty`].
+const MAX_BOOT_ATTEMPTS: u8 = 3;
+
+/// Magic identifying a valid metadata record header.
+const RECORD_MAGIC: [u8; 4] = *b"UPDR";
+/// Magic identifying a valid per-slot metadata word.
+const SLOT_MAGIC: [u8; 4] = *b"SLOT";
+
+/// Length in bytes of one 128-bit flash word, the atomic unit the FLC can program.
+const WORD_LEN: u32 = 16;
+/// A metadata record is a header word followed by one word per slot.
+const RECORD_LEN: u32 = WORD_LEN * 3;
+
+/// Identifies one of the two firmware slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotId {
+    /// Slot A, `[SLOT_A_ADDR, SLOT_A_ADDR + SLOT_SIZE)`.
+    A,
+    /// Slot B, `[SLOT_B_ADDR, SLOT_B_ADDR + SLOT_SIZE)`.
+    B,
+}
+
+impl SlotId {
+    /// The slot other than this one, i.e. the one to stage a new image into.
+    pub fn other(self) -> Self {
+        match self {
+            SlotId::A => SlotId::B,
+            SlotId::B => SlotId::A,
+        }
+    }
+
+    /// The start address of this slot in flash.
+    pub fn base_addr(self) -> u32 {
+        match self {
+            SlotId::A => SLOT_A_ADDR,
+            SlotId::B => SLOT_B_ADDR,
+        }
+    }
+}
+
+/// Lifecycle state of a slot, persisted in the metadata log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotState {
+    /// The slot holds no image worth booting.
+    Empty,
+    /// A freshly-written image that should be tried on the next boot.
+    Pending,
+    /// An image that has already booted successfully at least once.
+    Confirmed,
+}
+
+impl SlotState {
+    fn to_byte(self) -> u8 {
+        match self {
+            SlotState::Empty => 0,
+            SlotState::Pending => 1,
+            SlotState::Confirmed => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(SlotState::Empty),
+            1 => Some(SlotState::Pending),
+            2 => Some(SlotState::Confirmed),
+            _ => None,
+        }
+    }
+}
+
+/// Persisted metadata for a single slot.
+#[derive(Debug, Clone, Copy)]
+pub struct SlotMetadata {
+    /// Total length in bytes written to the slot, image plus its trailing
+    /// [`bootloader::ImageFooter`] signature -- i.e. what
+    /// [`bootloader::verify_image`] expects as `total_len`.
+    pub length: u32,
+    /// Caller-defined monotonic version number, used to pick the newer of
+    /// two otherwise-equally-trusted slots.
+    pub version: u32,
+    /// The slot's lifecycle state.
+    pub state: SlotState,
+    /// Number of times [`record_boot_attempt`] has been called for this slot
+    /// since it last became [`SlotState::Pending`], without an intervening
+    /// [`confirm_slot`].
+    pub attempts: u8,
+}
+
+impl SlotMetadata {
+    const EMPTY: Self = Self {
+        length: 0,
+        version: 0,
+        state: SlotState::Empty,
+        attempts: 0,
+    };
+
+    fn to_word(self) -> [u8; WORD_LEN as usize] {
+        let mut word = [0u8; WORD_LEN as usize];
+        word[0..4].copy_from_slice(&SLOT_MAGIC);
+        word[4..8].copy_from_slice(&self.length.to_le_bytes());
+        word[8..12].copy_from_slice(&self.version.to_le_bytes());
+        word[12] = self.state.to_byte();
+        word[13] = self.attempts;
+        word
+    }
+
+    fn from_word(word: &[u8; WORD_LEN as usize]) -> Option<Self> {
+        if word[0..4] != SLOT_MAGIC {
+            return None;
+        }
+        Some(Self {
+            length: u32::from_le_bytes(word[4..8].try_into().unwrap()),
+            version: u32::from_le_bytes(word[8..12].try_into().unwrap()),
+            state: SlotState::from_byte(word[12])?,
+            attempts: word[13],
+        })
+    }
+}
+
+/// Failure reasons for the update subsystem.
+#[derive(Debug)]
+pub enum UpdateError {
+    /// A flash read, write, or erase failed.
+    Flash(FlashErr),
+    /// The staged image does not fit within a single slot.
+    ImageTooLarge,
+}
+
+// Note: a failed [`bootloader::verify_image`] in [`SlotWriter::finish`] does
+// not become an `UpdateError` variant -- it instead routes into
+// [`never_exit`], per this subsystem's threat model, so there is nothing for
+// a caller to recover from and no code path exists to fall through on.
+
+impl From<FlashErr> for UpdateError {
+    fn from(err: FlashErr) -> Self {
+        UpdateError::Flash(err)
+    }
+}
+
+/// Both slots' metadata as recorded in the most recent valid log entry.
+#[derive(Debug, Clone, Copy)]
+struct MetadataLog {
+    seq: u32,
+    slot_a: SlotMetadata,
+    slot_b: SlotMetadata,
+}
+
+impl MetadataLog {
+    fn get(&self, slot: SlotId) -> SlotMetadata {
+        match slot {
+            SlotId::A => self.slot_a,
+            SlotId::B => self.slot_b,
+        }
+    }
+
+    /// Returns a copy of this log with `slot`'s metadata replaced and the
+    /// sequence number advanced, ready to be appended by [`commit_metadata`].
+    fn with(mut self, slot: SlotId, metadata: SlotMetadata) -> Self {
+        match slot {
+            SlotId::A => self.slot_a = metadata,
+            SlotId::B => self.slot_b = metadata,
+        }
+        self.seq = self.seq.wrapping_add(1);
+        self
+    }
+}
+
+impl Default for MetadataLog {
+    /// The log as it reads before any record has ever been committed: both
+    /// slots empty, sequence number `0` (the first committed record is always `1`).
+    fn default() -> Self {
+        Self {
+            seq: 0,
+            slot_a: SlotMetadata::EMPTY,
+            slot_b: SlotMetadata::EMPTY,
+        }
+    }
+}
+
+/// Reads one metadata record at `addr`, returning `None` if it is not a
+/// valid, fully-committed record (including a blank, erased one).
+fn read_record(addr: u32) -> Result<Option<MetadataLog>, FlashErr> {
+    let mut header = [0u8; WORD_LEN as usize];
+    FlashController::read_bytes(addr, &mut header)?;
+    if header[0..4] != RECORD_MAGIC {
+        return Ok(None);
+    }
+
+    let mut slot_a_word = [0u8; WORD_LEN as usize];
+    FlashController::read_bytes(addr + WORD_LEN, &mut slot_a_word)?;
+    let mut slot_b_word = [0u8; WORD_LEN as usize];
+    FlashController::read_bytes(addr + 2 * WORD_LEN, &mut slot_b_word)?;
+
+    let (Some(slot_a), Some(slot_b)) = (
+        SlotMetadata::from_word(&slot_a_word),
+        SlotMetadata::from_word(&slot_b_word),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(MetadataLog {
+        seq: u32::from_le_bytes(header[4..8].try_into().unwrap()),
+        slot_a,
+        slot_b,
+    }))
+}
+
+/// Scans both metadata pages for the most recent valid record, returning it
+/// (or the all-empty default, if neither page has one yet) along with the
+/// page and offset the next record should be appended at.
+fn scan_metadata() -> Result<(MetadataLog, u32, u32), FlashErr> {
+    let mut latest: Option<(MetadataLog, u32)> = None;
+    let mut end_of_log = [META_PAGE_0, META_PAGE_1];
+
+    for (page_idx, &page) in [META_PAGE_0, META_PAGE_1].iter().enumerate() {
+        let mut addr = page;
+        while addr + RECORD_LEN <= page + FLASH_PAGE_SIZE {
+            let Some(record) = read_record(addr)? else {
+                break;
+            };
+            let is_newer = match latest {
+                Some((best, _)) => record.seq > best.seq,
+                None => true,
+            };
+            if is_newer {
+                latest = Some((record, addr));
+            }
+            addr += RECORD_LEN;
+        }
+        end_of_log[page_idx] = addr;
+    }
+
+    match latest {
+        Some((record, addr)) => {
+            let page_idx = usize::from(addr >= META_PAGE_1);
+            Ok((record, [META_PAGE_0, META_PAGE_1][page_idx], end_of_log[page_idx]))
+        }
+        None => Ok((MetadataLog::default(), META_PAGE_0, META_PAGE_0)),
+    }
+}
+
+/// Appends `log` as a new record, rolling over to the other metadata page
+/// (erasing it first) if the active page is full.
+///
+/// The header word, which is what makes a record valid, is written last so a
+/// reset partway through this function leaves the previous record as the
+/// latest valid one instead of a torn, half-written record. Uses
+/// [`FlashController::page_erase_verified`]/[`FlashController::write_verified`]
+/// rather than their unverified counterparts, since a metadata record that
+/// silently failed to program is exactly as dangerous as a torn one but
+/// wouldn't be caught by the "header written last" ordering.
+fn commit_metadata(
+    flash: &FlashController,
+    sys_clk: &SystemClock,
+    log: MetadataLog,
+) -> Result<(), UpdateError> {
+    let (_, active_page, next_addr) = scan_metadata()?;
+
+    let write_addr = if next_addr + RECORD_LEN <= active_page + FLASH_PAGE_SIZE {
+        next_addr
+    } else {
+        let other_page = if active_page == META_PAGE_0 {
+            META_PAGE_1
+        } else {
+            META_PAGE_0
+        };
+        // SAFETY: `other_page` is never the page `scan_metadata` just found
+        // the latest valid record on, so erasing it cannot discard it.
+        unsafe {
+            flash.page_erase_verified(other_page, sys_clk)?;
+        }
+        other_page
+    };
+
+    // SAFETY: `write_addr..write_addr + RECORD_LEN` lies within the metadata
+    // pages reserved above the firmware slots, never in instruction memory.
+    unsafe {
+        flash.write_verified(write_addr + WORD_LEN, &log.slot_a.to_word(), sys_clk)?;
+        flash.write_verified(write_addr + 2 * WORD_LEN, &log.slot_b.to_word(), sys_clk)?;
+    }
+
+    let mut header = [0u8; WORD_LEN as usize];
+    header[0..4].copy_from_slice(&RECORD_MAGIC);
+    header[4..8].copy_from_slice(&log.seq.to_le_bytes());
+    // SAFETY: as above; writing this word last is what makes the record
+    // observable to `scan_metadata`.
+    unsafe {
+        flash.write_verified(write_addr, &header, sys_clk)?;
+    }
+
+    Ok(())
+}
+
+/// Streams a new firmware image into a slot, one flash page at a time.
+pub struct SlotWriter<'a, 'gcr, 'icc> {
+    flash: &'a FlashController<'gcr, 'icc>,
+    slot: SlotId,
+    offset: u32,
+}
+
+impl<'a, 'gcr, 'icc> SlotWriter<'a, 'gcr, 'icc> {
+    /// Begins staging a new image into `slot`.
+    pub fn new(flash: &'a FlashController<'gcr, 'icc>, slot: SlotId) -> Self {
+        Self {
+            flash,
+            slot,
+            offset: 0,
+        }
+    }
+
+    /// Erases the next flash page of the slot and writes `page` into it.
+    ///
+    /// `page` must be no larger than [`FLASH_PAGE_SIZE`]; callers stream an
+    /// image by calling this once per page, in order. Uses
+    /// [`FlashController::page_erase_verified`]/[`FlashController::write_verified`]
+    /// rather than their unverified counterparts, since a staged image that
+    /// silently failed to program would still pass [`bootloader::verify_image`]
+    /// against whatever garbage happens to be in flash.
+    ///
+    /// # Safety
+    ///
+    /// See [`FlashController::page_erase`] and [`FlashController::write`].
+    /// The slot being written to must not be the one currently selected by
+    /// [`select_boot_slot`].
+    pub unsafe fn write_page(&mut self, page: &[u8], sys_clk: &SystemClock) -> Result<(), UpdateError> {
+        if page.len() as u32 > FLASH_PAGE_SIZE || self.offset + FLASH_PAGE_SIZE > SLOT_SIZE {
+            return Err(UpdateError::ImageTooLarge);
+        }
+
+        let page_addr = self.slot.base_addr() + self.offset;
+        // SAFETY: `page_addr..page_addr + FLASH_PAGE_SIZE` lies within
+        // `self.slot`'s reserved range, per this function's safety contract
+        // never the currently active slot.
+        unsafe {
+            self.flash.page_erase_verified(page_addr, sys_clk)?;
+            self.flash.write_verified(page_addr, page, sys_clk)?;
+        }
+        self.offset += FLASH_PAGE_SIZE;
+        Ok(())
+    }
+
+    /// Fault-injection-hardened counterpart to [`Self::write_page`], gated
+    /// behind the `fip` feature: erases and writes the page one 128-bit word
+    /// at a time through [`FlashController::page_erase_fip_hardened`]/
+    /// [`FlashController::write128_fip_hardened`] instead of the Rust-level
+    /// readback [`FlashController::page_erase_verified`]/[`FlashController::write_verified`]
+    /// that [`Self::write_page`] uses, so glitching the comparison itself
+    /// can't let a corrupted staged image through.
+    ///
+    /// Unlike [`Self::write_page`], `page` must be exactly [`FLASH_PAGE_SIZE`]
+    /// bytes -- the FIP primitives only operate one flash word at a time and
+    /// have no notion of "leave the rest erased", so a caller with a shorter
+    /// final page must pad it out (e.g. with `0xFF`) itself.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::write_page`].
+    #[cfg(feature = "fip")]
+    pub unsafe fn write_page_fip_hardened(
+        &mut self,
+        page: &[u8],
+        sys_clk: &SystemClock,
+        fip: &FaultInjectionPrevention,
+        rng: &mut ChaChaRng,
+    ) -> Result<(), UpdateError> {
+        if page.len() as u32 != FLASH_PAGE_SIZE || self.offset + FLASH_PAGE_SIZE > SLOT_SIZE {
+            return Err(UpdateError::ImageTooLarge);
+        }
+
+        let page_addr = self.slot.base_addr() + self.offset;
+        // SAFETY: `page_addr..page_addr + FLASH_PAGE_SIZE` lies within
+        // `self.slot`'s reserved range, per this function's safety contract
+        // never the currently active slot.
+        unsafe {
+            self.flash.page_erase_fip_hardened(page_addr, sys_clk, fip, rng)?;
+            for (i, word) in page.chunks_exact(16).enumerate() {
+                let mut data = [0u32; 4];
+                for (dst, bytes) in data.iter_mut().zip(word.chunks_exact(4)) {
+                    *dst = u32::from_le_bytes(bytes.try_into().unwrap());
+                }
+                self.flash
+                    .write128_fip_hardened(page_addr + (i as u32) * 16, &data, sys_clk, fip, rng)?;
+            }
+        }
+        self.offset += FLASH_PAGE_SIZE;
+        Ok(())
+    }
+
+    /// Finishes staging the image: verifies the Ed25519 signature in its
+    /// trailing [`bootloader::ImageFooter`] over the `total_len` bytes just
+    /// written, then records `total_len` and `version` for this slot and
+    /// marks it [`SlotState::Pending`] so it is tried on the next boot.
+    ///
+    /// On a signature verification failure, this halts forever via
+    /// [`never_exit`] instead of returning an error, so a staged image can
+    /// never be marked bootable without a valid signature, even under a
+    /// glitched comparison.
+    pub fn finish(self, sys_clk: &SystemClock, total_len: u32, version: u32) -> Result<(), UpdateError> {
+        if bootloader::verify_image(self.slot.base_addr(), total_len).is_err() {
+            never_exit();
+        }
+
+        let (log, _, _) = scan_metadata()?;
+        let metadata = SlotMetadata {
+            length: total_len,
+            version,
+            state: SlotState::Pending,
+            attempts: 0,
+        };
+        commit_metadata(self.flash, sys_clk, log.with(self.slot, metadata))
+    }
+}
+
+/// Selects which slot a minimal boot-time verifier should try: the slot with
+/// the highest `version` among those that are [`SlotState::Pending`] or
+/// [`SlotState::Confirmed`], ties broken in favor of a confirmed slot.
+/// Returns `None` if neither slot holds an image worth booting.
+pub fn select_boot_slot() -> Result<Option<SlotId>, UpdateError> {
+    let (log, _, _) = scan_metadata()?;
+
+    Ok([(SlotId::A, log.slot_a), (SlotId::B, log.slot_b)]
+        .into_iter()
+        .filter(|(_, metadata)| matches!(metadata.state, SlotState::Pending | SlotState::Confirmed))
+        .max_by_key(|(_, metadata)| (metadata.version, metadata.state == SlotState::Confirmed))
+        .map(|(slot, _)| slot))
+}
+
+/// The address and length of `slot`'s image, for handing off to
+/// [`crate::peripherals::bootloader::verify_image`].
+pub fn slot_image(slot: SlotId) -> Result<(u32, u32), UpdateError> {
+    let (log, _, _) = scan_metadata()?;
+    Ok((slot.base_addr(), log.get(slot).length))
+}
+
+/// Marks `slot` as [`SlotState::Confirmed`], e.g. once it has booted and
+/// self-tested successfully. A confirmed slot is preferred by
+/// [`select_boot_slot`] as a known-good fallback over a merely pending slot
+/// of the same version.
+pub fn confirm_slot(flash: &FlashController, sys_clk: &SystemClock, slot: SlotId) -> Result<(), UpdateError> {
+    let (log, _, _) = scan_metadata()?;
+    let mut metadata = log.get(slot);
+    metadata.state = SlotState::Confirmed;
+    commit_metadata(flash, sys_clk, log.with(slot, metadata))
+}
+
+/// Records one boot attempt of `slot`, to be called early in boot before
+/// `slot`'s image has had a chance to [`confirm_slot`] itself.
+///
+/// If `slot` is not [`SlotState::Pending`] this is a no-op: a
+/// [`SlotState::Confirmed`] slot has already proven itself, and an
+/// [`SlotState::Empty`] slot was never booted into in the first place. Once a
+/// pending slot's attempt count reaches [`MAX_BOOT_ATTEMPTS`] without a
+/// [`confirm_slot`] call, it is demoted straight to [`SlotState::Empty`], so
+/// [`select_boot_slot`] falls back to the other, presumably still-confirmed,
+/// slot instead of retrying a bad image forever.
+pub fn record_boot_attempt(flash: &FlashController, sys_clk: &SystemClock, slot: SlotId) -> Result<(), UpdateError> {
+    let (log, _, _) = scan_metadata()?;
+    let mut metadata = log.get(slot);
+
+    if metadata.state != SlotState::Pending {
+        return Ok(());
+    }
+
+    metadata.attempts = metadata.attempts.saturating_add(1);
+    if metadata.attempts >= MAX_BOOT_ATTEMPTS {
+        metadata = SlotMetadata::EMPTY;
+    }
+
+    commit_metadata(flash, sys_clk, log.with(slot, metadata))
+}
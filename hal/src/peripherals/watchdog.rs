@@ -1,8 +1,12 @@
 //! Watchdog timer peripheral API.
 
 use crate::peripherals::bit_banding::{change_bit};
+use core::cell::RefCell;
 use core::ptr::{write_volatile};
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::time::Duration;
 use cortex_m::interrupt::free;
+use critical_section::Mutex;
 use max78000::gcr::pclkdis1::UART2_A;
 use max78000::gcr::rst0::RESET_A;
 use max78000::wdt::ctrl::{EN_A, INT_EARLY_A, INT_LATE_A, RST_EARLY_A, RST_LATE_A, WDT_INT_EN_A, WDT_RST_EN_A, WIN_EN_A};
@@ -10,6 +14,11 @@ use max78000::wdt::ctrl::{INT_EARLY_VAL_A, INT_LATE_VAL_A, RST_EARLY_VAL_A, RST_
 use max78000::{GCR, WDT};
 use max78000::wdt::rst::RESET_AW;
 
+/// Fixed frequency of the IBRO oscillator, in Hz, used by
+/// [`Configuration::from_timeout`]/[`WatchdogTimer::start`] to convert a
+/// millisecond timeout into cycles when [`ClockSource::IBRO`] is selected.
+const IBRO_HZ: u64 = 7_372_800;
+
 /// The Watchdog Timer peripheral struct. Obtain an instance of one with `WatchDogTimer::new`
 pub struct WatchdogTimer {
     wdt_regs: WDT
@@ -27,6 +36,7 @@ pub enum ClockSource {
 /// which represents a value in clock cycles for one of the watchdog timer events:
 /// late interrupt, late reset, early interrupt, and early reset. Values are in powers of two,
 /// ranging from `2^16` to `2^31`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Threshold {
     /// `2^16` cycles
     _2POW16,
@@ -87,6 +97,77 @@ macro_rules! into_threshold {
     };
 }
 
+/// Declarative macro that inverts [`into_threshold!`], turning one of the
+/// hardware's `_VAL_A` enums back into a generic [`Threshold`].
+macro_rules! from_threshold {
+    ($value:expr,$thresholdName:ty) => {
+        match $value {
+            <$thresholdName>::WDT2POW16 => Threshold::_2POW16,
+            <$thresholdName>::WDT2POW17 => Threshold::_2POW17,
+            <$thresholdName>::WDT2POW18 => Threshold::_2POW18,
+            <$thresholdName>::WDT2POW19 => Threshold::_2POW19,
+            <$thresholdName>::WDT2POW20 => Threshold::_2POW20,
+            <$thresholdName>::WDT2POW21 => Threshold::_2POW21,
+            <$thresholdName>::WDT2POW22 => Threshold::_2POW22,
+            <$thresholdName>::WDT2POW23 => Threshold::_2POW23,
+            <$thresholdName>::WDT2POW24 => Threshold::_2POW24,
+            <$thresholdName>::WDT2POW25 => Threshold::_2POW25,
+            <$thresholdName>::WDT2POW26 => Threshold::_2POW26,
+            <$thresholdName>::WDT2POW27 => Threshold::_2POW27,
+            <$thresholdName>::WDT2POW28 => Threshold::_2POW28,
+            <$thresholdName>::WDT2POW29 => Threshold::_2POW29,
+            <$thresholdName>::WDT2POW30 => Threshold::_2POW30,
+            <$thresholdName>::WDT2POW31 => Threshold::_2POW31,
+        }
+    };
+}
+
+impl Threshold {
+    /// Smallest [`Threshold`] whose `2^n` cycle count is at least `cycles`,
+    /// clamped to [`Threshold::_2POW31`] if even that isn't enough, or
+    /// `None` if `cycles` is smaller than `2^16`, the smallest threshold
+    /// the hardware supports.
+    fn smallest_covering(cycles: u64) -> Option<Self> {
+        if cycles < (1u64 << 16) {
+            return None;
+        }
+
+        Some(match cycles {
+            c if c <= (1u64 << 16) => Threshold::_2POW16,
+            c if c <= (1u64 << 17) => Threshold::_2POW17,
+            c if c <= (1u64 << 18) => Threshold::_2POW18,
+            c if c <= (1u64 << 19) => Threshold::_2POW19,
+            c if c <= (1u64 << 20) => Threshold::_2POW20,
+            c if c <= (1u64 << 21) => Threshold::_2POW21,
+            c if c <= (1u64 << 22) => Threshold::_2POW22,
+            c if c <= (1u64 << 23) => Threshold::_2POW23,
+            c if c <= (1u64 << 24) => Threshold::_2POW24,
+            c if c <= (1u64 << 25) => Threshold::_2POW25,
+            c if c <= (1u64 << 26) => Threshold::_2POW26,
+            c if c <= (1u64 << 27) => Threshold::_2POW27,
+            c if c <= (1u64 << 28) => Threshold::_2POW28,
+            c if c <= (1u64 << 29) => Threshold::_2POW29,
+            c if c <= (1u64 << 30) => Threshold::_2POW30,
+            _ => Threshold::_2POW31,
+        })
+    }
+}
+
+/// Error returned when a [`Configuration`]/[`WindowedConfiguration`] can't
+/// be derived from a requested timeout or from live register state.
+#[derive(Debug, Copy, Clone)]
+pub enum WatchdogConfigError {
+    /// The requested timeout is shorter than `2^16` clock cycles, the
+    /// smallest threshold the hardware supports.
+    TimeoutTooShort,
+    /// `WDT_CLKSEL.source` held a reserved encoding that isn't one of the
+    /// documented [`ClockSource`] values.
+    UnknownClockSource,
+    /// [`WindowedConfiguration::try_from_registers`] was called but
+    /// `WDT_CTRL.win_en` isn't set.
+    WindowedModeNotEnabled,
+}
+
 /// Windowed timer mode configuration - allows the timer to also trigger an interrupt or reset
 /// if the watchdog is kicked too early.
 pub struct WindowedConfiguration {
@@ -98,6 +179,24 @@ pub struct WindowedConfiguration {
     pub reset_early_val: Threshold,
 }
 
+impl WindowedConfiguration {
+    /// Reconstructs a [`WindowedConfiguration`] from `wdt`'s live `ctrl`
+    /// register, inverting the `into_threshold!` mapping
+    /// [`WatchdogTimer::configure`] used to program it. Fails if windowed
+    /// mode isn't actually enabled.
+    pub fn try_from_registers(wdt: &WatchdogTimer) -> Result<Self, WatchdogConfigError> {
+        let ctrl = wdt.wdt_regs.ctrl().read();
+        if ctrl.win_en().variant() != WIN_EN_A::EN {
+            return Err(WatchdogConfigError::WindowedModeNotEnabled);
+        }
+
+        Ok(Self {
+            interrupt_early_val: from_threshold!(ctrl.int_early_val().variant(), INT_EARLY_VAL_A),
+            reset_early_val: from_threshold!(ctrl.rst_early_val().variant(), RST_EARLY_VAL_A),
+        })
+    }
+}
+
 /// Configuration for the watchdog timer.
 pub struct Configuration {
     /// Clock source for the watchdog timer to use.
@@ -119,6 +218,73 @@ pub struct Configuration {
     pub windowed_mode: Option<WindowedConfiguration>,
 }
 
+impl Configuration {
+    /// Builds a non-windowed [`Configuration`] from a requested timeout in
+    /// milliseconds, instead of requiring the caller to pick raw
+    /// [`Threshold`] power-of-two values by hand. `pclk_hz` is only
+    /// consulted when `clock_source` is [`ClockSource::PCLK`] (pass the
+    /// system clock's frequency); [`ClockSource::IBRO`] always runs at its
+    /// fixed 7.3728 MHz. `interrupt_margin_ms` is subtracted from
+    /// `reset_timeout_ms` so the late interrupt fires that much before the
+    /// reset, mirroring how the gd32/stm32 independent-watchdog HALs
+    /// translate a requested millisecond timeout into a prescaler + reload
+    /// value.
+    pub fn from_timeout(
+        clock_source: ClockSource,
+        pclk_hz: u32,
+        reset_timeout_ms: u32,
+        interrupt_margin_ms: u32,
+    ) -> Result<Self, WatchdogConfigError> {
+        let freq_hz = match clock_source {
+            ClockSource::PCLK => pclk_hz as u64,
+            ClockSource::IBRO => IBRO_HZ,
+        };
+
+        let reset_cycles = freq_hz * reset_timeout_ms as u64 / 1000;
+        let interrupt_timeout_ms = reset_timeout_ms.saturating_sub(interrupt_margin_ms);
+        let interrupt_cycles = freq_hz * interrupt_timeout_ms as u64 / 1000;
+
+        Ok(Self {
+            clock_source,
+            reset_late_val: Threshold::smallest_covering(reset_cycles)
+                .ok_or(WatchdogConfigError::TimeoutTooShort)?,
+            interrupt_late_val: Threshold::smallest_covering(interrupt_cycles)
+                .ok_or(WatchdogConfigError::TimeoutTooShort)?,
+            watchdog_interrupt_enable: true,
+            watchdog_reset_enable: true,
+            windowed_mode: None,
+        })
+    }
+
+    /// Reconstructs a [`Configuration`] from `wdt`'s live `clksel`/`ctrl`
+    /// registers, inverting the `into_threshold!` mapping
+    /// [`WatchdogTimer::configure`] used to program them. Useful after a
+    /// watchdog-triggered reset, or when a bootloader has already started
+    /// the timer, so application code can discover the existing
+    /// timeout/window instead of duplicating magic constants.
+    pub fn try_from_registers(wdt: &WatchdogTimer) -> Result<Self, WatchdogConfigError> {
+        let clock_source = match wdt.wdt_regs.clksel().read().source().bits() {
+            1 => ClockSource::PCLK,
+            2 => ClockSource::IBRO,
+            _ => return Err(WatchdogConfigError::UnknownClockSource),
+        };
+
+        let ctrl = wdt.wdt_regs.ctrl().read();
+        let windowed_mode = (ctrl.win_en().variant() == WIN_EN_A::EN)
+            .then(|| WindowedConfiguration::try_from_registers(wdt))
+            .transpose()?;
+
+        Ok(Self {
+            clock_source,
+            interrupt_late_val: from_threshold!(ctrl.int_late_val().variant(), INT_LATE_VAL_A),
+            reset_late_val: from_threshold!(ctrl.rst_late_val().variant(), RST_LATE_VAL_A),
+            watchdog_interrupt_enable: ctrl.wdt_int_en().variant() == WDT_INT_EN_A::EN,
+            watchdog_reset_enable: ctrl.wdt_rst_en().variant() == WDT_RST_EN_A::EN,
+            windowed_mode,
+        })
+    }
+}
+
 enum FeedSequenceOperation {
     Disable,
     Enable,
@@ -404,4 +570,216 @@ impl WatchdogTimer {
             }
         });
     }
+
+    /// Splits this watchdog into `N` independent [`WatchdogHandle`]s (`N` up
+    /// to 8, one bit per handle in the internal check-in mask), modeled on
+    /// embassy-nrf's multi-handle WDT. The hardware is only actually kicked
+    /// once every handle has called [`WatchdogHandle::pet`] since the
+    /// previous kick, so if any one supervised task hangs and never pets,
+    /// the watchdog lapses and the system resets. This gives cooperative
+    /// liveness monitoring across several independent tasks, which a single
+    /// [`Self::kick`] can't express.
+    pub fn into_handles<const N: usize>(self) -> [WatchdogHandle; N] {
+        assert!(N <= 8, "watchdog only supports up to 8 handles");
+
+        let all_handles_mask = ((1u16 << N) - 1) as u8;
+        critical_section::with(|cs| {
+            PET_MASK.store(0, Ordering::Release);
+            ALL_HANDLES_MASK.store(all_handles_mask, Ordering::Release);
+            SHARED.borrow(cs).replace(Some(self));
+        });
+
+        core::array::from_fn(|i| WatchdogHandle { bit: i as u8 })
+    }
+}
+
+/// Bitmask of handles that have called [`WatchdogHandle::pet`] since the
+/// last real hardware kick. Bit `i` corresponds to the handle returned at
+/// index `i` of [`WatchdogTimer::into_handles`].
+static PET_MASK: AtomicU8 = AtomicU8::new(0);
+
+/// Bitmask with one bit set for every handle created by the most recent
+/// [`WatchdogTimer::into_handles`] call; [`PET_MASK`] must equal this for
+/// the hardware to actually be kicked.
+static ALL_HANDLES_MASK: AtomicU8 = AtomicU8::new(0);
+
+/// The [`WatchdogTimer`] behind the handles created by
+/// [`WatchdogTimer::into_handles`], kept here so [`WatchdogHandle::pet`] can
+/// reach the real [`WatchdogTimer::kick`] without each handle owning (or
+/// borrowing) the peripheral itself.
+static SHARED: Mutex<RefCell<Option<WatchdogTimer>>> = Mutex::new(RefCell::new(None));
+
+/// One supervised task's view of a [`WatchdogTimer`] split by
+/// [`WatchdogTimer::into_handles`]. Call [`Self::pet`] once per period from
+/// each task; the underlying hardware is only actually kicked once every
+/// handle has petted.
+pub struct WatchdogHandle {
+    bit: u8,
+}
+
+impl WatchdogHandle {
+    /// Marks this handle as having checked in for the current period. If
+    /// this was the last outstanding handle, issues the real hardware kick
+    /// and resets the mask for the next period.
+    pub fn pet(&self) {
+        let mask = PET_MASK.fetch_or(1 << self.bit, Ordering::AcqRel) | (1 << self.bit);
+        if mask == ALL_HANDLES_MASK.load(Ordering::Acquire) {
+            PET_MASK.store(0, Ordering::Release);
+            critical_section::with(|cs| {
+                if let Some(wdt) = SHARED.borrow(cs).borrow_mut().as_mut() {
+                    wdt.kick();
+                }
+            });
+        }
+    }
+}
+
+/// Which way a supervised task missed its window: too soon (runaway loop)
+/// or too late (stalled loop). Reported by [`on_interrupt`] in the brief
+/// window before the corresponding reset event fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorFault {
+    /// A task kicked before the early threshold elapsed, ie. it's running
+    /// faster than the window allows.
+    KickedTooEarly {
+        /// The task ID last passed to [`WindowSupervisor::kick`].
+        task: u8,
+    },
+    /// A task failed to kick before the late threshold elapsed, ie. it's
+    /// stalled.
+    KickedTooLate {
+        /// The task ID last passed to [`WindowSupervisor::kick`].
+        task: u8,
+    },
+}
+
+/// Task ID last passed to [`WindowSupervisor::kick`], consulted by
+/// [`on_interrupt`] to attribute a fault to the task that caused it.
+static LAST_KICKER: AtomicU8 = AtomicU8::new(0xFF);
+
+/// Callback registered via [`WindowSupervisor::set_fault_callback`] and
+/// invoked by [`on_interrupt`] with the detected [`SupervisorFault`].
+static FAULT_CALLBACK: Mutex<RefCell<Option<fn(SupervisorFault)>>> = Mutex::new(RefCell::new(None));
+
+/// Higher-level supervisor built on a windowed [`WatchdogTimer`] (inspired by
+/// Tock's stm32f3 window-watchdog usage) that turns the raw early/late event
+/// flags into an actionable fault-reporting pipeline: it tracks which
+/// supervised task last kicked, and on the early/late interrupt reports
+/// whether that task kicked too early (runaway loop) or too late (stalled
+/// loop) to a caller-supplied callback.
+pub struct WindowSupervisor {
+    wdt: WatchdogTimer,
+}
+
+impl WindowSupervisor {
+    /// Wraps `wdt`, which the caller must already have [`WatchdogTimer::configure`]d
+    /// with a [`Configuration::windowed_mode`] and `watchdog_interrupt_enable: true`.
+    pub fn new(wdt: WatchdogTimer) -> Self {
+        Self { wdt }
+    }
+
+    /// Registers the callback [`on_interrupt`] invokes with the detected
+    /// [`SupervisorFault`], so firmware can log diagnostics (eg. over the
+    /// `communication` layer) in the brief window before the reset fires.
+    pub fn set_fault_callback(&mut self, callback: fn(SupervisorFault)) {
+        critical_section::with(|cs| {
+            FAULT_CALLBACK.borrow(cs).replace(Some(callback));
+        });
+    }
+
+    /// Kicks the watchdog on behalf of `task`, recording it as the last
+    /// supervised task to check in so a subsequent [`on_interrupt`] fault
+    /// is attributed to the right task.
+    pub fn kick(&mut self, task: u8) {
+        LAST_KICKER.store(task, Ordering::Release);
+        self.wdt.kick();
+    }
+
+    /// Releases the underlying [`WatchdogTimer`].
+    pub fn release(self) -> WatchdogTimer {
+        self.wdt
+    }
+}
+
+/// Interrupt handler for the windowed-watchdog early/late interrupt. Call
+/// this from your own `#[interrupt]` handler for the WDT interrupt line —
+/// this HAL never unmasks the NVIC interrupt itself, the same way
+/// `peripherals::timer::InterruptTimer` leaves NVIC routing to the caller.
+/// Inspects which event flag fired, builds the corresponding
+/// [`SupervisorFault`] attributed to the last task that called
+/// [`WindowSupervisor::kick`], invokes the registered callback, and clears
+/// the flags.
+pub fn on_interrupt(supervisor: &mut WindowSupervisor) {
+    let task = LAST_KICKER.load(Ordering::Acquire);
+
+    let fault = if supervisor.wdt.interrupt_early_event() {
+        Some(SupervisorFault::KickedTooEarly { task })
+    } else if supervisor.wdt.interrupt_late_event() {
+        Some(SupervisorFault::KickedTooLate { task })
+    } else {
+        None
+    };
+
+    if let Some(fault) = fault {
+        critical_section::with(|cs| {
+            if let Some(callback) = *FAULT_CALLBACK.borrow(cs).borrow() {
+                callback(fault);
+            }
+        });
+    }
+
+    supervisor.wdt.clear_interrupt_early_flag();
+    supervisor.wdt.clear_interrupt_late_flag();
+}
+
+// Fully qualified rather than imported, the same way this crate's other
+// optional `embedded_hal` surfaces are (see e.g. `peripherals::gpio::common`'s
+// `eh1-digital` impls), so a differently-versioned watchdog trait could be
+// added later without the two colliding.
+
+#[cfg(feature = "eh02-watchdog")]
+impl embedded_hal_0_2::watchdog::Watchdog for WatchdogTimer {
+    fn feed(&mut self) {
+        self.kick();
+    }
+}
+
+#[cfg(feature = "eh02-watchdog")]
+impl embedded_hal_0_2::watchdog::WatchdogEnable for WatchdogTimer {
+    type Time = Duration;
+
+    /// Configures and enables the watchdog for `period`, the way the
+    /// stm32f3xx-hal `IndependentWatchDog` does: non-windowed, with the
+    /// hardware reset path enabled and the interrupt path left off since
+    /// there's no generic way for this trait to wire up an interrupt
+    /// handler. Uses [`ClockSource::IBRO`] so the timeout doesn't depend on
+    /// the system clock configuration.
+    fn start<T>(&mut self, period: T)
+    where
+        T: Into<Self::Time>,
+    {
+        let timeout_ms = period.into().as_millis().min(u32::MAX as u128) as u32;
+
+        let mut config = Configuration::from_timeout(ClockSource::IBRO, 0, timeout_ms, 0)
+            .unwrap_or_else(|_| Configuration {
+                clock_source: ClockSource::IBRO,
+                interrupt_late_val: Threshold::_2POW31,
+                reset_late_val: Threshold::_2POW31,
+                watchdog_interrupt_enable: false,
+                watchdog_reset_enable: true,
+                windowed_mode: None,
+            });
+        config.watchdog_interrupt_enable = false;
+        config.watchdog_reset_enable = true;
+
+        self.configure(config);
+        self.enable();
+    }
+}
+
+#[cfg(feature = "eh02-watchdog")]
+impl embedded_hal_0_2::watchdog::WatchdogDisable for WatchdogTimer {
+    fn disable(&mut self) {
+        self.disable();
+    }
 }
@@ -17,9 +17,13 @@ use max78000_hal::peripherals::i2c::BusSpeed;
 use max78000_hal::peripherals::oscillator::{Ipo, IpoDivider, IpoFrequency};
 use max78000_hal::peripherals::timer::{Oscillator, Prescaler, Time};
 use max78000_hal::peripherals::{PeripheralManagerBuilder, SplittablePeripheral};
-
 extern crate fault_injection_protection_arm;
 
+#[path = "../tests.rs"]
+pub mod tests;
+
+use tests::i2c_loopback_tests;
+
 /// Entry point for tests.
 #[entry]
 fn main() -> ! {
@@ -119,6 +123,10 @@ fn main() -> ! {
 
     writeln!(stdout, "Read {:?}", stuff).unwrap();*/
 
+    writeln!(stdout, "Running shared loopback script...\n").unwrap();
+
+    i2c_loopback_tests::run_master_side(&mut i2c_master, &mut stdout);
+
     writeln!(stdout, "Finished i2c master tests!\n").unwrap();
 
     #[allow(clippy::empty_loop)]
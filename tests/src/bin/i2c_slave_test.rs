@@ -11,13 +11,18 @@ use cortex_m_semihosting::hio;
 use max78000_hal::communication::InfTimeout;
 use max78000_hal::communication::{RxChannel, TxChannel};
 use max78000_hal::max78000::Peripherals;
-use max78000_hal::peripherals::i2c::BusSpeed;
+use max78000_hal::peripherals::i2c::{BusSpeed, FifoEmptyMode, SlaveAddress};
 use max78000_hal::peripherals::oscillator::{Ipo, IpoDivider, IpoFrequency};
 use max78000_hal::peripherals::timer::{Oscillator, Prescaler, Time};
 use max78000_hal::peripherals::{PeripheralManagerBuilder, SplittablePeripheral};
 
 extern crate fault_injection_protection_arm;
 
+#[path = "../tests.rs"]
+pub mod tests;
+
+use tests::i2c_loopback_tests;
+
 /// Entry point for tests.
 #[entry]
 fn main() -> ! {
@@ -45,7 +50,14 @@ fn main() -> ! {
     .configure_timer_2(Oscillator::ISO, Prescaler::_4096)
     .build();
 
-    let mut i2c_slave = manager.i2c_slave(BusSpeed::Standard100kbps, 69).unwrap();
+    let mut i2c_slave = manager
+        .i2c_slave(
+            BusSpeed::Standard100kbps,
+            SlaveAddress::SevenBit(69),
+            false,
+            FifoEmptyMode::ClockStretch,
+        )
+        .unwrap();
     let clock = manager.timer_0().unwrap();
 
     let mut timer = clock.new_timer(Time::Milliseconds(1000));
@@ -129,6 +141,10 @@ fn main() -> ! {
         }
     }*/
 
+    writeln!(stdout, "Running shared loopback script...\n").unwrap();
+
+    i2c_loopback_tests::run_slave_side(&mut i2c_slave, &mut stdout);
+
     writeln!(stdout, "Finished i2c slave tests!\n").unwrap();
 
     #[allow(clippy::empty_loop)]
@@ -13,6 +13,7 @@ use max78000_hal::{
     peripherals::{
         oscillator::{Ipo, IpoDivider, IpoFrequency},
         timer::{Oscillator, Prescaler},
+        uart::UartConfig,
         PeripheralManagerBuilder, SplittablePeripheral,
     },
 };
@@ -66,7 +67,10 @@ fn main() -> ! {
     );
 
     {
-        let mut uart = manager.build_uart().unwrap().build(115200);
+        let mut uart = manager
+            .build_uart()
+            .unwrap()
+            .build(115200, UartConfig::default());
 
         // run FLC tests with UART
         flc_tests::run_flc_tests(
@@ -89,6 +93,7 @@ fn main() -> ! {
     oscillator_tests::run_oscillator_tests(
         to_borrow.gcr.clkctrl(),
         manager.system_clock().unwrap(),
+        manager.power(),
         &mut stdout,
         #[cfg(feature = "low_frequency_test")]
         to_borrow.trimsir.inro(),
@@ -119,6 +124,12 @@ fn main() -> ! {
 
     writeln!(stdout, "Finished MAX78000 HAL tests!\n").unwrap();
 
-    #[allow(clippy::empty_loop)]
-    loop {}
+    // Wrap up by handing UART0 over to the host as an echo server, driven by
+    // the embedded_io Read/Write impls rather than RxChannel/TxChannel.
+    uart_tests::run_uart_echo_example(
+        manager
+            .build_uart()
+            .unwrap()
+            .build(115200, UartConfig::default()),
+    );
 }
@@ -5,6 +5,7 @@ pub mod csprng_tests;
 pub mod fip_tests;
 pub mod flc_tests;
 pub mod gpio_tests;
+pub mod i2c_loopback_tests;
 pub mod oscillator_tests;
 pub mod timer_tests;
 pub mod trng_tests;
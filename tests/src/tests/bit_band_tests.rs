@@ -83,7 +83,7 @@ fn test_spin_bit(stdout: &mut hio::HostStream, clock: &RTC) {
             spin_bit(&control as *const _, i, false);
         }
 
-            control |= 1 << i;
+        control |= 1 << i;
 
         // SAFETY: Safe as we are passing in a valid memory address in the bit-banded SRAM space, initialized in the `control` variable.
         unsafe {
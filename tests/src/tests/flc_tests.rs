@@ -3,11 +3,12 @@
 use core::fmt::Write;
 use cortex_m_semihosting::hio;
 use max78000_hal::max78000::{trimsir::INRO, FLC, GCR, ICC0};
-use max78000_hal::peripherals::flash_controller::{FlashController, FlashErr};
+use max78000_hal::peripherals::flash_controller::{Flash, FlashController, FlashErr};
 use max78000_hal::peripherals::oscillator::{
     Ibro, IbroDivider, IbroFrequency, Ipo, IpoDivider, IpoFrequency, Iso, IsoDivider, IsoFrequency,
     Oscillator, SystemClock,
 };
+use max78000_hal::peripherals::power::Power;
 
 /// Runs all flash controller tests: [`flash_write`], [`flash_write_large`],
 /// [`flash_write_extra_large`], [`flash_write_after_sys_osc_switch`],
@@ -17,8 +18,9 @@ use max78000_hal::peripherals::oscillator::{
 /// [`flash_write_full_paritially_outbound_end`].
 pub fn run_flc_tests(stdout: &mut hio::HostStream, flc: FLC, icc0: &ICC0, gcr: &GCR, inro: &INRO) {
     writeln!(stdout, "Starting flash tests...").unwrap();
+    let power = Power::new(gcr);
     let ipo = Ipo::new(IpoFrequency::_100MHz, IpoDivider::_1);
-    let mut sys_clk = SystemClock::new(&ipo, gcr.clkctrl(), inro);
+    let mut sys_clk = SystemClock::new(&ipo, gcr.clkctrl(), inro, &power).unwrap();
     let flash_controller = FlashController::new(flc, icc0, gcr);
 
     writeln!(stdout, "Test flash write...").unwrap();
@@ -36,7 +38,7 @@ pub fn run_flc_tests(stdout: &mut hio::HostStream, flc: FLC, icc0: &ICC0, gcr: &
     {
         writeln!(stdout, "Test flash write after invalid clock divider...").unwrap();
         let ibro = Ibro::new(IbroFrequency::_7_3728MHz, IbroDivider::_2);
-        sys_clk.set_sysclk(&ibro);
+        sys_clk.set_sysclk(&ibro, &power).unwrap();
         flash_write_invalid_clk_div(&flash_controller, &sys_clk);
     }
 
@@ -47,7 +49,7 @@ pub fn run_flc_tests(stdout: &mut hio::HostStream, flc: FLC, icc0: &ICC0, gcr: &
         )
         .unwrap();
         let iso = Iso::new(IsoFrequency::_60MHz, IsoDivider::_1);
-        sys_clk.set_sysclk(&iso);
+        sys_clk.set_sysclk(&iso, &power).unwrap();
         flash_write_after_sys_osc_switch(&flash_controller, &sys_clk);
     }
 
@@ -58,7 +60,7 @@ pub fn run_flc_tests(stdout: &mut hio::HostStream, flc: FLC, icc0: &ICC0, gcr: &
         )
         .unwrap();
         let iso = Iso::new(IsoFrequency::_60MHz, IsoDivider::_4);
-        sys_clk.set_sysclk(&iso);
+        sys_clk.set_sysclk(&iso, &power).unwrap();
         flash_write_after_sys_clk_div_changes(&flash_controller, &sys_clk);
     }
 
@@ -79,6 +81,11 @@ pub fn run_flc_tests(stdout: &mut hio::HostStream, flc: FLC, icc0: &ICC0, gcr: &
     .unwrap();
     flash_write_full_paritially_outbound_end(&flash_controller, &sys_clk);
 
+    writeln!(stdout, "Test Flash wrapper write and erase...").unwrap();
+    let flash = Flash::new(flash_controller);
+    flash_wrapper_write(&flash, &sys_clk);
+    flash_wrapper_erase(&flash, &sys_clk);
+
     writeln!(stdout, "Flash Controller tests complete!").unwrap();
 }
 
@@ -243,6 +250,34 @@ fn flash_write_paritially_outbound_beginning(
     }
 }
 
+/// A [`Flash`]-wrapped write is verified by reading the data back, on top of
+/// everything [`FlashController::write`] already checks.
+fn flash_wrapper_write(flash: &Flash, sys_clk: &SystemClock) {
+    let test_addr: u32 = 0x10070E00;
+    let test_val: u32 = 0xDEADBEEF;
+    let mut data_read: [u8; 4] = [0; 4];
+
+    unsafe {
+        flash.page_erase(test_addr, sys_clk).unwrap();
+        flash
+            .write(test_addr, &u32::to_le_bytes(test_val), sys_clk)
+            .unwrap();
+    }
+    Flash::read_bytes(test_addr, &mut data_read).unwrap();
+
+    assert!(u32::from_le_bytes(data_read) == test_val);
+}
+
+/// A [`Flash`]-wrapped erase is verified by reading the page back and
+/// checking it is fully erased.
+fn flash_wrapper_erase(flash: &Flash, sys_clk: &SystemClock) {
+    let test_addr: u32 = 0x10070E00;
+
+    unsafe {
+        flash.page_erase(test_addr, sys_clk).unwrap();
+    }
+}
+
 /// Flash writes which have the end address above the end of a valid flash
 /// address range are caught by the write function which checks if the
 /// end address is in bounds
@@ -5,6 +5,7 @@ use cortex_m_semihosting::hio;
 use max78000_hal::peripherals::gpio::{
     active::{
         port_num_types::GpioPortNum, ActiveGpio, ActiveInputPinConfig, ActiveOutputPinConfig,
+        InterruptTrigger,
     },
     pin_traits::{InputPin, IoPin, OutputPin, PinState, StatefulOutputPin},
     Gpio0, Gpio1, Gpio2, GpioError, GpioPort, PinIoMode, PinOperatingMode,
@@ -52,6 +53,12 @@ fn test_active_port<const PIN_CT: usize, PortNum: GpioPortNum + 'static>(
 
     assert_ne!(pin.is_low(), pin.is_high());
 
+    assert!(!pin.is_pending());
+    pin.enable_interrupt(InterruptTrigger::BothEdges).unwrap();
+    pin.disable_interrupt();
+    pin.clear_pending();
+    assert!(!pin.is_pending());
+
     let mut pin = pin
         .into_output_pin(PinState::High, ActiveOutputPinConfig::default())
         .unwrap();
@@ -67,6 +74,14 @@ fn test_active_port<const PIN_CT: usize, PortNum: GpioPortNum + 'static>(
     assert!(pin.is_set_high().unwrap());
     drop(pin);
 
+    let pin = port.get_pin_handle(PIN_CT - 1).unwrap();
+    assert_eq!(pin.get_io_mode(), PinIoMode::Output);
+    assert_eq!(
+        pin.enable_interrupt(InterruptTrigger::RisingEdge),
+        Err(GpioError::WrongIoMode)
+    );
+    drop(pin);
+
     let _pin = port.get_pin_handle(PIN_CT - 1).unwrap();
     assert!(matches!(
         port.get_pin_handle(PIN_CT - 1),
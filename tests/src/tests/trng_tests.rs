@@ -7,12 +7,13 @@ use cortex_m_semihosting::hio;
 use max78000_hal::peripherals::{trng::Trng, PeripheralHandle};
 
 /// Runs all TRNG tests.
-pub fn run_trng_tests(trng: PeripheralHandle<'_, Trng>, stdout: &mut hio::HostStream) {
+pub fn run_trng_tests(mut trng: PeripheralHandle<'_, Trng>, stdout: &mut hio::HostStream) {
     writeln!(stdout, "Starting TRNG peripheral tests...").unwrap();
 
     // Run tests.
     test_random_u32(&trng);
     test_fill_buffer(&trng, stdout);
+    test_fill_buffer_checked(&mut trng);
     writeln!(stdout, "TRNG peripheral tests complete!\n").unwrap();
 }
 
@@ -100,3 +101,11 @@ fn test_fill_buffer(trng: &Trng, stdout: &mut hio::HostStream) {
         assert_ne!(buf, [0u8; 124]);
     }
 }
+
+/// Tests the [`Trng::fill_buffer_checked()`] function.
+fn test_fill_buffer_checked(trng: &mut Trng) {
+    let mut buf = [0u8; 4096];
+    trng.fill_buffer_checked(&mut buf)
+        .expect("a healthy TRNG should pass its continuous health tests");
+    assert_ne!(buf, [0u8; 4096]);
+}
@@ -2,6 +2,7 @@
 
 use core::fmt::Write;
 use cortex_m_semihosting::hio;
+use embedded_io::{Read, Write as EioWrite};
 use max78000_hal::communication::{
     CommunicationError, LineDelimitedRxChannel, LineEnding, RxChannel, TxChannel,
 };
@@ -9,7 +10,7 @@ use max78000_hal::max78000::TMR2;
 use max78000_hal::peripherals::timer::Time::Milliseconds;
 use max78000_hal::peripherals::{
     timer::Clock,
-    uart::{Uart0, UartBuilder},
+    uart::{Uart, Uart0, UartBuilder, UartConfig, UartInstance},
     PeripheralHandle,
 };
 
@@ -74,7 +75,7 @@ pub fn run_uart_test(
 ) {
     writeln!(stdout, "Starting UART tests...\n").unwrap();
 
-    let mut uart = uart_builder.build(115200);
+    let mut uart = uart_builder.build(115200, UartConfig::default());
 
     // send, host should receive the same data
     let mut buf = *b"bleh bleh bleh";
@@ -173,3 +174,16 @@ pub fn run_uart_test(
     );
     assert_eq!(&line_buf[0..19], b"r before a newline\n");
 }
+
+/// Echoes every byte the host sends straight back over UART0, driven purely
+/// through the `embedded_io` `Read`/`Write` impls instead of `RxChannel`/
+/// `TxChannel`. Mirrors the va416xx HAL's "send test string, then enter echo
+/// mode" way of wrapping up its UART example; this is meant to be run last,
+/// since (like that example) it never returns.
+pub fn run_uart_echo_example<T: UartInstance>(mut uart: Uart<'_, T>) -> ! {
+    let mut byte = [0u8; 1];
+    loop {
+        uart.read_exact(&mut byte).unwrap();
+        uart.write_all(&byte).unwrap();
+    }
+}